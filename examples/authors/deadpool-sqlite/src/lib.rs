@@ -0,0 +1,70 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_context::test_context;
+    use test_utils::DeadpoolSqliteContext;
+
+    async fn migrate_db(conn: &deadpool_sqlite::Object) {
+        conn.interact(|conn| conn.execute_batch(include_str!("../../sqlx-sqlite/schema.sql")))
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test_context(DeadpoolSqliteContext)]
+    #[tokio::test]
+    async fn test_authors(ctx: &mut DeadpoolSqliteContext) {
+        let conn = ctx.pool.get().await.unwrap();
+        migrate_db(&conn).await;
+
+        let authors = queries::ListAuthors.query_many(&conn).await.unwrap();
+        assert_eq!(authors.len(), 0);
+
+        let affected_rows = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(Some(
+                "Co-author of The C Programming Language and The Go Programming Language",
+            ))
+            .build()
+            .execute(&conn)
+            .await
+            .unwrap();
+        assert_eq!(affected_rows, 1);
+
+        let id = conn
+            .interact(|conn| conn.last_insert_rowid())
+            .await
+            .unwrap();
+        let fetched_author = queries::GetAuthor::builder()
+            .id(id)
+            .build()
+            .query_one(&conn)
+            .await
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+
+        let missing = queries::GetAuthor::builder()
+            .id(id + 1)
+            .build()
+            .query_opt(&conn)
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+
+        // errors of the statement are returned as `DeadpoolSqliteError::Rusqlite`
+        let error = queries::GetAuthor::builder()
+            .id(id + 1)
+            .build()
+            .query_one(&conn)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            queries::DeadpoolSqliteError::Rusqlite(rusqlite::Error::QueryReturnedNoRows)
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(all(test, feature = "tokio-postgres"))]
+mod tokio_postgres_tests {
+    use super::queries::{self, tokio_postgres_ext::*};
+    use test_context::test_context;
+    use test_utils::PgTokioTestContext;
+
+    #[test_context(PgTokioTestContext)]
+    #[tokio::test]
+    async fn test_authors(ctx: &mut PgTokioTestContext) {
+        let client = &ctx.client;
+        client
+            .batch_execute(include_str!("../../tokio-postgres/schema.sql"))
+            .await
+            .unwrap();
+
+        let inserted_author = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(None)
+            .build()
+            .query_one(client)
+            .await
+            .unwrap();
+
+        let fetched_author = queries::GetAuthor::builder()
+            .id(inserted_author.id)
+            .build()
+            .query_one(client)
+            .await
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+    }
+}
+
+#[cfg(all(test, feature = "sqlx"))]
+mod sqlx_tests {
+    use super::queries::{self, sqlx_ext::*};
+    use test_context::test_context;
+    use test_utils::SqlxPgContext;
+
+    #[test_context(SqlxPgContext)]
+    #[tokio::test]
+    async fn test_authors(ctx: &mut SqlxPgContext) {
+        let pool = &ctx.pool;
+        sqlx::raw_sql(include_str!("../../tokio-postgres/schema.sql"))
+            .execute(pool)
+            .await
+            .unwrap();
+
+        let inserted_author = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(None)
+            .build()
+            .query_one(pool)
+            .await
+            .unwrap();
+
+        let fetched_author = queries::GetAuthor::builder()
+            .id(inserted_author.id)
+            .build()
+            .query_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+    }
+}
@@ -0,0 +1,705 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+#[cfg(feature = "tokio-postgres")]
+use tokio_postgres::types::ToSql;
+#[cfg(feature = "sqlx")]
+pub struct CopyDataSink<C: std::ops::DerefMut<Target = sqlx::PgConnection>> {
+    encode_buf: sqlx::postgres::PgArgumentBuffer,
+    data_buf: Vec<u8>,
+    copy_in: sqlx::postgres::PgCopyIn<C>,
+}
+#[cfg(feature = "sqlx")]
+impl<C: std::ops::DerefMut<Target = sqlx::PgConnection>> CopyDataSink<C> {
+    const BUFFER_SIZE: usize = 4096;
+    fn new(copy_in: sqlx::postgres::PgCopyIn<C>) -> Self {
+        let mut data_buf = Vec::with_capacity(Self::BUFFER_SIZE);
+        const COPY_SIGNATURE: &[u8] = &[
+            b'P', b'G', b'C', b'O', b'P', b'Y', b'\n', 0xFF, b'\r', b'\n', 0x00,
+        ];
+        assert_eq!(COPY_SIGNATURE.len(), 11);
+        data_buf.extend_from_slice(COPY_SIGNATURE);
+        data_buf.extend(0_i32.to_be_bytes());
+        data_buf.extend(0_i32.to_be_bytes());
+        CopyDataSink {
+            encode_buf: Default::default(),
+            data_buf,
+            copy_in,
+        }
+    }
+    async fn send(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let _copy_in = self.copy_in.send(self.data_buf.as_slice()).await?;
+        self.data_buf.clear();
+        Ok(())
+    }
+    /// Complete copy process and return number of rows affected.
+    pub async fn finish(mut self) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        const COPY_TRAILER: &[u8] = &(-1_i16).to_be_bytes();
+        self.data_buf.extend(COPY_TRAILER);
+        self.send().await?;
+        self.copy_in.finish().await.map_err(|e| e.into())
+    }
+    fn insert_row(&mut self) {
+        let num_col = self.copy_in.num_columns() as i16;
+        self.data_buf.extend(num_col.to_be_bytes());
+    }
+    async fn add<'q, T>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+    where
+        T: sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>,
+    {
+        let is_null = value.encode_by_ref(&mut self.encode_buf)?;
+        match is_null {
+            sqlx::encode::IsNull::Yes => {
+                self.data_buf.extend((-1_i32).to_be_bytes());
+            }
+            sqlx::encode::IsNull::No => {
+                self.data_buf
+                    .extend((self.encode_buf.len() as i32).to_be_bytes());
+                self.data_buf.extend_from_slice(self.encode_buf.as_slice());
+            }
+        }
+        self.encode_buf.clear();
+        if self.data_buf.len() > Self::BUFFER_SIZE {
+            self.send().await?;
+        }
+        Ok(())
+    }
+}
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct GetAuthorRow {
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "id"))]
+    pub id: i64,
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "name"))]
+    pub name: String,
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "bio"))]
+    pub bio: Option<String>,
+}
+pub struct GetAuthor {
+    id: i64,
+}
+impl GetAuthor {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+WHERE id = $1 LIMIT 1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetAuthor {
+    pub const fn builder() -> GetAuthorBuilder<'static, ((),)> {
+        GetAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> GetAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        GetAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> GetAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> GetAuthor {
+        let (id,) = self.fields;
+        GetAuthor { id }
+    }
+}
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct ListAuthorsRow {
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "id"))]
+    pub id: i64,
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "name"))]
+    pub name: String,
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "bio"))]
+    pub bio: Option<String>,
+}
+pub struct ListAuthors;
+impl ListAuthors {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+ORDER BY name";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl ListAuthors {
+    pub const fn builder() -> ListAuthorsBuilder<'static, ()> {
+        ListAuthorsBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct ListAuthorsBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> ListAuthorsBuilder<'a, ()> {
+    pub fn build(self) -> ListAuthors {
+        let () = self.fields;
+        ListAuthors {}
+    }
+}
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct CreateAuthorRow {
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "id"))]
+    pub id: i64,
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "name"))]
+    pub name: String,
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "bio"))]
+    pub bio: Option<String>,
+}
+pub struct CreateAuthor<'a> {
+    name: &'a str,
+    bio: Option<&'a str>,
+}
+impl<'a> CreateAuthor<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO authors (
+          name, bio
+) VALUES (
+  $1, $2
+)
+RETURNING id, name, bio";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub const fn builder() -> CreateAuthorBuilder<'a, ((), ())> {
+        CreateAuthorBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CreateAuthorBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Bio> CreateAuthorBuilder<'a, ((), Bio)> {
+    pub fn name(self, name: &'a str) -> CreateAuthorBuilder<'a, (&'a str, Bio)> {
+        let ((), bio) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a, Name> CreateAuthorBuilder<'a, (Name, ())> {
+    pub fn bio(self, bio: Option<&'a str>) -> CreateAuthorBuilder<'a, (Name, Option<&'a str>)> {
+        let (name, ()) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a> CreateAuthorBuilder<'a, (&'a str, Option<&'a str>)> {
+    pub fn build(self) -> CreateAuthor<'a> {
+        let (name, bio) = self.fields;
+        CreateAuthor { name, bio }
+    }
+}
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+pub struct DeleteAuthorRow {}
+pub struct DeleteAuthor {
+    id: i64,
+}
+impl DeleteAuthor {
+    pub const QUERY: &'static str = r"DELETE FROM authors
+WHERE id = $1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl DeleteAuthor {
+    pub const fn builder() -> DeleteAuthorBuilder<'static, ((),)> {
+        DeleteAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct DeleteAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> DeleteAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> DeleteAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        DeleteAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> DeleteAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> DeleteAuthor {
+        let (id,) = self.fields;
+        DeleteAuthor { id }
+    }
+}
+/// Items generated for the feature `tokio-postgres`
+#[cfg(feature = "tokio-postgres")]
+pub mod tokio_postgres_ext {
+    use super::*;
+    /// Methods of [`GetAuthorRow`] for the feature `tokio-postgres`
+    pub trait GetAuthorRowExt: Sized {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error>;
+    }
+    impl GetAuthorRowExt for GetAuthorRow {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+            Ok(Self {
+                id: row.try_get(0)?,
+                name: row.try_get(1)?,
+                bio: row.try_get(2)?,
+            })
+        }
+    }
+    /// Methods of [`GetAuthor`] for the feature `tokio-postgres`
+    #[allow(async_fn_in_trait)]
+    pub trait GetAuthorExt: Sized {
+        async fn query_one(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<GetAuthorRow, tokio_postgres::Error>;
+        async fn query_opt(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<Option<GetAuthorRow>, tokio_postgres::Error>;
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error>;
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 1];
+    }
+    impl GetAuthorExt for GetAuthor {
+        async fn query_one(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<GetAuthorRow, tokio_postgres::Error> {
+            let stmt = self.prepare(client).await?;
+            let row = client.query_one(&stmt, &self.as_params()).await?;
+            GetAuthorRow::from_row(&row)
+        }
+        async fn query_opt(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<Option<GetAuthorRow>, tokio_postgres::Error> {
+            let stmt = self.prepare(client).await?;
+            let row = client.query_opt(&stmt, &self.as_params()).await?;
+            match row {
+                Some(row) => Ok(Some(GetAuthorRow::from_row(&row)?)),
+                None => Ok(None),
+            }
+        }
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+            client.prepare(self.query_str()).await
+        }
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 1] {
+            [&self.id]
+        }
+    }
+    /// Methods of [`ListAuthorsRow`] for the feature `tokio-postgres`
+    pub trait ListAuthorsRowExt: Sized {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error>;
+    }
+    impl ListAuthorsRowExt for ListAuthorsRow {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+            Ok(Self {
+                id: row.try_get(0)?,
+                name: row.try_get(1)?,
+                bio: row.try_get(2)?,
+            })
+        }
+    }
+    /// Methods of [`ListAuthors`] for the feature `tokio-postgres`
+    #[allow(async_fn_in_trait)]
+    pub trait ListAuthorsExt: Sized {
+        async fn query_stream(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::RowStream, tokio_postgres::Error>;
+        async fn query_many(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<Vec<ListAuthorsRow>, tokio_postgres::Error>;
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error>;
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 0];
+    }
+    impl ListAuthorsExt for ListAuthors {
+        async fn query_stream(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::RowStream, tokio_postgres::Error> {
+            let stmt = self.prepare(client).await?;
+            let st = client.query_raw(&stmt, self.as_params()).await?;
+            Ok(st)
+        }
+        async fn query_many(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<Vec<ListAuthorsRow>, tokio_postgres::Error> {
+            let stmt = self.prepare(client).await?;
+            let rows = client.query(&stmt, &self.as_params()).await?;
+            rows.into_iter()
+                .map(|r| ListAuthorsRow::from_row(&r))
+                .collect()
+        }
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+            client.prepare(self.query_str()).await
+        }
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 0] {
+            []
+        }
+    }
+    /// Methods of [`CreateAuthorRow`] for the feature `tokio-postgres`
+    pub trait CreateAuthorRowExt: Sized {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error>;
+    }
+    impl CreateAuthorRowExt for CreateAuthorRow {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+            Ok(Self {
+                id: row.try_get(0)?,
+                name: row.try_get(1)?,
+                bio: row.try_get(2)?,
+            })
+        }
+    }
+    /// Methods of [`CreateAuthor`] for the feature `tokio-postgres`
+    #[allow(async_fn_in_trait)]
+    pub trait CreateAuthorExt<'a>: Sized {
+        async fn query_one(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<CreateAuthorRow, tokio_postgres::Error>;
+        async fn query_opt(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<Option<CreateAuthorRow>, tokio_postgres::Error>;
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error>;
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 2];
+    }
+    impl<'a> CreateAuthorExt<'a> for CreateAuthor<'a> {
+        async fn query_one(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<CreateAuthorRow, tokio_postgres::Error> {
+            let stmt = self.prepare(client).await?;
+            let row = client.query_one(&stmt, &self.as_params()).await?;
+            CreateAuthorRow::from_row(&row)
+        }
+        async fn query_opt(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<Option<CreateAuthorRow>, tokio_postgres::Error> {
+            let stmt = self.prepare(client).await?;
+            let row = client.query_opt(&stmt, &self.as_params()).await?;
+            match row {
+                Some(row) => Ok(Some(CreateAuthorRow::from_row(&row)?)),
+                None => Ok(None),
+            }
+        }
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+            client.prepare(self.query_str()).await
+        }
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 2] {
+            [&self.name, &self.bio]
+        }
+    }
+    /// Methods of [`DeleteAuthorRow`] for the feature `tokio-postgres`
+    pub trait DeleteAuthorRowExt: Sized {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error>;
+    }
+    impl DeleteAuthorRowExt for DeleteAuthorRow {
+        fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+            Ok(Self {})
+        }
+    }
+    /// Methods of [`DeleteAuthor`] for the feature `tokio-postgres`
+    #[allow(async_fn_in_trait)]
+    pub trait DeleteAuthorExt: Sized {
+        async fn execute(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<u64, tokio_postgres::Error>;
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error>;
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 1];
+    }
+    impl DeleteAuthorExt for DeleteAuthor {
+        async fn execute(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<u64, tokio_postgres::Error> {
+            let stmt = self.prepare(client).await?;
+            client.execute(&stmt, &self.as_params()).await
+        }
+        async fn prepare(
+            &self,
+            client: &impl tokio_postgres::GenericClient,
+        ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+            client.prepare(self.query_str()).await
+        }
+        fn as_params(&self) -> [&(dyn ToSql + Sync); 1] {
+            [&self.id]
+        }
+    }
+}
+/// Items generated for the feature `sqlx`
+#[cfg(feature = "sqlx")]
+pub mod sqlx_ext {
+    use super::*;
+    /// Methods of [`GetAuthor`] for the feature `sqlx`
+    pub trait GetAuthorExt: Sized {
+        fn query_as<'a>(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            GetAuthorRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        >;
+        fn query_one<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<GetAuthorRow, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a;
+        fn query_opt<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<Option<GetAuthorRow>, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a;
+    }
+    impl GetAuthorExt for GetAuthor {
+        fn query_as<'a>(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            GetAuthorRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        > {
+            let q = sqlx::query_as(self.query_str());
+            let q = q.bind(self.id);
+            q
+        }
+        fn query_one<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<GetAuthorRow, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+        {
+            async move {
+                let mut conn = conn.acquire().await?;
+                let val = self.query_as().fetch_one(&mut *conn).await?;
+                Ok(val)
+            }
+        }
+        fn query_opt<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<Option<GetAuthorRow>, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+        {
+            async move {
+                let mut conn = conn.acquire().await?;
+                let val = self.query_as().fetch_optional(&mut *conn).await?;
+                Ok(val)
+            }
+        }
+    }
+    /// Methods of [`ListAuthors`] for the feature `sqlx`
+    pub trait ListAuthorsExt: Sized {
+        fn query_as<'a>(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            ListAuthorsRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        >;
+        fn query_many<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<Vec<ListAuthorsRow>, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a;
+    }
+    impl ListAuthorsExt for ListAuthors {
+        fn query_as<'a>(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            ListAuthorsRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        > {
+            let q = sqlx::query_as(self.query_str());
+            q
+        }
+        fn query_many<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<Vec<ListAuthorsRow>, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+        {
+            async move {
+                let mut conn = conn.acquire().await?;
+                let vals = self.query_as().fetch_all(&mut *conn).await?;
+                Ok(vals)
+            }
+        }
+    }
+    /// Methods of [`CreateAuthor`] for the feature `sqlx`
+    pub trait CreateAuthorExt<'a>: Sized {
+        fn query_as(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            CreateAuthorRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        >;
+        fn query_one<'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<CreateAuthorRow, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a;
+        fn query_opt<'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<Option<CreateAuthorRow>, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a;
+    }
+    impl<'a> CreateAuthorExt<'a> for CreateAuthor<'a> {
+        fn query_as(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            CreateAuthorRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        > {
+            let q = sqlx::query_as(self.query_str());
+            let q = q.bind(self.name);
+            let q = q.bind(self.bio);
+            q
+        }
+        fn query_one<'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<CreateAuthorRow, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+        {
+            async move {
+                let mut conn = conn.acquire().await?;
+                let val = self.query_as().fetch_one(&mut *conn).await?;
+                Ok(val)
+            }
+        }
+        fn query_opt<'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<Output = Result<Option<CreateAuthorRow>, sqlx::Error>> + Send + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+        {
+            async move {
+                let mut conn = conn.acquire().await?;
+                let val = self.query_as().fetch_optional(&mut *conn).await?;
+                Ok(val)
+            }
+        }
+    }
+    /// Methods of [`DeleteAuthor`] for the feature `sqlx`
+    pub trait DeleteAuthorExt: Sized {
+        fn query_as<'a>(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            DeleteAuthorRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        >;
+        fn execute<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<
+            Output = Result<<sqlx::Postgres as sqlx::Database>::QueryResult, sqlx::Error>,
+        > + Send
+        + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a;
+    }
+    impl DeleteAuthorExt for DeleteAuthor {
+        fn query_as<'a>(
+            &'a self,
+        ) -> sqlx::query::QueryAs<
+            'a,
+            sqlx::Postgres,
+            DeleteAuthorRow,
+            <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+        > {
+            let q = sqlx::query_as(self.query_str());
+            let q = q.bind(self.id);
+            q
+        }
+        fn execute<'a, 'b, A>(
+            &'a self,
+            conn: A,
+        ) -> impl Future<
+            Output = Result<<sqlx::Postgres as sqlx::Database>::QueryResult, sqlx::Error>,
+        > + Send
+        + 'a
+        where
+            A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+        {
+            async move {
+                let mut conn = conn.acquire().await?;
+                let q = sqlx::query(self.query_str());
+                let q = q.bind(self.id);
+                q.execute(&mut *conn).await
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn connect() -> libsql::Connection {
+        let db = libsql::Builder::new_local(":memory:")
+            .build()
+            .await
+            .unwrap();
+        let conn = db.connect().unwrap();
+        conn.execute_batch(include_str!("../../sqlx-sqlite/schema.sql"))
+            .await
+            .unwrap();
+        conn
+    }
+
+    #[tokio::test]
+    async fn test_authors() {
+        let conn = connect().await;
+
+        let authors = queries::ListAuthors.query_many(&conn).await.unwrap();
+        assert_eq!(authors.len(), 0);
+
+        let affected_rows = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(Some(
+                "Co-author of The C Programming Language and The Go Programming Language",
+            ))
+            .build()
+            .execute(&conn)
+            .await
+            .unwrap();
+        assert_eq!(affected_rows, 1);
+
+        let author = queries::GetAuthor::builder()
+            .id(conn.last_insert_rowid())
+            .build()
+            .query_one(&conn)
+            .await
+            .unwrap();
+        assert_eq!(author.name, "Brian Kernighan");
+
+        queries::CreateAuthor::builder()
+            .name("Dennis Ritchie")
+            .bio(None)
+            .build()
+            .execute(&conn)
+            .await
+            .unwrap();
+
+        let authors = queries::ListAuthors.query_many(&conn).await.unwrap();
+        assert_eq!(authors.len(), 2);
+        assert_eq!(authors[1].bio, None);
+
+        queries::DeleteAuthor::builder()
+            .id(author.id)
+            .build()
+            .execute(&conn)
+            .await
+            .unwrap();
+
+        let deleted = queries::GetAuthor::builder()
+            .id(author.id)
+            .build()
+            .query_opt(&conn)
+            .await
+            .unwrap();
+        assert!(deleted.is_none());
+    }
+}
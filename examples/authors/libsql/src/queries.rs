@@ -0,0 +1,409 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+/// Value of a NUMERIC affinity column, kept in the storage class SQLite returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqliteNumeric {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+impl SqliteNumeric {
+    /// Convert to `f64`. Large integers and non-numeric text may lose precision or return `None`
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(v) => Some(*v as f64),
+            Self::Real(v) => Some(*v),
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+    /// Convert to `i64` if the value is integral
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            Self::Real(v) if v.fract() == 0.0 => Some(*v as i64),
+            Self::Real(_) => None,
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+}
+impl From<i64> for SqliteNumeric {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+impl From<f64> for SqliteNumeric {
+    fn from(value: f64) -> Self {
+        Self::Real(value)
+    }
+}
+impl From<String> for SqliteNumeric {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+/// Decode a column value. `libsql::FromValue` is sealed, so row fields are decoded by this trait
+pub trait FromLibsqlValue: Sized {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self>;
+}
+impl FromLibsqlValue for libsql::Value {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        Ok(value)
+    }
+}
+impl<T: FromLibsqlValue> FromLibsqlValue for Option<T> {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Null => Ok(None),
+            value => T::from_libsql_value(value).map(Some),
+        }
+    }
+}
+impl FromLibsqlValue for i64 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Integer(v) => Ok(v),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for i32 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        i64::from_libsql_value(value)?
+            .try_into()
+            .map_err(|_| libsql::Error::InvalidColumnType)
+    }
+}
+impl FromLibsqlValue for u32 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        i64::from_libsql_value(value)?
+            .try_into()
+            .map_err(|_| libsql::Error::InvalidColumnType)
+    }
+}
+impl FromLibsqlValue for u64 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        i64::from_libsql_value(value)?
+            .try_into()
+            .map_err(|_| libsql::Error::InvalidColumnType)
+    }
+}
+impl FromLibsqlValue for bool {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match i64::from_libsql_value(value)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for f64 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Real(v) => Ok(v),
+            libsql::Value::Integer(v) => Ok(v as f64),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for String {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Text(v) => Ok(v),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for Vec<u8> {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Blob(v) => Ok(v),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for SqliteNumeric {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Integer(v) => Ok(Self::Integer(v)),
+            libsql::Value::Real(v) => Ok(Self::Real(v)),
+            libsql::Value::Text(v) => Ok(Self::Text(v)),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl From<SqliteNumeric> for libsql::Value {
+    fn from(value: SqliteNumeric) -> Self {
+        match value {
+            SqliteNumeric::Integer(v) => Self::Integer(v),
+            SqliteNumeric::Real(v) => Self::Real(v),
+            SqliteNumeric::Text(v) => Self::Text(v),
+        }
+    }
+}
+impl From<&SqliteNumeric> for libsql::Value {
+    fn from(value: &SqliteNumeric) -> Self {
+        value.clone().into()
+    }
+}
+pub struct GetAuthorRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl GetAuthorRow {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, libsql::Error> {
+        Ok(Self {
+            id: FromLibsqlValue::from_libsql_value(row.get_value(0)?)?,
+            name: FromLibsqlValue::from_libsql_value(row.get_value(1)?)?,
+            bio: FromLibsqlValue::from_libsql_value(row.get_value(2)?)?,
+        })
+    }
+}
+pub struct GetAuthor {
+    id: i64,
+}
+impl GetAuthor {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+WHERE id = ? LIMIT 1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetAuthor {
+    pub async fn query_one(
+        &self,
+        conn: &libsql::Connection,
+    ) -> Result<GetAuthorRow, libsql::Error> {
+        let row = conn
+            .prepare(self.query_str())
+            .await?
+            .query_row(self.as_params())
+            .await?;
+        GetAuthorRow::from_row(&row)
+    }
+    pub async fn query_opt(
+        &self,
+        conn: &libsql::Connection,
+    ) -> Result<Option<GetAuthorRow>, libsql::Error> {
+        let mut rows = conn.query(self.query_str(), self.as_params()).await?;
+        match rows.next().await? {
+            Some(row) => Ok(Some(GetAuthorRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub fn as_params(&self) -> libsql::params::Params {
+        libsql::params::Params::Positional(Vec::from([libsql::Value::from(self.id)]))
+    }
+}
+impl GetAuthor {
+    pub const fn builder() -> GetAuthorBuilder<'static, ((),)> {
+        GetAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> GetAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        GetAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> GetAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> GetAuthor {
+        let (id,) = self.fields;
+        GetAuthor { id }
+    }
+}
+pub struct ListAuthorsRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl ListAuthorsRow {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, libsql::Error> {
+        Ok(Self {
+            id: FromLibsqlValue::from_libsql_value(row.get_value(0)?)?,
+            name: FromLibsqlValue::from_libsql_value(row.get_value(1)?)?,
+            bio: FromLibsqlValue::from_libsql_value(row.get_value(2)?)?,
+        })
+    }
+}
+pub struct ListAuthors;
+impl ListAuthors {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+ORDER BY name";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl ListAuthors {
+    pub async fn query_many(
+        &self,
+        conn: &libsql::Connection,
+    ) -> Result<Vec<ListAuthorsRow>, libsql::Error> {
+        let mut rows = conn.query(self.query_str(), self.as_params()).await?;
+        let mut vals = Vec::new();
+        while let Some(row) = rows.next().await? {
+            vals.push(ListAuthorsRow::from_row(&row)?);
+        }
+        Ok(vals)
+    }
+    pub fn as_params(&self) -> libsql::params::Params {
+        libsql::params::Params::None
+    }
+}
+impl ListAuthors {
+    pub const fn builder() -> ListAuthorsBuilder<'static, ()> {
+        ListAuthorsBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct ListAuthorsBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> ListAuthorsBuilder<'a, ()> {
+    pub fn build(self) -> ListAuthors {
+        let () = self.fields;
+        ListAuthors {}
+    }
+}
+pub struct CreateAuthorRow {}
+impl CreateAuthorRow {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, libsql::Error> {
+        Ok(Self {})
+    }
+}
+pub struct CreateAuthor<'a> {
+    name: &'a str,
+    bio: Option<&'a str>,
+}
+impl<'a> CreateAuthor<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO authors (
+  name, bio
+) VALUES (
+  ?, ? 
+)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub async fn execute(&self, conn: &libsql::Connection) -> Result<u64, libsql::Error> {
+        conn.execute(self.query_str(), self.as_params()).await
+    }
+    pub fn as_params(&self) -> libsql::params::Params {
+        libsql::params::Params::Positional(Vec::from([
+            libsql::Value::from(self.name),
+            libsql::Value::from(self.bio),
+        ]))
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub const fn builder() -> CreateAuthorBuilder<'a, ((), ())> {
+        CreateAuthorBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CreateAuthorBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Bio> CreateAuthorBuilder<'a, ((), Bio)> {
+    pub fn name(self, name: &'a str) -> CreateAuthorBuilder<'a, (&'a str, Bio)> {
+        let ((), bio) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a, Name> CreateAuthorBuilder<'a, (Name, ())> {
+    pub fn bio(self, bio: Option<&'a str>) -> CreateAuthorBuilder<'a, (Name, Option<&'a str>)> {
+        let (name, ()) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a> CreateAuthorBuilder<'a, (&'a str, Option<&'a str>)> {
+    pub fn build(self) -> CreateAuthor<'a> {
+        let (name, bio) = self.fields;
+        CreateAuthor { name, bio }
+    }
+}
+pub struct DeleteAuthorRow {}
+impl DeleteAuthorRow {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, libsql::Error> {
+        Ok(Self {})
+    }
+}
+pub struct DeleteAuthor {
+    id: i64,
+}
+impl DeleteAuthor {
+    pub const QUERY: &'static str = r"DELETE FROM authors
+WHERE id = ?";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl DeleteAuthor {
+    pub async fn execute(&self, conn: &libsql::Connection) -> Result<u64, libsql::Error> {
+        conn.execute(self.query_str(), self.as_params()).await
+    }
+    pub fn as_params(&self) -> libsql::params::Params {
+        libsql::params::Params::Positional(Vec::from([libsql::Value::from(self.id)]))
+    }
+}
+impl DeleteAuthor {
+    pub const fn builder() -> DeleteAuthorBuilder<'static, ((),)> {
+        DeleteAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct DeleteAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> DeleteAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> DeleteAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        DeleteAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> DeleteAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> DeleteAuthor {
+        let (id,) = self.fields;
+        DeleteAuthor { id }
+    }
+}
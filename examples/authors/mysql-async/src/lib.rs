@@ -0,0 +1,54 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mysql_async::prelude::Queryable as _;
+    use test_context::test_context;
+    use test_utils::MysqlAsyncContext;
+
+    async fn migrate_db(conn: &mut mysql_async::Conn) {
+        conn.query_drop(include_str!("../../sqlx-mysql/schema.sql"))
+            .await
+            .unwrap();
+    }
+
+    /// port from https://github.com/sqlc-dev/sqlc/blob/v1.29.0/examples/authors/mysql/db_test.go
+    #[test_context(MysqlAsyncContext)]
+    #[tokio::test]
+    async fn test_authors(ctx: &mut MysqlAsyncContext) {
+        let conn = &mut ctx.conn;
+        migrate_db(conn).await;
+
+        let authors = queries::ListAuthors.query_many(conn).await.unwrap();
+        assert_eq!(authors.len(), 0);
+
+        let affected_rows = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(Some(
+                "Co-author of The C Programming Language and The Go Programming Language",
+            ))
+            .build()
+            .execute(conn)
+            .await
+            .unwrap();
+        assert_eq!(affected_rows, 1);
+
+        let fetched_author = queries::GetAuthor::builder()
+            .id(conn.last_insert_id().unwrap().try_into().unwrap())
+            .build()
+            .query_opt(conn)
+            .await
+            .unwrap();
+        assert!(fetched_author.is_some_and(|author| author.name == "Brian Kernighan"));
+
+        let missing_author = queries::GetAuthor::builder()
+            .id(-1)
+            .build()
+            .query_opt(conn)
+            .await
+            .unwrap();
+        assert!(missing_author.is_none());
+    }
+}
@@ -0,0 +1,281 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+pub struct GetAuthorRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl mysql_async::prelude::FromRow for GetAuthorRow {
+    fn from_row_opt(row: mysql_async::Row) -> Result<Self, mysql_async::FromRowError> {
+        Ok(Self {
+            id: match row.get_opt::<i64, _>(0) {
+                Some(Ok(v)) => v,
+                _ => return Err(mysql_async::FromRowError(row)),
+            },
+            name: match row.get_opt::<String, _>(1) {
+                Some(Ok(v)) => v,
+                _ => return Err(mysql_async::FromRowError(row)),
+            },
+            bio: match row.get_opt::<Option<String>, _>(2) {
+                Some(Ok(v)) => v,
+                _ => return Err(mysql_async::FromRowError(row)),
+            },
+        })
+    }
+}
+pub struct GetAuthor {
+    id: i64,
+}
+impl GetAuthor {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+WHERE id = ? LIMIT 1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetAuthor {
+    pub async fn query_opt(
+        &self,
+        conn: &mut impl mysql_async::prelude::Queryable,
+    ) -> Result<Option<GetAuthorRow>, mysql_async::Error> {
+        conn.exec_first(self.query_str(), self.as_params()).await
+    }
+    pub fn as_params(&self) -> mysql_async::Params {
+        mysql_async::Params::Positional(Vec::from([mysql_async::prelude::ToValue::to_value(
+            &self.id,
+        )]))
+    }
+}
+impl GetAuthor {
+    pub const fn builder() -> GetAuthorBuilder<'static, ((),)> {
+        GetAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> GetAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        GetAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> GetAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> GetAuthor {
+        let (id,) = self.fields;
+        GetAuthor { id }
+    }
+}
+pub struct ListAuthorsRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl mysql_async::prelude::FromRow for ListAuthorsRow {
+    fn from_row_opt(row: mysql_async::Row) -> Result<Self, mysql_async::FromRowError> {
+        Ok(Self {
+            id: match row.get_opt::<i64, _>(0) {
+                Some(Ok(v)) => v,
+                _ => return Err(mysql_async::FromRowError(row)),
+            },
+            name: match row.get_opt::<String, _>(1) {
+                Some(Ok(v)) => v,
+                _ => return Err(mysql_async::FromRowError(row)),
+            },
+            bio: match row.get_opt::<Option<String>, _>(2) {
+                Some(Ok(v)) => v,
+                _ => return Err(mysql_async::FromRowError(row)),
+            },
+        })
+    }
+}
+pub struct ListAuthors;
+impl ListAuthors {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+ORDER BY name";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl ListAuthors {
+    pub async fn query_many(
+        &self,
+        conn: &mut impl mysql_async::prelude::Queryable,
+    ) -> Result<Vec<ListAuthorsRow>, mysql_async::Error> {
+        self.query_map(conn, |row| row).await
+    }
+    pub async fn query_map<T, F>(
+        &self,
+        conn: &mut impl mysql_async::prelude::Queryable,
+        f: F,
+    ) -> Result<Vec<T>, mysql_async::Error>
+    where
+        F: FnMut(ListAuthorsRow) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        conn.exec_map(self.query_str(), self.as_params(), f).await
+    }
+    pub fn as_params(&self) -> mysql_async::Params {
+        mysql_async::Params::Empty
+    }
+}
+impl ListAuthors {
+    pub const fn builder() -> ListAuthorsBuilder<'static, ()> {
+        ListAuthorsBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct ListAuthorsBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> ListAuthorsBuilder<'a, ()> {
+    pub fn build(self) -> ListAuthors {
+        let () = self.fields;
+        ListAuthors {}
+    }
+}
+pub struct CreateAuthorRow {}
+impl mysql_async::prelude::FromRow for CreateAuthorRow {
+    fn from_row_opt(row: mysql_async::Row) -> Result<Self, mysql_async::FromRowError> {
+        Ok(Self {})
+    }
+}
+pub struct CreateAuthor<'a> {
+    name: &'a str,
+    bio: Option<&'a str>,
+}
+impl<'a> CreateAuthor<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO authors (
+  name, bio
+) VALUES (
+  ?, ? 
+)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub async fn execute(
+        &self,
+        conn: &mut impl mysql_async::prelude::Queryable,
+    ) -> Result<u64, mysql_async::Error> {
+        let result = conn.exec_iter(self.query_str(), self.as_params()).await?;
+        let affected_rows = result.affected_rows();
+        result.drop_result().await?;
+        Ok(affected_rows)
+    }
+    pub fn as_params(&self) -> mysql_async::Params {
+        mysql_async::Params::Positional(Vec::from([
+            mysql_async::prelude::ToValue::to_value(&self.name),
+            mysql_async::prelude::ToValue::to_value(&self.bio),
+        ]))
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub const fn builder() -> CreateAuthorBuilder<'a, ((), ())> {
+        CreateAuthorBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CreateAuthorBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Bio> CreateAuthorBuilder<'a, ((), Bio)> {
+    pub fn name(self, name: &'a str) -> CreateAuthorBuilder<'a, (&'a str, Bio)> {
+        let ((), bio) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a, Name> CreateAuthorBuilder<'a, (Name, ())> {
+    pub fn bio(self, bio: Option<&'a str>) -> CreateAuthorBuilder<'a, (Name, Option<&'a str>)> {
+        let (name, ()) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a> CreateAuthorBuilder<'a, (&'a str, Option<&'a str>)> {
+    pub fn build(self) -> CreateAuthor<'a> {
+        let (name, bio) = self.fields;
+        CreateAuthor { name, bio }
+    }
+}
+pub struct DeleteAuthorRow {}
+impl mysql_async::prelude::FromRow for DeleteAuthorRow {
+    fn from_row_opt(row: mysql_async::Row) -> Result<Self, mysql_async::FromRowError> {
+        Ok(Self {})
+    }
+}
+pub struct DeleteAuthor {
+    id: i64,
+}
+impl DeleteAuthor {
+    pub const QUERY: &'static str = r"DELETE FROM authors
+WHERE id = ?";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl DeleteAuthor {
+    pub async fn execute(
+        &self,
+        conn: &mut impl mysql_async::prelude::Queryable,
+    ) -> Result<(), mysql_async::Error> {
+        conn.exec_drop(self.query_str(), self.as_params()).await
+    }
+    pub fn as_params(&self) -> mysql_async::Params {
+        mysql_async::Params::Positional(Vec::from([mysql_async::prelude::ToValue::to_value(
+            &self.id,
+        )]))
+    }
+}
+impl DeleteAuthor {
+    pub const fn builder() -> DeleteAuthorBuilder<'static, ((),)> {
+        DeleteAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct DeleteAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> DeleteAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> DeleteAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        DeleteAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> DeleteAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> DeleteAuthor {
+        let (id,) = self.fields;
+        DeleteAuthor { id }
+    }
+}
@@ -0,0 +1,49 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_context::test_context;
+    use test_utils::R2d2PgContext;
+
+    #[test_context(R2d2PgContext)]
+    #[test]
+    fn test_authors(ctx: &mut R2d2PgContext) {
+        let mut conn = ctx.pool.get().unwrap();
+        conn.batch_execute(include_str!("../../tokio-postgres/schema.sql"))
+            .unwrap();
+
+        let authors = queries::ListAuthors.query_many(&mut conn).unwrap();
+        assert_eq!(authors.len(), 0);
+
+        let inserted_author = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(Some(
+                "Co-author of The C Programming Language and The Go Programming Language",
+            ))
+            .build()
+            .query_one(&mut conn)
+            .unwrap();
+
+        // any connection of the pool is accepted, not only the one which inserted
+        let mut other_conn = ctx.pool.get().unwrap();
+        let fetched_author = queries::GetAuthor::builder()
+            .id(inserted_author.id)
+            .build()
+            .query_one(&mut other_conn)
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+
+        let mut tx = conn.transaction().unwrap();
+        queries::DeleteAuthor::builder()
+            .id(inserted_author.id)
+            .build()
+            .execute(&mut tx)
+            .unwrap();
+        tx.commit().unwrap();
+
+        let authors = queries::ListAuthors.query_many(&mut other_conn).unwrap();
+        assert_eq!(authors.len(), 0);
+    }
+}
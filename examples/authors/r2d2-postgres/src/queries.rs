@@ -0,0 +1,345 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+use postgres::GenericClient as _;
+use postgres::types::ToSql;
+/// Client of the query methods, implemented for clients, transactions and pooled connections
+pub trait PostgresClient {
+    type Client: postgres::GenericClient;
+    fn client(&mut self) -> &mut Self::Client;
+}
+impl PostgresClient for postgres::Client {
+    type Client = Self;
+    fn client(&mut self) -> &mut Self {
+        self
+    }
+}
+impl PostgresClient for postgres::Transaction<'_> {
+    type Client = Self;
+    fn client(&mut self) -> &mut Self {
+        self
+    }
+}
+impl<M> PostgresClient for r2d2::PooledConnection<M>
+where
+    M: r2d2::ManageConnection<Connection = postgres::Client>,
+{
+    type Client = postgres::Client;
+    fn client(&mut self) -> &mut postgres::Client {
+        self
+    }
+}
+pub struct GetAuthorRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl GetAuthorRow {
+    pub fn from_row(row: &postgres::Row) -> Result<Self, postgres::Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            name: row.try_get(1)?,
+            bio: row.try_get(2)?,
+        })
+    }
+}
+pub struct GetAuthor {
+    id: i64,
+}
+impl GetAuthor {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+WHERE id = $1 LIMIT 1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetAuthor {
+    pub fn query_one(
+        &self,
+        client: &mut impl PostgresClient,
+    ) -> Result<GetAuthorRow, postgres::Error> {
+        let client = client.client();
+        let stmt = self.prepare(client)?;
+        let row = client.query_one(&stmt, &self.as_params())?;
+        GetAuthorRow::from_row(&row)
+    }
+    pub fn query_opt(
+        &self,
+        client: &mut impl PostgresClient,
+    ) -> Result<Option<GetAuthorRow>, postgres::Error> {
+        let client = client.client();
+        let stmt = self.prepare(client)?;
+        let row = client.query_opt(&stmt, &self.as_params())?;
+        match row {
+            Some(row) => Ok(Some(GetAuthorRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 1] {
+        [&self.id]
+    }
+}
+impl GetAuthor {
+    pub const fn builder() -> GetAuthorBuilder<'static, ((),)> {
+        GetAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> GetAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        GetAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> GetAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> GetAuthor {
+        let (id,) = self.fields;
+        GetAuthor { id }
+    }
+}
+pub struct ListAuthorsRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl ListAuthorsRow {
+    pub fn from_row(row: &postgres::Row) -> Result<Self, postgres::Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            name: row.try_get(1)?,
+            bio: row.try_get(2)?,
+        })
+    }
+}
+pub struct ListAuthors;
+impl ListAuthors {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+ORDER BY name";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl ListAuthors {
+    pub fn query_iter<'row_iter>(
+        &self,
+        client: &'row_iter mut impl PostgresClient,
+    ) -> Result<postgres::RowIter<'row_iter>, postgres::Error> {
+        let client = client.client();
+        let stmt = self.prepare(client)?;
+        client.query_raw(&stmt, self.as_params())
+    }
+    pub fn query_many(
+        &self,
+        client: &mut impl PostgresClient,
+    ) -> Result<Vec<ListAuthorsRow>, postgres::Error> {
+        let client = client.client();
+        let stmt = self.prepare(client)?;
+        let rows = client.query(&stmt, &self.as_params())?;
+        rows.into_iter()
+            .map(|r| ListAuthorsRow::from_row(&r))
+            .collect()
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 0] {
+        []
+    }
+}
+impl ListAuthors {
+    pub const fn builder() -> ListAuthorsBuilder<'static, ()> {
+        ListAuthorsBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct ListAuthorsBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> ListAuthorsBuilder<'a, ()> {
+    pub fn build(self) -> ListAuthors {
+        let () = self.fields;
+        ListAuthors {}
+    }
+}
+pub struct CreateAuthorRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl CreateAuthorRow {
+    pub fn from_row(row: &postgres::Row) -> Result<Self, postgres::Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            name: row.try_get(1)?,
+            bio: row.try_get(2)?,
+        })
+    }
+}
+pub struct CreateAuthor<'a> {
+    name: &'a str,
+    bio: Option<&'a str>,
+}
+impl<'a> CreateAuthor<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO authors (
+          name, bio
+) VALUES (
+  $1, $2
+)
+RETURNING id, name, bio";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub fn query_one(
+        &self,
+        client: &mut impl PostgresClient,
+    ) -> Result<CreateAuthorRow, postgres::Error> {
+        let client = client.client();
+        let stmt = self.prepare(client)?;
+        let row = client.query_one(&stmt, &self.as_params())?;
+        CreateAuthorRow::from_row(&row)
+    }
+    pub fn query_opt(
+        &self,
+        client: &mut impl PostgresClient,
+    ) -> Result<Option<CreateAuthorRow>, postgres::Error> {
+        let client = client.client();
+        let stmt = self.prepare(client)?;
+        let row = client.query_opt(&stmt, &self.as_params())?;
+        match row {
+            Some(row) => Ok(Some(CreateAuthorRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 2] {
+        [&self.name, &self.bio]
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub const fn builder() -> CreateAuthorBuilder<'a, ((), ())> {
+        CreateAuthorBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CreateAuthorBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Bio> CreateAuthorBuilder<'a, ((), Bio)> {
+    pub fn name(self, name: &'a str) -> CreateAuthorBuilder<'a, (&'a str, Bio)> {
+        let ((), bio) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a, Name> CreateAuthorBuilder<'a, (Name, ())> {
+    pub fn bio(self, bio: Option<&'a str>) -> CreateAuthorBuilder<'a, (Name, Option<&'a str>)> {
+        let (name, ()) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a> CreateAuthorBuilder<'a, (&'a str, Option<&'a str>)> {
+    pub fn build(self) -> CreateAuthor<'a> {
+        let (name, bio) = self.fields;
+        CreateAuthor { name, bio }
+    }
+}
+pub struct DeleteAuthorRow {}
+impl DeleteAuthorRow {
+    pub fn from_row(row: &postgres::Row) -> Result<Self, postgres::Error> {
+        Ok(Self {})
+    }
+}
+pub struct DeleteAuthor {
+    id: i64,
+}
+impl DeleteAuthor {
+    pub const QUERY: &'static str = r"DELETE FROM authors
+WHERE id = $1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl DeleteAuthor {
+    pub fn execute(&self, client: &mut impl PostgresClient) -> Result<u64, postgres::Error> {
+        let client = client.client();
+        let stmt = self.prepare(client)?;
+        client.execute(&stmt, &self.as_params())
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 1] {
+        [&self.id]
+    }
+}
+impl DeleteAuthor {
+    pub const fn builder() -> DeleteAuthorBuilder<'static, ((),)> {
+        DeleteAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct DeleteAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> DeleteAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> DeleteAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        DeleteAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> DeleteAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> DeleteAuthor {
+        let (id,) = self.fields;
+        DeleteAuthor { id }
+    }
+}
@@ -0,0 +1,47 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_context::test_context;
+    use test_utils::SqlxAnyContext;
+
+    async fn migrate_db(pool: &sqlx::AnyPool) {
+        sqlx::raw_sql(include_str!("../../sqlx-sqlite/schema.sql"))
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[test_context(SqlxAnyContext)]
+    #[tokio::test]
+    async fn test_authors(ctx: &mut SqlxAnyContext) {
+        let pool = &ctx.pool;
+        migrate_db(pool).await;
+
+        let authors = queries::ListAuthors.query_many(pool).await.unwrap();
+        assert_eq!(authors.len(), 0);
+
+        let result = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(Some(
+                "Co-author of The C Programming Language and The Go Programming Language",
+            ))
+            .build()
+            .execute(pool)
+            .await
+            .unwrap();
+
+        assert_eq!(result.rows_affected(), 1);
+
+        let authors = queries::ListAuthors.query_many(pool).await.unwrap();
+        let fetched_author = queries::GetAuthor::builder()
+            .id(authors[0].id)
+            .build()
+            .query_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+    }
+}
@@ -2,6 +2,71 @@
 //! sqlc version: v1.28.0
 //! sqlc-gen-rust version: v0.1.12
 
+/// Members of a MySQL `SET` column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MySqlSet(pub std::collections::HashSet<String>);
+impl sqlx::Type<sqlx::MySql> for MySqlSet {
+    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+        <str as sqlx::Type<sqlx::MySql>>::type_info()
+    }
+    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+        use sqlx::TypeInfo as _;
+        ty.name() == "SET" || <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
+    }
+}
+impl<'q> sqlx::Encode<'q, sqlx::MySql> for MySqlSet {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        let members = self
+            .0
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        <String as sqlx::Encode<'q, sqlx::MySql>>::encode(members, buf)
+    }
+}
+impl<'r> sqlx::Decode<'r, sqlx::MySql> for MySqlSet {
+    fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let members = <&str as sqlx::Decode<sqlx::MySql>>::decode(value)?;
+        Ok(Self(
+            members
+                .split(',')
+                .filter(|member| !member.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        ))
+    }
+}
+/// Geometry value in MySQL's internal format: a 4-byte SRID followed by WKB.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MySqlGeometry(pub Vec<u8>);
+impl sqlx::Type<sqlx::MySql> for MySqlGeometry {
+    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+        <[u8] as sqlx::Type<sqlx::MySql>>::type_info()
+    }
+    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+        use sqlx::TypeInfo as _;
+        ty.name() == "GEOMETRY" || <[u8] as sqlx::Type<sqlx::MySql>>::compatible(ty)
+    }
+}
+impl<'q> sqlx::Encode<'q, sqlx::MySql> for MySqlGeometry {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <&[u8] as sqlx::Encode<'q, sqlx::MySql>>::encode(self.0.as_slice(), buf)
+    }
+}
+impl<'r> sqlx::Decode<'r, sqlx::MySql> for MySqlGeometry {
+    fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        Ok(Self(
+            <&[u8] as sqlx::Decode<sqlx::MySql>>::decode(value)?.to_vec(),
+        ))
+    }
+}
 #[derive(sqlx::FromRow)]
 pub struct GetAuthorRow {
     #[sqlx(rename = "id")]
@@ -2,6 +2,87 @@
 //! sqlc version: v1.28.0
 //! sqlc-gen-rust version: v0.1.12
 
+/// Value of a NUMERIC affinity column, kept in the storage class SQLite returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqliteNumeric {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+impl SqliteNumeric {
+    /// Convert to `f64`. Large integers and non-numeric text may lose precision or return `None`
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(v) => Some(*v as f64),
+            Self::Real(v) => Some(*v),
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+    /// Convert to `i64` if the value is integral
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            Self::Real(v) if v.fract() == 0.0 => Some(*v as i64),
+            Self::Real(_) => None,
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+}
+impl From<i64> for SqliteNumeric {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+impl From<f64> for SqliteNumeric {
+    fn from(value: f64) -> Self {
+        Self::Real(value)
+    }
+}
+impl From<String> for SqliteNumeric {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl sqlx::Type<sqlx::Sqlite> for SqliteNumeric {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <f64 as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+    fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+        <i64 as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+            || <f64 as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+            || <String as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+    }
+}
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for SqliteNumeric {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        match self {
+            Self::Integer(v) => <i64 as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+            Self::Real(v) => <f64 as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+            Self::Text(v) => <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+        }
+    }
+}
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for SqliteNumeric {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        use sqlx::{TypeInfo as _, ValueRef as _};
+        let storage_class = value.type_info().name().to_owned();
+        match storage_class.as_str() {
+            "INTEGER" => Ok(Self::Integer(<i64 as sqlx::Decode<sqlx::Sqlite>>::decode(
+                value,
+            )?)),
+            "REAL" => Ok(Self::Real(<f64 as sqlx::Decode<sqlx::Sqlite>>::decode(
+                value,
+            )?)),
+            "TEXT" => Ok(Self::Text(<String as sqlx::Decode<sqlx::Sqlite>>::decode(
+                value,
+            )?)),
+            _ => Err(format!("cannot decode {storage_class} as NUMERIC").into()),
+        }
+    }
+}
 #[derive(sqlx::FromRow)]
 pub struct GetAuthorRow {
     #[sqlx(rename = "id")]
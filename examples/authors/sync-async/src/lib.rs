@@ -0,0 +1,61 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_context::test_context;
+    use test_utils::{PgSyncTestContext, PgTokioTestContext};
+
+    #[test_context(PgSyncTestContext)]
+    #[test]
+    fn test_authors_sync(ctx: &mut PgSyncTestContext) {
+        let client = &mut ctx.client;
+        client
+            .batch_execute(include_str!("../../tokio-postgres/schema.sql"))
+            .unwrap();
+
+        let inserted_author = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(None)
+            .build()
+            .query_one(client)
+            .unwrap();
+
+        let fetched_author = queries::GetAuthor::builder()
+            .id(inserted_author.id)
+            .build()
+            .query_one(client)
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+    }
+
+    #[test_context(PgTokioTestContext)]
+    #[tokio::test]
+    async fn test_authors_async(ctx: &mut PgTokioTestContext) {
+        let client = &ctx.client;
+        client
+            .batch_execute(include_str!("../../tokio-postgres/schema.sql"))
+            .await
+            .unwrap();
+
+        let inserted_author = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(None)
+            .build()
+            .query_one_async(client)
+            .await
+            .unwrap();
+
+        let fetched_author = queries::GetAuthor::builder()
+            .id(inserted_author.id)
+            .build()
+            .query_one_async(client)
+            .await
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+
+        let authors = queries::ListAuthors.query_many_async(client).await.unwrap();
+        assert_eq!(authors.len(), 1);
+    }
+}
@@ -0,0 +1,406 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+use tokio_postgres::types::ToSql;
+/// Rows decoded by both the `postgres` and `tokio_postgres` methods.
+/// `postgres::Row` is this `tokio_postgres::Row` only if both crates use the same `tokio-postgres`
+pub trait FromPostgresRow: Sized {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error>;
+}
+pub struct GetAuthorRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl FromPostgresRow for GetAuthorRow {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            name: row.try_get(1)?,
+            bio: row.try_get(2)?,
+        })
+    }
+}
+pub struct GetAuthor {
+    id: i64,
+}
+impl GetAuthor {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+WHERE id = $1 LIMIT 1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetAuthor {
+    pub fn query_one(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<GetAuthorRow, postgres::Error> {
+        let stmt = self.prepare(client)?;
+        let row = client.query_one(&stmt, &self.as_params())?;
+        GetAuthorRow::from_row(&row)
+    }
+    pub fn query_opt(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<Option<GetAuthorRow>, postgres::Error> {
+        let stmt = self.prepare(client)?;
+        let row = client.query_opt(&stmt, &self.as_params())?;
+        match row {
+            Some(row) => Ok(Some(GetAuthorRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub async fn query_one_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<GetAuthorRow, tokio_postgres::Error> {
+        let stmt = self.prepare_async(client).await?;
+        let row = client.query_one(&stmt, &self.as_params()).await?;
+        GetAuthorRow::from_row(&row)
+    }
+    pub async fn query_opt_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<Option<GetAuthorRow>, tokio_postgres::Error> {
+        let stmt = self.prepare_async(client).await?;
+        let row = client.query_opt(&stmt, &self.as_params()).await?;
+        match row {
+            Some(row) => Ok(Some(GetAuthorRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub async fn prepare_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+        client.prepare(self.query_str()).await
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 1] {
+        [&self.id]
+    }
+}
+impl GetAuthor {
+    pub const fn builder() -> GetAuthorBuilder<'static, ((),)> {
+        GetAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> GetAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        GetAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> GetAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> GetAuthor {
+        let (id,) = self.fields;
+        GetAuthor { id }
+    }
+}
+pub struct ListAuthorsRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl FromPostgresRow for ListAuthorsRow {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            name: row.try_get(1)?,
+            bio: row.try_get(2)?,
+        })
+    }
+}
+pub struct ListAuthors;
+impl ListAuthors {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+ORDER BY name";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl ListAuthors {
+    pub fn query_iter<'row_iter>(
+        &self,
+        client: &'row_iter mut impl postgres::GenericClient,
+    ) -> Result<postgres::RowIter<'row_iter>, postgres::Error> {
+        let stmt = self.prepare(client)?;
+        client.query_raw(&stmt, self.as_params())
+    }
+    pub fn query_many(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<Vec<ListAuthorsRow>, postgres::Error> {
+        let stmt = self.prepare(client)?;
+        let rows = client.query(&stmt, &self.as_params())?;
+        rows.into_iter()
+            .map(|r| ListAuthorsRow::from_row(&r))
+            .collect()
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub async fn query_stream_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<tokio_postgres::RowStream, tokio_postgres::Error> {
+        let stmt = self.prepare_async(client).await?;
+        let st = client.query_raw(&stmt, self.as_params()).await?;
+        Ok(st)
+    }
+    pub async fn query_many_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<Vec<ListAuthorsRow>, tokio_postgres::Error> {
+        let stmt = self.prepare_async(client).await?;
+        let rows = client.query(&stmt, &self.as_params()).await?;
+        rows.into_iter()
+            .map(|r| ListAuthorsRow::from_row(&r))
+            .collect()
+    }
+    pub async fn prepare_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+        client.prepare(self.query_str()).await
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 0] {
+        []
+    }
+}
+impl ListAuthors {
+    pub const fn builder() -> ListAuthorsBuilder<'static, ()> {
+        ListAuthorsBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct ListAuthorsBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> ListAuthorsBuilder<'a, ()> {
+    pub fn build(self) -> ListAuthors {
+        let () = self.fields;
+        ListAuthors {}
+    }
+}
+pub struct CreateAuthorRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl FromPostgresRow for CreateAuthorRow {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+        Ok(Self {
+            id: row.try_get(0)?,
+            name: row.try_get(1)?,
+            bio: row.try_get(2)?,
+        })
+    }
+}
+pub struct CreateAuthor<'a> {
+    name: &'a str,
+    bio: Option<&'a str>,
+}
+impl<'a> CreateAuthor<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO authors (
+          name, bio
+) VALUES (
+  $1, $2
+)
+RETURNING id, name, bio";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub fn query_one(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<CreateAuthorRow, postgres::Error> {
+        let stmt = self.prepare(client)?;
+        let row = client.query_one(&stmt, &self.as_params())?;
+        CreateAuthorRow::from_row(&row)
+    }
+    pub fn query_opt(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<Option<CreateAuthorRow>, postgres::Error> {
+        let stmt = self.prepare(client)?;
+        let row = client.query_opt(&stmt, &self.as_params())?;
+        match row {
+            Some(row) => Ok(Some(CreateAuthorRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub async fn query_one_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<CreateAuthorRow, tokio_postgres::Error> {
+        let stmt = self.prepare_async(client).await?;
+        let row = client.query_one(&stmt, &self.as_params()).await?;
+        CreateAuthorRow::from_row(&row)
+    }
+    pub async fn query_opt_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<Option<CreateAuthorRow>, tokio_postgres::Error> {
+        let stmt = self.prepare_async(client).await?;
+        let row = client.query_opt(&stmt, &self.as_params()).await?;
+        match row {
+            Some(row) => Ok(Some(CreateAuthorRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub async fn prepare_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+        client.prepare(self.query_str()).await
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 2] {
+        [&self.name, &self.bio]
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub const fn builder() -> CreateAuthorBuilder<'a, ((), ())> {
+        CreateAuthorBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CreateAuthorBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Bio> CreateAuthorBuilder<'a, ((), Bio)> {
+    pub fn name(self, name: &'a str) -> CreateAuthorBuilder<'a, (&'a str, Bio)> {
+        let ((), bio) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a, Name> CreateAuthorBuilder<'a, (Name, ())> {
+    pub fn bio(self, bio: Option<&'a str>) -> CreateAuthorBuilder<'a, (Name, Option<&'a str>)> {
+        let (name, ()) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a> CreateAuthorBuilder<'a, (&'a str, Option<&'a str>)> {
+    pub fn build(self) -> CreateAuthor<'a> {
+        let (name, bio) = self.fields;
+        CreateAuthor { name, bio }
+    }
+}
+pub struct DeleteAuthorRow {}
+impl FromPostgresRow for DeleteAuthorRow {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+        Ok(Self {})
+    }
+}
+pub struct DeleteAuthor {
+    id: i64,
+}
+impl DeleteAuthor {
+    pub const QUERY: &'static str = r"DELETE FROM authors
+WHERE id = $1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl DeleteAuthor {
+    pub fn execute(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<u64, postgres::Error> {
+        let stmt = self.prepare(client)?;
+        client.execute(&stmt, &self.as_params())
+    }
+    pub fn prepare(
+        &self,
+        client: &mut impl postgres::GenericClient,
+    ) -> Result<postgres::Statement, postgres::Error> {
+        client.prepare(self.query_str())
+    }
+    pub async fn execute_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<u64, tokio_postgres::Error> {
+        let stmt = self.prepare_async(client).await?;
+        client.execute(&stmt, &self.as_params()).await
+    }
+    pub async fn prepare_async(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+        client.prepare(self.query_str()).await
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 1] {
+        [&self.id]
+    }
+}
+impl DeleteAuthor {
+    pub const fn builder() -> DeleteAuthorBuilder<'static, ((),)> {
+        DeleteAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct DeleteAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> DeleteAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> DeleteAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        DeleteAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> DeleteAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> DeleteAuthor {
+        let (id,) = self.fields;
+        DeleteAuthor { id }
+    }
+}
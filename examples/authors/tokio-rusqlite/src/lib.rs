@@ -0,0 +1,52 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_context::test_context;
+    use test_utils::TokioRusqliteContext;
+
+    async fn migrate_db(conn: &tokio_rusqlite::Connection) {
+        conn.call(|conn| {
+            conn.execute_batch(include_str!("../../sqlx-sqlite/schema.sql"))?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[test_context(TokioRusqliteContext)]
+    #[tokio::test]
+    async fn test_authors(ctx: &mut TokioRusqliteContext) {
+        let conn = &ctx.conn;
+        migrate_db(conn).await;
+
+        let authors = queries::ListAuthors.query_many(conn).await.unwrap();
+        assert_eq!(authors.len(), 0);
+
+        let affected_rows = queries::CreateAuthor::builder()
+            .name("Brian Kernighan")
+            .bio(Some(
+                "Co-author of The C Programming Language and The Go Programming Language",
+            ))
+            .build()
+            .execute(conn)
+            .await
+            .unwrap();
+
+        assert_eq!(affected_rows, 1);
+
+        let id = conn
+            .call(|conn| Ok(conn.last_insert_rowid()))
+            .await
+            .unwrap();
+        let fetched_author = queries::GetAuthor::builder()
+            .id(id)
+            .build()
+            .query_one(conn)
+            .await
+            .unwrap();
+        assert_eq!(fetched_author.name, "Brian Kernighan");
+    }
+}
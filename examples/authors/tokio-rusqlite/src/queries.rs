@@ -0,0 +1,395 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+/// Value of a NUMERIC affinity column, kept in the storage class SQLite returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqliteNumeric {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+impl SqliteNumeric {
+    /// Convert to `f64`. Large integers and non-numeric text may lose precision or return `None`
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(v) => Some(*v as f64),
+            Self::Real(v) => Some(*v),
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+    /// Convert to `i64` if the value is integral
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            Self::Real(v) if v.fract() == 0.0 => Some(*v as i64),
+            Self::Real(_) => None,
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+}
+impl From<i64> for SqliteNumeric {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+impl From<f64> for SqliteNumeric {
+    fn from(value: f64) -> Self {
+        Self::Real(value)
+    }
+}
+impl From<String> for SqliteNumeric {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl rusqlite::types::FromSql for SqliteNumeric {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        match value {
+            rusqlite::types::ValueRef::Integer(v) => Ok(Self::Integer(v)),
+            rusqlite::types::ValueRef::Real(v) => Ok(Self::Real(v)),
+            rusqlite::types::ValueRef::Text(_) => value.as_str().map(|v| Self::Text(v.to_owned())),
+            _ => Err(rusqlite::types::FromSqlError::InvalidType),
+        }
+    }
+}
+impl rusqlite::ToSql for SqliteNumeric {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        match self {
+            Self::Integer(v) => v.to_sql(),
+            Self::Real(v) => v.to_sql(),
+            Self::Text(v) => v.to_sql(),
+        }
+    }
+}
+pub trait RusqliteClient {
+    fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>>;
+}
+impl RusqliteClient for rusqlite::Connection {
+    fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>> {
+        self.prepare(sql)
+    }
+}
+impl RusqliteClient for rusqlite::Transaction<'_> {
+    fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>> {
+        rusqlite::Connection::prepare(&self, sql)
+    }
+}
+impl RusqliteClient for rusqlite::Savepoint<'_> {
+    fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>> {
+        rusqlite::Connection::prepare(self, sql)
+    }
+}
+pub struct GetAuthorRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl GetAuthorRow {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            bio: row.get(2)?,
+        })
+    }
+}
+pub struct GetAuthor {
+    id: i64,
+}
+impl GetAuthor {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+WHERE id = ? LIMIT 1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetAuthor {
+    pub async fn query_one(
+        &self,
+        conn: &tokio_rusqlite::Connection,
+    ) -> Result<GetAuthorRow, tokio_rusqlite::Error> {
+        let (id,) = (self.id,);
+        conn.call(move |conn| {
+            let query = GetAuthor { id };
+            let result: rusqlite::Result<GetAuthorRow> = query
+                .prepare(conn)?
+                .query_row(query.as_params(), GetAuthorRow::from_row);
+            result.map_err(Into::into)
+        })
+        .await
+    }
+    pub async fn query_opt(
+        &self,
+        conn: &tokio_rusqlite::Connection,
+    ) -> Result<Option<GetAuthorRow>, tokio_rusqlite::Error> {
+        let (id,) = (self.id,);
+        conn.call(move |conn| {
+            let query = GetAuthor { id };
+            let result: rusqlite::Result<Option<GetAuthorRow>> = query
+                .prepare(conn)?
+                .query_map(query.as_params(), GetAuthorRow::from_row)?
+                .next()
+                .transpose();
+            result.map_err(Into::into)
+        })
+        .await
+    }
+    pub fn prepare<'conn>(
+        &self,
+        client: &'conn impl RusqliteClient,
+    ) -> rusqlite::Result<rusqlite::Statement<'conn>> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl rusqlite::Params {
+        (self.id,)
+    }
+}
+impl GetAuthor {
+    pub const fn builder() -> GetAuthorBuilder<'static, ((),)> {
+        GetAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> GetAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        GetAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> GetAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> GetAuthor {
+        let (id,) = self.fields;
+        GetAuthor { id }
+    }
+}
+pub struct ListAuthorsRow {
+    pub id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+}
+impl ListAuthorsRow {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            bio: row.get(2)?,
+        })
+    }
+}
+pub struct ListAuthors;
+impl ListAuthors {
+    pub const QUERY: &'static str = r"SELECT id, name, bio FROM authors
+ORDER BY name";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl ListAuthors {
+    pub async fn query_many(
+        &self,
+        conn: &tokio_rusqlite::Connection,
+    ) -> Result<Vec<ListAuthorsRow>, tokio_rusqlite::Error> {
+        let () = ();
+        conn.call(move |conn| {
+            let query = ListAuthors {};
+            let result: rusqlite::Result<Vec<ListAuthorsRow>> = query
+                .prepare(conn)?
+                .query_map(query.as_params(), ListAuthorsRow::from_row)?
+                .collect();
+            result.map_err(Into::into)
+        })
+        .await
+    }
+    pub fn prepare<'conn>(
+        &self,
+        client: &'conn impl RusqliteClient,
+    ) -> rusqlite::Result<rusqlite::Statement<'conn>> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl rusqlite::Params {
+        ()
+    }
+}
+impl ListAuthors {
+    pub const fn builder() -> ListAuthorsBuilder<'static, ()> {
+        ListAuthorsBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct ListAuthorsBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> ListAuthorsBuilder<'a, ()> {
+    pub fn build(self) -> ListAuthors {
+        let () = self.fields;
+        ListAuthors {}
+    }
+}
+pub struct CreateAuthorRow {}
+impl CreateAuthorRow {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {})
+    }
+}
+pub struct CreateAuthor<'a> {
+    name: &'a str,
+    bio: Option<&'a str>,
+}
+impl<'a> CreateAuthor<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO authors (
+  name, bio
+) VALUES (
+  ?, ? 
+)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub async fn execute(
+        &self,
+        conn: &tokio_rusqlite::Connection,
+    ) -> Result<usize, tokio_rusqlite::Error> {
+        let (name, bio) = (
+            ToOwned::to_owned(self.name),
+            self.bio.map(ToOwned::to_owned),
+        );
+        conn.call(move |conn| {
+            let query = CreateAuthor {
+                name: &name,
+                bio: bio.as_ref().map(std::borrow::Borrow::borrow),
+            };
+            let result: rusqlite::Result<usize> = query.prepare(conn)?.execute(query.as_params());
+            result.map_err(Into::into)
+        })
+        .await
+    }
+    pub fn prepare<'conn>(
+        &self,
+        client: &'conn impl RusqliteClient,
+    ) -> rusqlite::Result<rusqlite::Statement<'conn>> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl rusqlite::Params {
+        (self.name, self.bio)
+    }
+}
+impl<'a> CreateAuthor<'a> {
+    pub const fn builder() -> CreateAuthorBuilder<'a, ((), ())> {
+        CreateAuthorBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CreateAuthorBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Bio> CreateAuthorBuilder<'a, ((), Bio)> {
+    pub fn name(self, name: &'a str) -> CreateAuthorBuilder<'a, (&'a str, Bio)> {
+        let ((), bio) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a, Name> CreateAuthorBuilder<'a, (Name, ())> {
+    pub fn bio(self, bio: Option<&'a str>) -> CreateAuthorBuilder<'a, (Name, Option<&'a str>)> {
+        let (name, ()) = self.fields;
+        let _phantom = self._phantom;
+        CreateAuthorBuilder {
+            fields: (name, bio),
+            _phantom,
+        }
+    }
+}
+impl<'a> CreateAuthorBuilder<'a, (&'a str, Option<&'a str>)> {
+    pub fn build(self) -> CreateAuthor<'a> {
+        let (name, bio) = self.fields;
+        CreateAuthor { name, bio }
+    }
+}
+pub struct DeleteAuthorRow {}
+impl DeleteAuthorRow {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {})
+    }
+}
+pub struct DeleteAuthor {
+    id: i64,
+}
+impl DeleteAuthor {
+    pub const QUERY: &'static str = r"DELETE FROM authors
+WHERE id = ?";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl DeleteAuthor {
+    pub async fn execute(
+        &self,
+        conn: &tokio_rusqlite::Connection,
+    ) -> Result<usize, tokio_rusqlite::Error> {
+        let (id,) = (self.id,);
+        conn.call(move |conn| {
+            let query = DeleteAuthor { id };
+            let result: rusqlite::Result<usize> = query.prepare(conn)?.execute(query.as_params());
+            result.map_err(Into::into)
+        })
+        .await
+    }
+    pub fn prepare<'conn>(
+        &self,
+        client: &'conn impl RusqliteClient,
+    ) -> rusqlite::Result<rusqlite::Statement<'conn>> {
+        client.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl rusqlite::Params {
+        (self.id,)
+    }
+}
+impl DeleteAuthor {
+    pub const fn builder() -> DeleteAuthorBuilder<'static, ((),)> {
+        DeleteAuthorBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct DeleteAuthorBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> DeleteAuthorBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> DeleteAuthorBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        DeleteAuthorBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> DeleteAuthorBuilder<'a, (i64,)> {
+    pub fn build(self) -> DeleteAuthor {
+        let (id,) = self.fields;
+        DeleteAuthor { id }
+    }
+}
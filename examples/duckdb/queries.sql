@@ -0,0 +1,25 @@
+-- name: GetEvent :one
+SELECT id, amount, created_at, tags FROM events
+WHERE id = $1;
+
+-- name: ListEventsByTags :many
+SELECT id, amount, created_at, tags FROM events
+WHERE tags && $1::text[]
+ORDER BY id;
+
+-- name: CreateEvent :exec
+INSERT INTO events (
+  id, amount, created_at, tags
+) VALUES (
+  $1, $2, $3, $4
+);
+
+-- name: CopyEvents :copyfrom
+INSERT INTO events (
+  id, amount, created_at
+) VALUES (
+  $1, $2, $3
+);
+
+-- name: DeleteEvents :execrows
+DELETE FROM events;
@@ -0,0 +1,6 @@
+CREATE TABLE events (
+  id         BIGINT        PRIMARY KEY,
+  amount     NUMERIC(18,3) NOT NULL,
+  created_at TIMESTAMP     NOT NULL,
+  tags       TEXT[]
+);
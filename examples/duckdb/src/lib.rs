@@ -0,0 +1,94 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr as _;
+
+    fn connect() -> duckdb::Connection {
+        let conn = duckdb::Connection::open_in_memory().unwrap();
+        conn.execute_batch(include_str!("../schema.sql")).unwrap();
+        conn
+    }
+
+    fn created_at() -> chrono::NaiveDateTime {
+        chrono::NaiveDateTime::from_str("2025-01-23T04:05:06").unwrap()
+    }
+
+    #[test]
+    fn test_events() {
+        let conn = connect();
+        let amount = rust_decimal::Decimal::from_str("12.345").unwrap();
+        let tags = vec!["click".to_string(), "mobile".to_string()];
+
+        queries::CreateEvent::builder()
+            .id(1)
+            .amount(amount)
+            .created_at(created_at())
+            .tags(Some(tags.as_slice()))
+            .build()
+            .execute(&conn)
+            .unwrap();
+        queries::CreateEvent::builder()
+            .id(2)
+            .amount(amount)
+            .created_at(created_at())
+            .tags(None)
+            .build()
+            .execute(&conn)
+            .unwrap();
+
+        let event = queries::GetEvent::builder()
+            .id(1)
+            .build()
+            .query_one(&conn)
+            .unwrap();
+        assert_eq!(event.amount, amount);
+        assert_eq!(event.created_at, created_at());
+        assert_eq!(event.tags, Some(tags));
+
+        let missing = queries::GetEvent::builder()
+            .id(3)
+            .build()
+            .query_opt(&conn)
+            .unwrap();
+        assert!(missing.is_none());
+
+        let events = queries::ListEventsByTags::builder()
+            .tags(&["mobile".to_string()])
+            .build()
+            .query_many(&conn)
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, 1);
+    }
+
+    #[test]
+    fn test_copy_from() {
+        let conn = connect();
+        // `CopyEvents` lists only some of the columns of `events`
+        let rows = (0..1000)
+            .map(|id| {
+                queries::CopyEvents::builder()
+                    .id(id)
+                    .amount(rust_decimal::Decimal::from(id))
+                    .created_at(created_at())
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        queries::CopyEvents::copy_from(&conn, &rows).unwrap();
+
+        let event = queries::GetEvent::builder()
+            .id(999)
+            .build()
+            .query_one(&conn)
+            .unwrap();
+        assert_eq!(event.amount, rust_decimal::Decimal::from(999));
+        assert_eq!(event.created_at, created_at());
+        assert_eq!(event.tags, None);
+
+        let deleted = queries::DeleteEvents.execute(&conn).unwrap();
+        assert_eq!(deleted, 1000);
+    }
+}
@@ -0,0 +1,470 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+/// Convert a parameter into an owned value to nest it in a `LIST` or `STRUCT`
+fn to_duckdb_value(value: &dyn duckdb::ToSql) -> duckdb::Result<duckdb::types::Value> {
+    match value.to_sql()? {
+        duckdb::types::ToSqlOutput::Borrowed(v) => Ok(duckdb::types::Value::from(v)),
+        duckdb::types::ToSqlOutput::Owned(v) => Ok(v),
+    }
+}
+/// Value of a DuckDB `LIST` column or parameter
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuckdbList<T>(pub Vec<T>);
+impl<T: duckdb::types::FromSql> duckdb::types::FromSql for DuckdbList<T> {
+    fn column_result(value: duckdb::types::ValueRef<'_>) -> duckdb::types::FromSqlResult<Self> {
+        match duckdb::types::Value::from(value) {
+            duckdb::types::Value::List(values) => values
+                .iter()
+                .map(|v| T::column_result(duckdb::types::ValueRef::from(v)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self),
+            _ => Err(duckdb::types::FromSqlError::InvalidType),
+        }
+    }
+}
+impl<T: duckdb::ToSql> duckdb::ToSql for DuckdbList<T> {
+    fn to_sql(&self) -> duckdb::Result<duckdb::types::ToSqlOutput<'_>> {
+        let values = self
+            .0
+            .iter()
+            .map(|v| to_duckdb_value(v))
+            .collect::<duckdb::Result<Vec<_>>>()?;
+        Ok(duckdb::types::ToSqlOutput::Owned(
+            duckdb::types::Value::List(values),
+        ))
+    }
+}
+pub struct GetEventRow {
+    pub id: i64,
+    pub amount: rust_decimal::Decimal,
+    pub created_at: chrono::NaiveDateTime,
+    pub tags: Option<Vec<String>>,
+}
+impl GetEventRow {
+    pub fn from_row(row: &duckdb::Row) -> duckdb::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            amount: row.get(1)?,
+            created_at: row.get(2)?,
+            tags: row.get::<_, Option<DuckdbList<_>>>(3)?.map(|v| v.0),
+        })
+    }
+}
+pub struct GetEvent {
+    id: i64,
+}
+impl GetEvent {
+    pub const QUERY: &'static str = r"SELECT id, amount, created_at, tags FROM events
+WHERE id = $1";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetEvent {
+    pub fn query_one(&self, conn: &duckdb::Connection) -> duckdb::Result<GetEventRow> {
+        self.prepare(conn)?
+            .query_row(self.as_params(), GetEventRow::from_row)
+    }
+    pub fn query_opt(&self, conn: &duckdb::Connection) -> duckdb::Result<Option<GetEventRow>> {
+        self.prepare(conn)?
+            .query_map(self.as_params(), GetEventRow::from_row)?
+            .next()
+            .transpose()
+    }
+    pub fn prepare<'conn>(
+        &self,
+        conn: &'conn duckdb::Connection,
+    ) -> duckdb::Result<duckdb::Statement<'conn>> {
+        conn.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl duckdb::Params + '_ {
+        duckdb::params_from_iter(self.param_values())
+    }
+    fn param_values(&self) -> [&dyn duckdb::ToSql; 1] {
+        [&self.id]
+    }
+}
+impl GetEvent {
+    pub const fn builder() -> GetEventBuilder<'static, ((),)> {
+        GetEventBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetEventBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetEventBuilder<'a, ((),)> {
+    pub fn id(self, id: i64) -> GetEventBuilder<'a, (i64,)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        GetEventBuilder {
+            fields: (id,),
+            _phantom,
+        }
+    }
+}
+impl<'a> GetEventBuilder<'a, (i64,)> {
+    pub fn build(self) -> GetEvent {
+        let (id,) = self.fields;
+        GetEvent { id }
+    }
+}
+pub struct ListEventsByTagsRow {
+    pub id: i64,
+    pub amount: rust_decimal::Decimal,
+    pub created_at: chrono::NaiveDateTime,
+    pub tags: Option<Vec<String>>,
+}
+impl ListEventsByTagsRow {
+    pub fn from_row(row: &duckdb::Row) -> duckdb::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            amount: row.get(1)?,
+            created_at: row.get(2)?,
+            tags: row.get::<_, Option<DuckdbList<_>>>(3)?.map(|v| v.0),
+        })
+    }
+}
+pub struct ListEventsByTags<'a> {
+    tags: &'a [String],
+}
+impl<'a> ListEventsByTags<'a> {
+    pub const QUERY: &'static str = r"SELECT id, amount, created_at, tags FROM events
+WHERE tags && $1::text[]
+ORDER BY id";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> ListEventsByTags<'a> {
+    pub fn query_many(
+        &self,
+        conn: &duckdb::Connection,
+    ) -> duckdb::Result<Vec<ListEventsByTagsRow>> {
+        self.prepare(conn)?
+            .query_map(self.as_params(), ListEventsByTagsRow::from_row)?
+            .collect()
+    }
+    pub fn prepare<'conn>(
+        &self,
+        conn: &'conn duckdb::Connection,
+    ) -> duckdb::Result<duckdb::Statement<'conn>> {
+        conn.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl duckdb::Params + '_ {
+        duckdb::params_from_iter(self.param_values())
+    }
+    fn param_values(&self) -> [Box<dyn duckdb::ToSql + '_>; 1] {
+        [Box::new(DuckdbList(self.tags.iter().collect::<Vec<_>>()))]
+    }
+}
+impl<'a> ListEventsByTags<'a> {
+    pub const fn builder() -> ListEventsByTagsBuilder<'a, ((),)> {
+        ListEventsByTagsBuilder {
+            fields: ((),),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct ListEventsByTagsBuilder<'a, Fields = ((),)> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> ListEventsByTagsBuilder<'a, ((),)> {
+    pub fn tags(self, tags: &'a [String]) -> ListEventsByTagsBuilder<'a, (&'a [String],)> {
+        let ((),) = self.fields;
+        let _phantom = self._phantom;
+        ListEventsByTagsBuilder {
+            fields: (tags,),
+            _phantom,
+        }
+    }
+}
+impl<'a> ListEventsByTagsBuilder<'a, (&'a [String],)> {
+    pub fn build(self) -> ListEventsByTags<'a> {
+        let (tags,) = self.fields;
+        ListEventsByTags { tags }
+    }
+}
+pub struct CreateEventRow {}
+impl CreateEventRow {
+    pub fn from_row(row: &duckdb::Row) -> duckdb::Result<Self> {
+        Ok(Self {})
+    }
+}
+pub struct CreateEvent<'a> {
+    id: i64,
+    amount: rust_decimal::Decimal,
+    created_at: chrono::NaiveDateTime,
+    tags: Option<&'a [String]>,
+}
+impl<'a> CreateEvent<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO events (
+  id, amount, created_at, tags
+) VALUES (
+  $1, $2, $3, $4
+)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> CreateEvent<'a> {
+    pub fn execute(&self, conn: &duckdb::Connection) -> duckdb::Result<usize> {
+        self.prepare(conn)?.execute(self.as_params())
+    }
+    pub fn prepare<'conn>(
+        &self,
+        conn: &'conn duckdb::Connection,
+    ) -> duckdb::Result<duckdb::Statement<'conn>> {
+        conn.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl duckdb::Params + '_ {
+        duckdb::params_from_iter(self.param_values())
+    }
+    fn param_values(&self) -> [Box<dyn duckdb::ToSql + '_>; 4] {
+        [
+            Box::new(&self.id),
+            Box::new(&self.amount),
+            Box::new(&self.created_at),
+            Box::new(self.tags.map(|v| DuckdbList(v.iter().collect::<Vec<_>>()))),
+        ]
+    }
+}
+impl<'a> CreateEvent<'a> {
+    pub const fn builder() -> CreateEventBuilder<'a, ((), (), (), ())> {
+        CreateEventBuilder {
+            fields: ((), (), (), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CreateEventBuilder<'a, Fields = ((), (), (), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Amount, CreatedAt, Tags> CreateEventBuilder<'a, ((), Amount, CreatedAt, Tags)> {
+    pub fn id(self, id: i64) -> CreateEventBuilder<'a, (i64, Amount, CreatedAt, Tags)> {
+        let ((), amount, created_at, tags) = self.fields;
+        let _phantom = self._phantom;
+        CreateEventBuilder {
+            fields: (id, amount, created_at, tags),
+            _phantom,
+        }
+    }
+}
+impl<'a, Id, CreatedAt, Tags> CreateEventBuilder<'a, (Id, (), CreatedAt, Tags)> {
+    pub fn amount(
+        self,
+        amount: rust_decimal::Decimal,
+    ) -> CreateEventBuilder<'a, (Id, rust_decimal::Decimal, CreatedAt, Tags)> {
+        let (id, (), created_at, tags) = self.fields;
+        let _phantom = self._phantom;
+        CreateEventBuilder {
+            fields: (id, amount, created_at, tags),
+            _phantom,
+        }
+    }
+}
+impl<'a, Id, Amount, Tags> CreateEventBuilder<'a, (Id, Amount, (), Tags)> {
+    pub fn created_at(
+        self,
+        created_at: chrono::NaiveDateTime,
+    ) -> CreateEventBuilder<'a, (Id, Amount, chrono::NaiveDateTime, Tags)> {
+        let (id, amount, (), tags) = self.fields;
+        let _phantom = self._phantom;
+        CreateEventBuilder {
+            fields: (id, amount, created_at, tags),
+            _phantom,
+        }
+    }
+}
+impl<'a, Id, Amount, CreatedAt> CreateEventBuilder<'a, (Id, Amount, CreatedAt, ())> {
+    pub fn tags(
+        self,
+        tags: Option<&'a [String]>,
+    ) -> CreateEventBuilder<'a, (Id, Amount, CreatedAt, Option<&'a [String]>)> {
+        let (id, amount, created_at, ()) = self.fields;
+        let _phantom = self._phantom;
+        CreateEventBuilder {
+            fields: (id, amount, created_at, tags),
+            _phantom,
+        }
+    }
+}
+impl<'a>
+    CreateEventBuilder<
+        'a,
+        (
+            i64,
+            rust_decimal::Decimal,
+            chrono::NaiveDateTime,
+            Option<&'a [String]>,
+        ),
+    >
+{
+    pub fn build(self) -> CreateEvent<'a> {
+        let (id, amount, created_at, tags) = self.fields;
+        CreateEvent {
+            id,
+            amount,
+            created_at,
+            tags,
+        }
+    }
+}
+pub struct CopyEventsRow {}
+impl CopyEventsRow {
+    pub fn from_row(row: &duckdb::Row) -> duckdb::Result<Self> {
+        Ok(Self {})
+    }
+}
+pub struct CopyEvents {
+    id: i64,
+    amount: rust_decimal::Decimal,
+    created_at: chrono::NaiveDateTime,
+}
+impl CopyEvents {
+    pub const QUERY: &'static str =
+        r"COPY events (id,amount,created_at) FROM STDIN (FORMAT BINARY)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl CopyEvents {
+    /// Append the row to an `Appender` of the inserted columns
+    pub fn append(&self, appender: &mut duckdb::Appender<'_>) -> duckdb::Result<()> {
+        appender.append_row(duckdb::appender_params_from_iter(self.param_values()))
+    }
+    /// Bulk load rows with `Appender`, which is much faster than `INSERT` per row.
+    /// Columns missing from the `INSERT` get their default values
+    pub fn copy_from(conn: &duckdb::Connection, rows: &[Self]) -> duckdb::Result<()> {
+        let mut appender = conn.appender_with_columns("events", &["id", "amount", "created_at"])?;
+        for row in rows {
+            row.append(&mut appender)?;
+        }
+        appender.flush()
+    }
+    pub fn prepare<'conn>(
+        &self,
+        conn: &'conn duckdb::Connection,
+    ) -> duckdb::Result<duckdb::Statement<'conn>> {
+        conn.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl duckdb::Params + '_ {
+        duckdb::params_from_iter(self.param_values())
+    }
+    fn param_values(&self) -> [&dyn duckdb::ToSql; 3] {
+        [&self.id, &self.amount, &self.created_at]
+    }
+}
+impl CopyEvents {
+    pub const fn builder() -> CopyEventsBuilder<'static, ((), (), ())> {
+        CopyEventsBuilder {
+            fields: ((), (), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CopyEventsBuilder<'a, Fields = ((), (), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, Amount, CreatedAt> CopyEventsBuilder<'a, ((), Amount, CreatedAt)> {
+    pub fn id(self, id: i64) -> CopyEventsBuilder<'a, (i64, Amount, CreatedAt)> {
+        let ((), amount, created_at) = self.fields;
+        let _phantom = self._phantom;
+        CopyEventsBuilder {
+            fields: (id, amount, created_at),
+            _phantom,
+        }
+    }
+}
+impl<'a, Id, CreatedAt> CopyEventsBuilder<'a, (Id, (), CreatedAt)> {
+    pub fn amount(
+        self,
+        amount: rust_decimal::Decimal,
+    ) -> CopyEventsBuilder<'a, (Id, rust_decimal::Decimal, CreatedAt)> {
+        let (id, (), created_at) = self.fields;
+        let _phantom = self._phantom;
+        CopyEventsBuilder {
+            fields: (id, amount, created_at),
+            _phantom,
+        }
+    }
+}
+impl<'a, Id, Amount> CopyEventsBuilder<'a, (Id, Amount, ())> {
+    pub fn created_at(
+        self,
+        created_at: chrono::NaiveDateTime,
+    ) -> CopyEventsBuilder<'a, (Id, Amount, chrono::NaiveDateTime)> {
+        let (id, amount, ()) = self.fields;
+        let _phantom = self._phantom;
+        CopyEventsBuilder {
+            fields: (id, amount, created_at),
+            _phantom,
+        }
+    }
+}
+impl<'a> CopyEventsBuilder<'a, (i64, rust_decimal::Decimal, chrono::NaiveDateTime)> {
+    pub fn build(self) -> CopyEvents {
+        let (id, amount, created_at) = self.fields;
+        CopyEvents {
+            id,
+            amount,
+            created_at,
+        }
+    }
+}
+pub struct DeleteEventsRow {}
+impl DeleteEventsRow {
+    pub fn from_row(row: &duckdb::Row) -> duckdb::Result<Self> {
+        Ok(Self {})
+    }
+}
+pub struct DeleteEvents;
+impl DeleteEvents {
+    pub const QUERY: &'static str = r"DELETE FROM events";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl DeleteEvents {
+    pub fn execute(&self, conn: &duckdb::Connection) -> duckdb::Result<usize> {
+        self.prepare(conn)?.execute(self.as_params())
+    }
+    pub fn prepare<'conn>(
+        &self,
+        conn: &'conn duckdb::Connection,
+    ) -> duckdb::Result<duckdb::Statement<'conn>> {
+        conn.prepare(self.query_str())
+    }
+    pub fn as_params(&self) -> impl duckdb::Params + '_ {
+        duckdb::params_from_iter(self.param_values())
+    }
+    fn param_values(&self) -> [&dyn duckdb::ToSql; 0] {
+        []
+    }
+}
+impl DeleteEvents {
+    pub const fn builder() -> DeleteEventsBuilder<'static, ()> {
+        DeleteEventsBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct DeleteEventsBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> DeleteEventsBuilder<'a, ()> {
+    pub fn build(self) -> DeleteEvents {
+        let () = self.fields;
+        DeleteEvents {}
+    }
+}
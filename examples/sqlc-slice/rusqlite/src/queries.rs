@@ -2,6 +2,66 @@
 //! sqlc version: v1.28.0
 //! sqlc-gen-rust version: v0.1.12
 
+/// Value of a NUMERIC affinity column, kept in the storage class SQLite returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqliteNumeric {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+impl SqliteNumeric {
+    /// Convert to `f64`. Large integers and non-numeric text may lose precision or return `None`
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(v) => Some(*v as f64),
+            Self::Real(v) => Some(*v),
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+    /// Convert to `i64` if the value is integral
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            Self::Real(v) if v.fract() == 0.0 => Some(*v as i64),
+            Self::Real(_) => None,
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+}
+impl From<i64> for SqliteNumeric {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+impl From<f64> for SqliteNumeric {
+    fn from(value: f64) -> Self {
+        Self::Real(value)
+    }
+}
+impl From<String> for SqliteNumeric {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl rusqlite::types::FromSql for SqliteNumeric {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        match value {
+            rusqlite::types::ValueRef::Integer(v) => Ok(Self::Integer(v)),
+            rusqlite::types::ValueRef::Real(v) => Ok(Self::Real(v)),
+            rusqlite::types::ValueRef::Text(_) => value.as_str().map(|v| Self::Text(v.to_owned())),
+            _ => Err(rusqlite::types::FromSqlError::InvalidType),
+        }
+    }
+}
+impl rusqlite::ToSql for SqliteNumeric {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        match self {
+            Self::Integer(v) => v.to_sql(),
+            Self::Real(v) => v.to_sql(),
+            Self::Text(v) => v.to_sql(),
+        }
+    }
+}
 pub trait RusqliteClient {
     fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>>;
 }
@@ -15,6 +75,11 @@ impl RusqliteClient for rusqlite::Transaction<'_> {
         rusqlite::Connection::prepare(&self, sql)
     }
 }
+impl RusqliteClient for rusqlite::Savepoint<'_> {
+    fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>> {
+        rusqlite::Connection::prepare(self, sql)
+    }
+}
 pub struct ListAuthorsByIDsRow {
     pub id: i64,
     pub name: String,
@@ -217,6 +217,43 @@ impl AsyncTestContext for DeadPoolContext {
         admin_client.batch_execute(&stmt).await.unwrap();
     }
 }
+pub struct R2d2PgContext {
+    db_name: String,
+    pub pool: r2d2::Pool<r2d2_postgres::PostgresConnectionManager<postgres::NoTls>>,
+}
+
+impl TestContext for R2d2PgContext {
+    fn setup() -> Self {
+        let mut admin_client = postgres_config().connect(postgres::NoTls).unwrap();
+
+        let test_db_name = generate_tmp_db();
+
+        admin_client
+            .batch_execute(&format!("CREATE DATABASE {test_db_name}"))
+            .unwrap();
+
+        let mut config = postgres_config();
+        let config = config.dbname(&test_db_name);
+
+        let mgr = r2d2_postgres::PostgresConnectionManager::new(config.to_owned(), postgres::NoTls);
+        let pool = r2d2::Pool::builder().max_size(4).build(mgr).unwrap();
+
+        Self {
+            pool,
+            db_name: test_db_name,
+        }
+    }
+    fn teardown(self) {
+        drop(self.pool);
+
+        let mut admin_client = postgres_config().connect(postgres::NoTls).unwrap();
+
+        admin_client
+            .batch_execute(&format!("DROP DATABASE {}", self.db_name))
+            .unwrap();
+    }
+}
+
 pub struct SqlxPgContext {
     db_name: String,
     pub pool: sqlx::PgPool,
@@ -341,6 +378,99 @@ impl AsyncTestContext for SqlxMysqlContext {
     }
 }
 
+pub struct MysqlContext {
+    db_name: String,
+    pub conn: mysql::Conn,
+}
+
+fn mysql_admin_url() -> String {
+    let (_, port, host, user, password) = mysql_config();
+    format!("mysql://{user}:{password}@{host}:{port}/mysql")
+}
+
+fn mysql_test_url(db_name: &str) -> String {
+    let (_, port, host, user, password) = mysql_config();
+    format!("mysql://{user}:{password}@{host}:{port}/{db_name}")
+}
+
+impl TestContext for MysqlContext {
+    fn setup() -> Self {
+        use mysql::prelude::Queryable as _;
+
+        let admin_opts = mysql::Opts::from_url(&mysql_admin_url()).unwrap();
+        let mut admin_conn = mysql::Conn::new(admin_opts).unwrap();
+
+        let test_db_name = generate_tmp_db();
+        admin_conn
+            .query_drop(format!("CREATE DATABASE `{test_db_name}`"))
+            .unwrap();
+
+        let opts = mysql::Opts::from_url(&mysql_test_url(&test_db_name)).unwrap();
+        let conn = mysql::Conn::new(opts).unwrap();
+        Self {
+            conn,
+            db_name: test_db_name,
+        }
+    }
+
+    fn teardown(self) {
+        use mysql::prelude::Queryable as _;
+
+        drop(self.conn);
+
+        let admin_opts = mysql::Opts::from_url(&mysql_admin_url()).unwrap();
+        let mut admin_conn = mysql::Conn::new(admin_opts).unwrap();
+        admin_conn
+            .query_drop(format!("DROP DATABASE `{}`", self.db_name))
+            .unwrap();
+    }
+}
+
+pub struct MysqlAsyncContext {
+    db_name: String,
+    pub conn: mysql_async::Conn,
+}
+
+impl AsyncTestContext for MysqlAsyncContext {
+    async fn setup() -> Self {
+        use mysql_async::prelude::Queryable as _;
+
+        let mut admin_conn = mysql_async::Conn::from_url(mysql_admin_url())
+            .await
+            .unwrap();
+
+        let test_db_name = generate_tmp_db();
+        admin_conn
+            .query_drop(format!("CREATE DATABASE `{test_db_name}`"))
+            .await
+            .unwrap();
+        admin_conn.disconnect().await.unwrap();
+
+        let conn = mysql_async::Conn::from_url(mysql_test_url(&test_db_name))
+            .await
+            .unwrap();
+        Self {
+            conn,
+            db_name: test_db_name,
+        }
+    }
+
+    async fn teardown(self) {
+        use mysql_async::prelude::Queryable as _;
+
+        self.conn.disconnect().await.unwrap();
+
+        let mut admin_conn = mysql_async::Conn::from_url(mysql_admin_url())
+            .await
+            .unwrap();
+        admin_conn
+            .query_drop(format!("DROP DATABASE `{}`", self.db_name))
+            .await
+            .unwrap();
+        admin_conn.disconnect().await.unwrap();
+    }
+}
+
 pub struct SqlxSqliteContext {
     pub pool: sqlx::SqlitePool,
 }
@@ -352,6 +482,23 @@ impl AsyncTestContext for SqlxSqliteContext {
     }
 }
 
+pub struct SqlxAnyContext {
+    pub pool: sqlx::AnyPool,
+}
+
+impl AsyncTestContext for SqlxAnyContext {
+    async fn setup() -> Self {
+        sqlx::any::install_default_drivers();
+        // unlike `SqlitePool`, every `Any` connection to `:memory:` opens its own database
+        let pool = sqlx::any::AnyPoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        Self { pool }
+    }
+}
+
 pub struct RusqliteContext {
     pub conn: rusqlite::Connection,
 }
@@ -363,6 +510,34 @@ impl TestContext for RusqliteContext {
     }
 }
 
+pub struct DeadpoolSqliteContext {
+    pub pool: deadpool_sqlite::Pool,
+}
+
+impl AsyncTestContext for DeadpoolSqliteContext {
+    async fn setup() -> Self {
+        // every connection to `:memory:` opens its own database, so the pool keeps only one
+        let pool = deadpool_sqlite::Config::new(":memory:")
+            .builder(deadpool_sqlite::Runtime::Tokio1)
+            .unwrap()
+            .max_size(1)
+            .build()
+            .unwrap();
+        Self { pool }
+    }
+}
+
+pub struct TokioRusqliteContext {
+    pub conn: tokio_rusqlite::Connection,
+}
+
+impl AsyncTestContext for TokioRusqliteContext {
+    async fn setup() -> Self {
+        let conn = tokio_rusqlite::Connection::open_in_memory().await.unwrap();
+        Self { conn }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -437,6 +612,22 @@ mod tests {
         assert_eq!(int_val, 1);
     }
 
+    #[test_context(MysqlContext)]
+    #[test]
+    fn test_mysql(ctx: &mut MysqlContext) {
+        use mysql::prelude::Queryable as _;
+        let int_val: Option<i32> = ctx.conn.query_first("SELECT 1 as int_val").unwrap();
+        assert_eq!(int_val, Some(1));
+    }
+
+    #[test_context(MysqlAsyncContext)]
+    #[tokio::test]
+    async fn test_mysql_async(ctx: &mut MysqlAsyncContext) {
+        use mysql_async::prelude::Queryable as _;
+        let int_val: Option<i32> = ctx.conn.query_first("SELECT 1 as int_val").await.unwrap();
+        assert_eq!(int_val, Some(1));
+    }
+
     #[test_context(SqlxSqliteContext)]
     #[tokio::test]
     async fn test_sqlx_sqlite(ctx: &mut SqlxSqliteContext) {
@@ -450,6 +641,19 @@ mod tests {
         assert_eq!(int_val, 1);
     }
 
+    #[test_context(SqlxAnyContext)]
+    #[tokio::test]
+    async fn test_sqlx_any(ctx: &mut SqlxAnyContext) {
+        use sqlx::Row as _;
+        let pool = &ctx.pool;
+        let row = sqlx::query("SELECT 1 as int_val")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        let int_val: i32 = row.try_get("int_val").unwrap();
+        assert_eq!(int_val, 1);
+    }
+
     #[test_context(RusqliteContext)]
     #[test]
     fn test_rusqlite(ctx: &mut RusqliteContext) {
@@ -460,4 +664,42 @@ mod tests {
 
         assert_eq!(int_val, 1);
     }
+
+    #[test_context(TokioRusqliteContext)]
+    #[tokio::test]
+    async fn test_tokio_rusqlite(ctx: &mut TokioRusqliteContext) {
+        let int_val: i32 = ctx
+            .conn
+            .call(|conn| {
+                let int_val =
+                    conn.query_row("SELECT 1 as int_val", (), |row| row.get("int_val"))?;
+                Ok(int_val)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(int_val, 1);
+    }
+
+    #[test_context(R2d2PgContext)]
+    #[test]
+    fn test_r2d2_pg(ctx: &mut R2d2PgContext) {
+        let mut conn = ctx.pool.get().unwrap();
+        let row = conn.query_one("SELECT 1 as int_val", &[]).unwrap();
+        let int_val: i32 = row.get("int_val");
+        assert_eq!(int_val, 1);
+    }
+
+    #[test_context(DeadpoolSqliteContext)]
+    #[tokio::test]
+    async fn test_deadpool_sqlite(ctx: &mut DeadpoolSqliteContext) {
+        let conn = ctx.pool.get().await.unwrap();
+        let int_val: i32 = conn
+            .interact(|conn| conn.query_row("SELECT 1 as int_val", (), |row| row.get("int_val")))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(int_val, 1);
+    }
 }
@@ -0,0 +1,75 @@
+#[allow(warnings)]
+mod queries;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn migrate_db(conn: &libsql::Connection) {
+        conn.execute_batch(include_str!("../../sqlx-sqlite/schema.sql"))
+            .await
+            .unwrap();
+    }
+
+    async fn connect() -> libsql::Connection {
+        let db = libsql::Builder::new_local(":memory:")
+            .build()
+            .await
+            .unwrap();
+        db.connect().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mapping() {
+        let conn = connect().await;
+        migrate_db(&conn).await;
+
+        let blob_val = vec![1, 2, 3, 4, 5];
+        let numeric_val = queries::SqliteNumeric::Real(26.1);
+        let decimal_val = queries::SqliteNumeric::from(27);
+
+        let q = queries::InsertMapping::builder()
+            .aff_integer_val(1)
+            .aff_real_val(2.0)
+            .aff_text_val("3")
+            .aff_blob_val(&blob_val)
+            .int_val(5)
+            .integer_val(6)
+            .tinyint_val(7)
+            .smallint_val(8)
+            .mediumint_val(9)
+            .bigint_val(10)
+            .unsigned_big_int_val(11)
+            .int_2_val(12)
+            .int_8_val(13)
+            .character_20_val("14")
+            .varchar_255_val("15")
+            .varying_char_255_val("16")
+            .nchar_55_val("17")
+            .native_char_70_val("18")
+            .nvarchar_100_val("19")
+            .text_val("20")
+            .clob_val("21")
+            .real_val(22.0)
+            .double_val(23.0)
+            .double_precision_val(24.0)
+            .float_val(25.0)
+            .numeric_val(&numeric_val)
+            .decimal_10_5_val(&decimal_val)
+            .boolean_val(true)
+            .date_val("2025-01-23")
+            .time_val("01:23:45")
+            .datetime_val("2025-01-23 04:05:06")
+            .build();
+
+        q.execute(&conn).await.unwrap();
+
+        let row = queries::GetMapping.query_one(&conn).await.unwrap();
+        assert_eq!(row.aff_blob_val, blob_val);
+        assert_eq!(row.tinyint_val, 7);
+        assert_eq!(row.numeric_val, numeric_val);
+        assert_eq!(row.decimal_10_5_val, queries::SqliteNumeric::Integer(27));
+        assert!(row.boolean_val);
+        assert_eq!(row.date_val, "2025-01-23");
+    }
+}
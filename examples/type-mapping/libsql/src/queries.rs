@@ -0,0 +1,6288 @@
+//! Code generated by sqlc-gen-rust. SHOULD NOT EDIT.
+//! sqlc version: v1.28.0
+//! sqlc-gen-rust version: v0.1.12
+
+/// Value of a NUMERIC affinity column, kept in the storage class SQLite returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqliteNumeric {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+impl SqliteNumeric {
+    /// Convert to `f64`. Large integers and non-numeric text may lose precision or return `None`
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(v) => Some(*v as f64),
+            Self::Real(v) => Some(*v),
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+    /// Convert to `i64` if the value is integral
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            Self::Real(v) if v.fract() == 0.0 => Some(*v as i64),
+            Self::Real(_) => None,
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+}
+impl From<i64> for SqliteNumeric {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+impl From<f64> for SqliteNumeric {
+    fn from(value: f64) -> Self {
+        Self::Real(value)
+    }
+}
+impl From<String> for SqliteNumeric {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+/// Decode a column value. `libsql::FromValue` is sealed, so row fields are decoded by this trait
+pub trait FromLibsqlValue: Sized {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self>;
+}
+impl FromLibsqlValue for libsql::Value {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        Ok(value)
+    }
+}
+impl<T: FromLibsqlValue> FromLibsqlValue for Option<T> {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Null => Ok(None),
+            value => T::from_libsql_value(value).map(Some),
+        }
+    }
+}
+impl FromLibsqlValue for i64 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Integer(v) => Ok(v),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for i32 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        i64::from_libsql_value(value)?
+            .try_into()
+            .map_err(|_| libsql::Error::InvalidColumnType)
+    }
+}
+impl FromLibsqlValue for u32 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        i64::from_libsql_value(value)?
+            .try_into()
+            .map_err(|_| libsql::Error::InvalidColumnType)
+    }
+}
+impl FromLibsqlValue for u64 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        i64::from_libsql_value(value)?
+            .try_into()
+            .map_err(|_| libsql::Error::InvalidColumnType)
+    }
+}
+impl FromLibsqlValue for bool {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match i64::from_libsql_value(value)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for f64 {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Real(v) => Ok(v),
+            libsql::Value::Integer(v) => Ok(v as f64),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for String {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Text(v) => Ok(v),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for Vec<u8> {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Blob(v) => Ok(v),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl FromLibsqlValue for SqliteNumeric {
+    fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+        match value {
+            libsql::Value::Integer(v) => Ok(Self::Integer(v)),
+            libsql::Value::Real(v) => Ok(Self::Real(v)),
+            libsql::Value::Text(v) => Ok(Self::Text(v)),
+            libsql::Value::Null => Err(libsql::Error::NullValue),
+            _ => Err(libsql::Error::InvalidColumnType),
+        }
+    }
+}
+impl From<SqliteNumeric> for libsql::Value {
+    fn from(value: SqliteNumeric) -> Self {
+        match value {
+            SqliteNumeric::Integer(v) => Self::Integer(v),
+            SqliteNumeric::Real(v) => Self::Real(v),
+            SqliteNumeric::Text(v) => Self::Text(v),
+        }
+    }
+}
+impl From<&SqliteNumeric> for libsql::Value {
+    fn from(value: &SqliteNumeric) -> Self {
+        value.clone().into()
+    }
+}
+pub struct GetMappingRow {
+    pub aff_integer_val: i64,
+    pub aff_real_val: f64,
+    pub aff_text_val: String,
+    pub aff_blob_val: Vec<u8>,
+    pub int_val: i64,
+    pub integer_val: i64,
+    pub tinyint_val: i32,
+    pub smallint_val: i32,
+    pub mediumint_val: i32,
+    pub bigint_val: i64,
+    pub unsigned_big_int_val: i64,
+    pub int_2_val: i32,
+    pub int_8_val: i64,
+    pub character_20_val: String,
+    pub varchar_255_val: String,
+    pub varying_char_255_val: String,
+    pub nchar_55_val: String,
+    pub native_char_70_val: String,
+    pub nvarchar_100_val: String,
+    pub text_val: String,
+    pub clob_val: String,
+    pub real_val: f64,
+    pub double_val: f64,
+    pub double_precision_val: f64,
+    pub float_val: f64,
+    pub numeric_val: SqliteNumeric,
+    pub decimal_10_5_val: SqliteNumeric,
+    pub boolean_val: bool,
+    pub date_val: String,
+    pub time_val: String,
+    pub datetime_val: String,
+}
+impl GetMappingRow {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, libsql::Error> {
+        Ok(Self {
+            aff_integer_val: FromLibsqlValue::from_libsql_value(row.get_value(0)?)?,
+            aff_real_val: FromLibsqlValue::from_libsql_value(row.get_value(1)?)?,
+            aff_text_val: FromLibsqlValue::from_libsql_value(row.get_value(2)?)?,
+            aff_blob_val: FromLibsqlValue::from_libsql_value(row.get_value(3)?)?,
+            int_val: FromLibsqlValue::from_libsql_value(row.get_value(4)?)?,
+            integer_val: FromLibsqlValue::from_libsql_value(row.get_value(5)?)?,
+            tinyint_val: FromLibsqlValue::from_libsql_value(row.get_value(6)?)?,
+            smallint_val: FromLibsqlValue::from_libsql_value(row.get_value(7)?)?,
+            mediumint_val: FromLibsqlValue::from_libsql_value(row.get_value(8)?)?,
+            bigint_val: FromLibsqlValue::from_libsql_value(row.get_value(9)?)?,
+            unsigned_big_int_val: FromLibsqlValue::from_libsql_value(row.get_value(10)?)?,
+            int_2_val: FromLibsqlValue::from_libsql_value(row.get_value(11)?)?,
+            int_8_val: FromLibsqlValue::from_libsql_value(row.get_value(12)?)?,
+            character_20_val: FromLibsqlValue::from_libsql_value(row.get_value(13)?)?,
+            varchar_255_val: FromLibsqlValue::from_libsql_value(row.get_value(14)?)?,
+            varying_char_255_val: FromLibsqlValue::from_libsql_value(row.get_value(15)?)?,
+            nchar_55_val: FromLibsqlValue::from_libsql_value(row.get_value(16)?)?,
+            native_char_70_val: FromLibsqlValue::from_libsql_value(row.get_value(17)?)?,
+            nvarchar_100_val: FromLibsqlValue::from_libsql_value(row.get_value(18)?)?,
+            text_val: FromLibsqlValue::from_libsql_value(row.get_value(19)?)?,
+            clob_val: FromLibsqlValue::from_libsql_value(row.get_value(20)?)?,
+            real_val: FromLibsqlValue::from_libsql_value(row.get_value(21)?)?,
+            double_val: FromLibsqlValue::from_libsql_value(row.get_value(22)?)?,
+            double_precision_val: FromLibsqlValue::from_libsql_value(row.get_value(23)?)?,
+            float_val: FromLibsqlValue::from_libsql_value(row.get_value(24)?)?,
+            numeric_val: FromLibsqlValue::from_libsql_value(row.get_value(25)?)?,
+            decimal_10_5_val: FromLibsqlValue::from_libsql_value(row.get_value(26)?)?,
+            boolean_val: FromLibsqlValue::from_libsql_value(row.get_value(27)?)?,
+            date_val: FromLibsqlValue::from_libsql_value(row.get_value(28)?)?,
+            time_val: FromLibsqlValue::from_libsql_value(row.get_value(29)?)?,
+            datetime_val: FromLibsqlValue::from_libsql_value(row.get_value(30)?)?,
+        })
+    }
+}
+pub struct GetMapping;
+impl GetMapping {
+    pub const QUERY: &'static str = r"SELECT
+    aff_integer_val,
+    aff_real_val,
+    aff_text_val,
+    aff_blob_val,
+    int_val,
+    integer_val,
+    tinyint_val,
+    smallint_val,
+    mediumint_val,
+    bigint_val,
+    unsigned_big_int_val,
+    int2_val,
+    int8_val,
+    character20_val,
+    varchar255_val,
+    varying_char255_val,
+    nchar55_val,
+    native_char70_val,
+    nvarchar100_val,
+    text_val,
+    clob_val,
+    real_val,
+    double_val,
+    double_precision_val,
+    float_val,
+    numeric_val,
+    decimal10_5_val,
+    boolean_val,
+    date_val,
+    time_val,
+    datetime_val
+FROM mapping";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetMapping {
+    pub async fn query_one(
+        &self,
+        conn: &libsql::Connection,
+    ) -> Result<GetMappingRow, libsql::Error> {
+        let row = conn
+            .prepare(self.query_str())
+            .await?
+            .query_row(self.as_params())
+            .await?;
+        GetMappingRow::from_row(&row)
+    }
+    pub async fn query_opt(
+        &self,
+        conn: &libsql::Connection,
+    ) -> Result<Option<GetMappingRow>, libsql::Error> {
+        let mut rows = conn.query(self.query_str(), self.as_params()).await?;
+        match rows.next().await? {
+            Some(row) => Ok(Some(GetMappingRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub fn as_params(&self) -> libsql::params::Params {
+        libsql::params::Params::None
+    }
+}
+impl GetMapping {
+    pub const fn builder() -> GetMappingBuilder<'static, ()> {
+        GetMappingBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetMappingBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetMappingBuilder<'a, ()> {
+    pub fn build(self) -> GetMapping {
+        let () = self.fields;
+        GetMapping {}
+    }
+}
+pub struct InsertMappingRow {}
+impl InsertMappingRow {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, libsql::Error> {
+        Ok(Self {})
+    }
+}
+pub struct InsertMapping<'a> {
+    aff_integer_val: i64,
+    aff_real_val: f64,
+    aff_text_val: &'a str,
+    aff_blob_val: &'a [u8],
+    int_val: i64,
+    integer_val: i64,
+    tinyint_val: i32,
+    smallint_val: i32,
+    mediumint_val: i32,
+    bigint_val: i64,
+    unsigned_big_int_val: i64,
+    int_2_val: i32,
+    int_8_val: i64,
+    character_20_val: &'a str,
+    varchar_255_val: &'a str,
+    varying_char_255_val: &'a str,
+    nchar_55_val: &'a str,
+    native_char_70_val: &'a str,
+    nvarchar_100_val: &'a str,
+    text_val: &'a str,
+    clob_val: &'a str,
+    real_val: f64,
+    double_val: f64,
+    double_precision_val: f64,
+    float_val: f64,
+    numeric_val: &'a SqliteNumeric,
+    decimal_10_5_val: &'a SqliteNumeric,
+    boolean_val: bool,
+    date_val: &'a str,
+    time_val: &'a str,
+    datetime_val: &'a str,
+}
+impl<'a> InsertMapping<'a> {
+    pub const QUERY: &'static str = r"INSERT INTO mapping (
+    aff_integer_val,
+    aff_real_val,
+    aff_text_val,
+    aff_blob_val,
+    int_val,
+    integer_val,
+    tinyint_val,
+    smallint_val,
+    mediumint_val,
+    bigint_val,
+    unsigned_big_int_val,
+    int2_val,
+    int8_val,
+    character20_val,
+    varchar255_val,
+    varying_char255_val,
+    nchar55_val,
+    native_char70_val,
+    nvarchar100_val,
+    text_val,
+    clob_val,
+    real_val,
+    double_val,
+    double_precision_val,
+    float_val,
+    numeric_val,
+    decimal10_5_val,
+    boolean_val,
+    date_val,
+    time_val,
+    datetime_val
+) VALUES (
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?,
+    ?
+)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> InsertMapping<'a> {
+    pub async fn execute(&self, conn: &libsql::Connection) -> Result<u64, libsql::Error> {
+        conn.execute(self.query_str(), self.as_params()).await
+    }
+    pub fn as_params(&self) -> libsql::params::Params {
+        libsql::params::Params::Positional(Vec::from([
+            libsql::Value::from(self.aff_integer_val),
+            libsql::Value::from(self.aff_real_val),
+            libsql::Value::from(self.aff_text_val),
+            libsql::Value::from(self.aff_blob_val),
+            libsql::Value::from(self.int_val),
+            libsql::Value::from(self.integer_val),
+            libsql::Value::from(self.tinyint_val),
+            libsql::Value::from(self.smallint_val),
+            libsql::Value::from(self.mediumint_val),
+            libsql::Value::from(self.bigint_val),
+            libsql::Value::from(self.unsigned_big_int_val),
+            libsql::Value::from(self.int_2_val),
+            libsql::Value::from(self.int_8_val),
+            libsql::Value::from(self.character_20_val),
+            libsql::Value::from(self.varchar_255_val),
+            libsql::Value::from(self.varying_char_255_val),
+            libsql::Value::from(self.nchar_55_val),
+            libsql::Value::from(self.native_char_70_val),
+            libsql::Value::from(self.nvarchar_100_val),
+            libsql::Value::from(self.text_val),
+            libsql::Value::from(self.clob_val),
+            libsql::Value::from(self.real_val),
+            libsql::Value::from(self.double_val),
+            libsql::Value::from(self.double_precision_val),
+            libsql::Value::from(self.float_val),
+            libsql::Value::from(self.numeric_val),
+            libsql::Value::from(self.decimal_10_5_val),
+            libsql::Value::from(self.boolean_val),
+            libsql::Value::from(self.date_val),
+            libsql::Value::from(self.time_val),
+            libsql::Value::from(self.datetime_val),
+        ]))
+    }
+}
+impl<'a> InsertMapping<'a> {
+    pub const fn builder() -> InsertMappingBuilder<
+        'a,
+        (
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+        ),
+    > {
+        InsertMappingBuilder {
+            fields: (
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+            ),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct InsertMappingBuilder<
+    'a,
+    Fields = (
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+    ),
+> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<
+    'a,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            (),
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn aff_integer_val(
+        self,
+        aff_integer_val: i64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            i64,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            (),
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            (),
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn aff_real_val(
+        self,
+        aff_real_val: f64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            f64,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            (),
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            (),
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn aff_text_val(
+        self,
+        aff_text_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            &'a str,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            (),
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            (),
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn aff_blob_val(
+        self,
+        aff_blob_val: &'a [u8],
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            &'a [u8],
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            (),
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            (),
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn int_val(
+        self,
+        int_val: i64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            i64,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            (),
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            (),
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn integer_val(
+        self,
+        integer_val: i64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            i64,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            (),
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            (),
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn tinyint_val(
+        self,
+        tinyint_val: i32,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            i32,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            (),
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            (),
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn smallint_val(
+        self,
+        smallint_val: i32,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            i32,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            (),
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            (),
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn mediumint_val(
+        self,
+        mediumint_val: i32,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            i32,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            (),
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            (),
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn bigint_val(
+        self,
+        bigint_val: i64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            i64,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            (),
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            (),
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn unsigned_big_int_val(
+        self,
+        unsigned_big_int_val: i64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            i64,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            (),
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            (),
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn int_2_val(
+        self,
+        int_2_val: i32,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            i32,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            (),
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            (),
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn int_8_val(
+        self,
+        int_8_val: i64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            i64,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            (),
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            (),
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn character_20_val(
+        self,
+        character_20_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            &'a str,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            (),
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            (),
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn varchar_255_val(
+        self,
+        varchar_255_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            &'a str,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            (),
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            (),
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn varying_char_255_val(
+        self,
+        varying_char_255_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            &'a str,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            (),
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            (),
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn nchar_55_val(
+        self,
+        nchar_55_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            &'a str,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            (),
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            (),
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn native_char_70_val(
+        self,
+        native_char_70_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            &'a str,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            (),
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            (),
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn nvarchar_100_val(
+        self,
+        nvarchar_100_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            &'a str,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            (),
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            (),
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn text_val(
+        self,
+        text_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            &'a str,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            (),
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            (),
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn clob_val(
+        self,
+        clob_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            &'a str,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            (),
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            (),
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn real_val(
+        self,
+        real_val: f64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            f64,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            (),
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            (),
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn double_val(
+        self,
+        double_val: f64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            f64,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            (),
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            (),
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn double_precision_val(
+        self,
+        double_precision_val: f64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            f64,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            (),
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            (),
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn float_val(
+        self,
+        float_val: f64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            f64,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            (),
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            (),
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn numeric_val(
+        self,
+        numeric_val: &'a SqliteNumeric,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            &'a SqliteNumeric,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            (),
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            (),
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn decimal_10_5_val(
+        self,
+        decimal_10_5_val: &'a SqliteNumeric,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            &'a SqliteNumeric,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            (),
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    DateVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            (),
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn boolean_val(
+        self,
+        boolean_val: bool,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            bool,
+            DateVal,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            (),
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    TimeVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            (),
+            TimeVal,
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn date_val(
+        self,
+        date_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            &'a str,
+            TimeVal,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            (),
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    DatetimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            (),
+            DatetimeVal,
+        ),
+    >
+{
+    pub fn time_val(
+        self,
+        time_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            &'a str,
+            DatetimeVal,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            (),
+            datetime_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    AffIntegerVal,
+    AffRealVal,
+    AffTextVal,
+    AffBlobVal,
+    IntVal,
+    IntegerVal,
+    TinyintVal,
+    SmallintVal,
+    MediumintVal,
+    BigintVal,
+    UnsignedBigIntVal,
+    Int2Val,
+    Int8Val,
+    Character20Val,
+    Varchar255Val,
+    VaryingChar255Val,
+    Nchar55Val,
+    NativeChar70Val,
+    Nvarchar100Val,
+    TextVal,
+    ClobVal,
+    RealVal,
+    DoubleVal,
+    DoublePrecisionVal,
+    FloatVal,
+    NumericVal,
+    Decimal105Val,
+    BooleanVal,
+    DateVal,
+    TimeVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            (),
+        ),
+    >
+{
+    pub fn datetime_val(
+        self,
+        datetime_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            AffIntegerVal,
+            AffRealVal,
+            AffTextVal,
+            AffBlobVal,
+            IntVal,
+            IntegerVal,
+            TinyintVal,
+            SmallintVal,
+            MediumintVal,
+            BigintVal,
+            UnsignedBigIntVal,
+            Int2Val,
+            Int8Val,
+            Character20Val,
+            Varchar255Val,
+            VaryingChar255Val,
+            Nchar55Val,
+            NativeChar70Val,
+            Nvarchar100Val,
+            TextVal,
+            ClobVal,
+            RealVal,
+            DoubleVal,
+            DoublePrecisionVal,
+            FloatVal,
+            NumericVal,
+            Decimal105Val,
+            BooleanVal,
+            DateVal,
+            TimeVal,
+            &'a str,
+        ),
+    > {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            (),
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                aff_integer_val,
+                aff_real_val,
+                aff_text_val,
+                aff_blob_val,
+                int_val,
+                integer_val,
+                tinyint_val,
+                smallint_val,
+                mediumint_val,
+                bigint_val,
+                unsigned_big_int_val,
+                int_2_val,
+                int_8_val,
+                character_20_val,
+                varchar_255_val,
+                varying_char_255_val,
+                nchar_55_val,
+                native_char_70_val,
+                nvarchar_100_val,
+                text_val,
+                clob_val,
+                real_val,
+                double_val,
+                double_precision_val,
+                float_val,
+                numeric_val,
+                decimal_10_5_val,
+                boolean_val,
+                date_val,
+                time_val,
+                datetime_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<'a>
+    InsertMappingBuilder<
+        'a,
+        (
+            i64,
+            f64,
+            &'a str,
+            &'a [u8],
+            i64,
+            i64,
+            i32,
+            i32,
+            i32,
+            i64,
+            i64,
+            i32,
+            i64,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            f64,
+            f64,
+            f64,
+            f64,
+            &'a SqliteNumeric,
+            &'a SqliteNumeric,
+            bool,
+            &'a str,
+            &'a str,
+            &'a str,
+        ),
+    >
+{
+    pub fn build(self) -> InsertMapping<'a> {
+        let (
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        ) = self.fields;
+        InsertMapping {
+            aff_integer_val,
+            aff_real_val,
+            aff_text_val,
+            aff_blob_val,
+            int_val,
+            integer_val,
+            tinyint_val,
+            smallint_val,
+            mediumint_val,
+            bigint_val,
+            unsigned_big_int_val,
+            int_2_val,
+            int_8_val,
+            character_20_val,
+            varchar_255_val,
+            varying_char_255_val,
+            nchar_55_val,
+            native_char_70_val,
+            nvarchar_100_val,
+            text_val,
+            clob_val,
+            real_val,
+            double_val,
+            double_precision_val,
+            float_val,
+            numeric_val,
+            decimal_10_5_val,
+            boolean_val,
+            date_val,
+            time_val,
+            datetime_val,
+        }
+    }
+}
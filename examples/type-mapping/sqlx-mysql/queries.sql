@@ -14,7 +14,16 @@ SELECT
     datetime_val,
     date_val,
     time_val,
-    json_val
+    json_val,
+    year_val,
+    bit_val,
+    bit8_val,
+    set_val,
+    geometry_val,
+    point_val,
+    decimal_val,
+    udecimal_val,
+    enum_val
 FROM mapping;
 
 /* name: InsertMapping :exec */
@@ -33,7 +42,16 @@ INSERT INTO mapping (
     datetime_val,
     date_val,
     time_val,
-    json_val
+    json_val,
+    year_val,
+    bit_val,
+    bit8_val,
+    set_val,
+    geometry_val,
+    point_val,
+    decimal_val,
+    udecimal_val,
+    enum_val
 ) VALUES (
-    ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+    ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
 );
@@ -14,5 +14,14 @@ CREATE TABLE mapping (
     date_val DATE NOT NULL,
     time_val TIME NOT NULL,
     json_val JSON NOT NULL,
+    year_val YEAR NOT NULL,
+    bit_val BIT(1) NOT NULL,
+    bit8_val BIT(8) NOT NULL,
+    set_val SET('a', 'b', 'c') NOT NULL,
+    geometry_val GEOMETRY NOT NULL,
+    point_val POINT NOT NULL,
+    decimal_val DECIMAL(10, 2) NOT NULL,
+    udecimal_val DECIMAL(10, 2) UNSIGNED NOT NULL,
+    enum_val ENUM('small', 'medium', 'large') NOT NULL,
     id BIGINT AUTO_INCREMENT PRIMARY KEY
 );
@@ -28,6 +28,14 @@ mod tests {
         let date_val = chrono::NaiveDate::from_ymd_opt(2025, 1, 23).unwrap();
         let time_val = sqlx::mysql::types::MySqlTime::ZERO;
         let json_val = serde_json::json!({ "type": "json" });
+        let set_val = queries::MySqlSet(["a".to_string(), "c".to_string()].into());
+        // POINT(1 2) with SRID 0 in MySQL's internal geometry format
+        let point_val = {
+            let mut wkb = vec![0, 0, 0, 0, 1, 1, 0, 0, 0];
+            wkb.extend_from_slice(&1.0f64.to_le_bytes());
+            wkb.extend_from_slice(&2.0f64.to_le_bytes());
+            queries::MySqlGeometry(wkb)
+        };
 
         let q = queries::InsertMapping::builder()
             .bool_val(true)
@@ -40,15 +48,33 @@ mod tests {
             .double_val(7.0)
             .text_val("8")
             .blob_val(&blob_val)
-            .timestamp_val(&timestamp_val)
-            .datetime_val(&datetime_val)
-            .date_val(&date_val)
+            .timestamp_val(timestamp_val)
+            .datetime_val(datetime_val)
+            .date_val(date_val)
             .time_val(time_val)
             .json_val(&json_val)
+            .year_val(2025)
+            .bit_val(true)
+            .bit_8_val(0b1010_0101)
+            .set_val(&set_val)
+            .geometry_val(&point_val)
+            .point_val(&point_val)
+            .decimal_val("1.23")
+            .udecimal_val("4.56")
+            .enum_val(queries::MappingEnumVal::Medium)
             .build();
 
         q.execute(pool).await.unwrap();
 
-        let _row = queries::GetMapping.query_one(pool).await.unwrap();
+        let row = queries::GetMapping.query_one(pool).await.unwrap();
+        assert_eq!(row.year_val, 2025);
+        assert!(row.bit_val);
+        assert_eq!(row.bit_8_val, 0b1010_0101);
+        assert_eq!(row.set_val, set_val);
+        assert_eq!(row.geometry_val, point_val);
+        assert_eq!(row.point_val, point_val);
+        assert_eq!(row.decimal_val, "1.23");
+        assert_eq!(row.udecimal_val, "4.56");
+        assert!(matches!(row.enum_val, queries::MappingEnumVal::Medium));
     }
 }
@@ -2,6 +2,88 @@
 //! sqlc version: v1.28.0
 //! sqlc-gen-rust version: v0.1.12
 
+/// Members of a MySQL `SET` column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MySqlSet(pub std::collections::HashSet<String>);
+impl sqlx::Type<sqlx::MySql> for MySqlSet {
+    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+        <str as sqlx::Type<sqlx::MySql>>::type_info()
+    }
+    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+        use sqlx::TypeInfo as _;
+        ty.name() == "SET" || <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
+    }
+}
+impl<'q> sqlx::Encode<'q, sqlx::MySql> for MySqlSet {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        let members = self
+            .0
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        <String as sqlx::Encode<'q, sqlx::MySql>>::encode(members, buf)
+    }
+}
+impl<'r> sqlx::Decode<'r, sqlx::MySql> for MySqlSet {
+    fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let members = <&str as sqlx::Decode<sqlx::MySql>>::decode(value)?;
+        Ok(Self(
+            members
+                .split(',')
+                .filter(|member| !member.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        ))
+    }
+}
+/// Geometry value in MySQL's internal format: a 4-byte SRID followed by WKB.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MySqlGeometry(pub Vec<u8>);
+impl sqlx::Type<sqlx::MySql> for MySqlGeometry {
+    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+        <[u8] as sqlx::Type<sqlx::MySql>>::type_info()
+    }
+    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+        use sqlx::TypeInfo as _;
+        ty.name() == "GEOMETRY" || <[u8] as sqlx::Type<sqlx::MySql>>::compatible(ty)
+    }
+}
+impl<'q> sqlx::Encode<'q, sqlx::MySql> for MySqlGeometry {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <&[u8] as sqlx::Encode<'q, sqlx::MySql>>::encode(self.0.as_slice(), buf)
+    }
+}
+impl<'r> sqlx::Decode<'r, sqlx::MySql> for MySqlGeometry {
+    fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        Ok(Self(
+            <&[u8] as sqlx::Decode<sqlx::MySql>>::decode(value)?.to_vec(),
+        ))
+    }
+}
+#[derive(Debug, Clone, Copy, sqlx::Encode, sqlx::Decode)]
+pub enum MappingEnumVal {
+    #[sqlx(rename = "small")]
+    Small,
+    #[sqlx(rename = "medium")]
+    Medium,
+    #[sqlx(rename = "large")]
+    Large,
+}
+impl sqlx::Type<sqlx::MySql> for MappingEnumVal {
+    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+        <str as sqlx::Type<sqlx::MySql>>::type_info()
+    }
+    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+        <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
+    }
+}
 #[derive(sqlx::FromRow)]
 pub struct GetMappingRow {
     #[sqlx(rename = "bool_val")]
@@ -34,6 +116,24 @@ pub struct GetMappingRow {
     pub time_val: sqlx::mysql::types::MySqlTime,
     #[sqlx(rename = "json_val")]
     pub json_val: serde_json::Value,
+    #[sqlx(rename = "year_val")]
+    pub year_val: u16,
+    #[sqlx(rename = "bit_val")]
+    pub bit_val: bool,
+    #[sqlx(rename = "bit8_val")]
+    pub bit_8_val: u64,
+    #[sqlx(rename = "set_val")]
+    pub set_val: MySqlSet,
+    #[sqlx(rename = "geometry_val")]
+    pub geometry_val: MySqlGeometry,
+    #[sqlx(rename = "point_val")]
+    pub point_val: MySqlGeometry,
+    #[sqlx(rename = "decimal_val")]
+    pub decimal_val: String,
+    #[sqlx(rename = "udecimal_val")]
+    pub udecimal_val: String,
+    #[sqlx(rename = "enum_val")]
+    pub enum_val: MappingEnumVal,
 }
 pub struct GetMapping;
 impl GetMapping {
@@ -52,7 +152,16 @@ impl GetMapping {
     datetime_val,
     date_val,
     time_val,
-    json_val
+    json_val,
+    year_val,
+    bit_val,
+    bit8_val,
+    set_val,
+    geometry_val,
+    point_val,
+    decimal_val,
+    udecimal_val,
+    enum_val
 FROM mapping";
     pub fn query_str(&self) -> &str {
         Self::QUERY
@@ -128,11 +237,20 @@ pub struct InsertMapping<'a> {
     double_val: f64,
     text_val: &'a str,
     blob_val: &'a [u8],
-    timestamp_val: &'a chrono::DateTime<chrono::Utc>,
-    datetime_val: &'a chrono::NaiveDateTime,
-    date_val: &'a chrono::NaiveDate,
+    timestamp_val: chrono::DateTime<chrono::Utc>,
+    datetime_val: chrono::NaiveDateTime,
+    date_val: chrono::NaiveDate,
     time_val: sqlx::mysql::types::MySqlTime,
     json_val: &'a serde_json::Value,
+    year_val: u16,
+    bit_val: bool,
+    bit_8_val: u64,
+    set_val: &'a MySqlSet,
+    geometry_val: &'a MySqlGeometry,
+    point_val: &'a MySqlGeometry,
+    decimal_val: &'a str,
+    udecimal_val: &'a str,
+    enum_val: MappingEnumVal,
 }
 impl<'a> InsertMapping<'a> {
     pub const QUERY: &'static str = r"INSERT INTO mapping (
@@ -150,9 +268,18 @@ impl<'a> InsertMapping<'a> {
     datetime_val,
     date_val,
     time_val,
-    json_val
+    json_val,
+    year_val,
+    bit_val,
+    bit8_val,
+    set_val,
+    geometry_val,
+    point_val,
+    decimal_val,
+    udecimal_val,
+    enum_val
 ) VALUES (
-    ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+    ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
 )";
     pub fn query_str(&self) -> &str {
         Self::QUERY
@@ -183,6 +310,15 @@ impl<'a> InsertMapping<'a> {
         let q = q.bind(self.date_val);
         let q = q.bind(self.time_val);
         let q = q.bind(self.json_val);
+        let q = q.bind(self.year_val);
+        let q = q.bind(self.bit_val);
+        let q = q.bind(self.bit_8_val);
+        let q = q.bind(self.set_val);
+        let q = q.bind(self.geometry_val);
+        let q = q.bind(self.point_val);
+        let q = q.bind(self.decimal_val);
+        let q = q.bind(self.udecimal_val);
+        let q = q.bind(self.enum_val);
         q
     }
     pub fn execute<'b, A>(
@@ -212,22 +348,108 @@ impl<'a> InsertMapping<'a> {
             let q = q.bind(self.date_val);
             let q = q.bind(self.time_val);
             let q = q.bind(self.json_val);
+            let q = q.bind(self.year_val);
+            let q = q.bind(self.bit_val);
+            let q = q.bind(self.bit_8_val);
+            let q = q.bind(self.set_val);
+            let q = q.bind(self.geometry_val);
+            let q = q.bind(self.point_val);
+            let q = q.bind(self.decimal_val);
+            let q = q.bind(self.udecimal_val);
+            let q = q.bind(self.enum_val);
             q.execute(&mut *conn).await
         }
     }
 }
 impl<'a> InsertMapping<'a> {
-    pub const fn builder()
-    -> InsertMappingBuilder<'a, ((), (), (), (), (), (), (), (), (), (), (), (), (), (), ())> {
+    pub const fn builder() -> InsertMappingBuilder<
+        'a,
+        (
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+            (),
+        ),
+    > {
         InsertMappingBuilder {
-            fields: ((), (), (), (), (), (), (), (), (), (), (), (), (), (), ()),
+            fields: (
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+                (),
+            ),
             _phantom: std::marker::PhantomData,
         }
     }
 }
 pub struct InsertMappingBuilder<
     'a,
-    Fields = ((), (), (), (), (), (), (), (), (), (), (), (), (), (), ()),
+    Fields = (
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+    ),
 > {
     fields: Fields,
     _phantom: std::marker::PhantomData<&'a ()>,
@@ -248,6 +470,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -267,6 +498,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -291,6 +531,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -309,6 +558,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -328,6 +586,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -349,6 +616,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -368,6 +644,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -392,6 +677,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -410,6 +704,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -429,6 +732,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -450,6 +762,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -469,6 +790,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -493,6 +823,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -511,6 +850,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -530,6 +878,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -551,6 +908,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -570,6 +936,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -594,6 +969,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -612,6 +996,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -631,6 +1024,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -652,6 +1054,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -671,6 +1082,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -695,6 +1115,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -713,6 +1142,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -732,6 +1170,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -753,6 +1200,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -772,6 +1228,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -796,6 +1261,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -814,6 +1288,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -833,6 +1316,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -854,6 +1346,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -873,6 +1374,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -897,6 +1407,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -915,6 +1434,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -934,6 +1462,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -955,6 +1492,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -974,6 +1520,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -998,6 +1553,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1016,6 +1580,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1035,6 +1608,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -1056,6 +1638,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -1075,6 +1666,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -1099,6 +1699,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1117,6 +1726,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1136,6 +1754,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -1157,6 +1784,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -1176,6 +1812,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -1200,6 +1845,15 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1218,6 +1872,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1237,6 +1900,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -1258,6 +1930,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -1277,12 +1958,21 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
     pub fn timestamp_val(
         self,
-        timestamp_val: &'a chrono::DateTime<chrono::Utc>,
+        timestamp_val: chrono::DateTime<chrono::Utc>,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -1296,11 +1986,20 @@ impl<
             DoubleVal,
             TextVal,
             BlobVal,
-            &'a chrono::DateTime<chrono::Utc>,
+            chrono::DateTime<chrono::Utc>,
             DatetimeVal,
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1319,6 +2018,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1338,6 +2046,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -1359,6 +2076,15 @@ impl<
     DateVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -1378,12 +2104,21 @@ impl<
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
     pub fn datetime_val(
         self,
-        datetime_val: &'a chrono::NaiveDateTime,
+        datetime_val: chrono::NaiveDateTime,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -1398,10 +2133,19 @@ impl<
             TextVal,
             BlobVal,
             TimestampVal,
-            &'a chrono::NaiveDateTime,
+            chrono::NaiveDateTime,
             DateVal,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1420,6 +2164,15 @@ impl<
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1439,6 +2192,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -1460,6 +2222,15 @@ impl<
     DatetimeVal,
     TimeVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -1479,12 +2250,21 @@ impl<
             (),
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
     pub fn date_val(
         self,
-        date_val: &'a chrono::NaiveDate,
+        date_val: chrono::NaiveDate,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -1500,9 +2280,18 @@ impl<
             BlobVal,
             TimestampVal,
             DatetimeVal,
-            &'a chrono::NaiveDate,
+            chrono::NaiveDate,
             TimeVal,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1521,6 +2310,15 @@ impl<
             (),
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1540,6 +2338,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -1561,6 +2368,15 @@ impl<
     DatetimeVal,
     DateVal,
     JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -1580,6 +2396,15 @@ impl<
             DateVal,
             (),
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -1604,6 +2429,15 @@ impl<
             DateVal,
             sqlx::mysql::types::MySqlTime,
             JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1622,6 +2456,15 @@ impl<
             date_val,
             (),
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1641,6 +2484,15 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
@@ -1662,6 +2514,15 @@ impl<
     DatetimeVal,
     DateVal,
     TimeVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
 >
     InsertMappingBuilder<
         'a,
@@ -1681,6 +2542,15 @@ impl<
             DateVal,
             TimeVal,
             (),
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     >
 {
@@ -1705,6 +2575,15 @@ impl<
             DateVal,
             TimeVal,
             &'a serde_json::Value,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
         ),
     > {
         let (
@@ -1723,6 +2602,15 @@ impl<
             date_val,
             time_val,
             (),
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         let _phantom = self._phantom;
         InsertMappingBuilder {
@@ -1742,30 +2630,1362 @@ impl<
                 date_val,
                 time_val,
                 json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
             ),
             _phantom,
         }
     }
 }
-impl<'a>
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            (),
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    >
+{
+    pub fn year_val(
+        self,
+        year_val: u16,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            u16,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            (),
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
+>
     InsertMappingBuilder<
         'a,
         (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            (),
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    >
+{
+    pub fn bit_val(
+        self,
+        bit_val: bool,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
             bool,
-            i8,
-            i16,
-            i32,
-            Option<i32>,
-            i64,
-            f32,
-            f64,
-            &'a str,
-            &'a [u8],
-            &'a chrono::DateTime<chrono::Utc>,
-            &'a chrono::NaiveDateTime,
-            &'a chrono::NaiveDate,
-            sqlx::mysql::types::MySqlTime,
-            &'a serde_json::Value,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            (),
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    BitVal,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            (),
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    >
+{
+    pub fn bit_8_val(
+        self,
+        bit_8_val: u64,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            u64,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            bit_val,
+            (),
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            (),
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    >
+{
+    pub fn set_val(
+        self,
+        set_val: &'a MySqlSet,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            &'a MySqlSet,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            (),
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            (),
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    >
+{
+    pub fn geometry_val(
+        self,
+        geometry_val: &'a MySqlGeometry,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            &'a MySqlGeometry,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            (),
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    DecimalVal,
+    UdecimalVal,
+    EnumVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            (),
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    >
+{
+    pub fn point_val(
+        self,
+        point_val: &'a MySqlGeometry,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            &'a MySqlGeometry,
+            DecimalVal,
+            UdecimalVal,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            (),
+            decimal_val,
+            udecimal_val,
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    UdecimalVal,
+    EnumVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            (),
+            UdecimalVal,
+            EnumVal,
+        ),
+    >
+{
+    pub fn decimal_val(
+        self,
+        decimal_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            &'a str,
+            UdecimalVal,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            (),
+            udecimal_val,
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    EnumVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            (),
+            EnumVal,
+        ),
+    >
+{
+    pub fn udecimal_val(
+        self,
+        udecimal_val: &'a str,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            &'a str,
+            EnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            (),
+            enum_val,
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<
+    'a,
+    BoolVal,
+    TinyintVal,
+    SmallintVal,
+    IntVal,
+    IntNullableVal,
+    BigintVal,
+    FloatVal,
+    DoubleVal,
+    TextVal,
+    BlobVal,
+    TimestampVal,
+    DatetimeVal,
+    DateVal,
+    TimeVal,
+    JsonVal,
+    YearVal,
+    BitVal,
+    Bit8Val,
+    SetVal,
+    GeometryVal,
+    PointVal,
+    DecimalVal,
+    UdecimalVal,
+>
+    InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            (),
+        ),
+    >
+{
+    pub fn enum_val(
+        self,
+        enum_val: MappingEnumVal,
+    ) -> InsertMappingBuilder<
+        'a,
+        (
+            BoolVal,
+            TinyintVal,
+            SmallintVal,
+            IntVal,
+            IntNullableVal,
+            BigintVal,
+            FloatVal,
+            DoubleVal,
+            TextVal,
+            BlobVal,
+            TimestampVal,
+            DatetimeVal,
+            DateVal,
+            TimeVal,
+            JsonVal,
+            YearVal,
+            BitVal,
+            Bit8Val,
+            SetVal,
+            GeometryVal,
+            PointVal,
+            DecimalVal,
+            UdecimalVal,
+            MappingEnumVal,
+        ),
+    > {
+        let (
+            bool_val,
+            tinyint_val,
+            smallint_val,
+            int_val,
+            int_nullable_val,
+            bigint_val,
+            float_val,
+            double_val,
+            text_val,
+            blob_val,
+            timestamp_val,
+            datetime_val,
+            date_val,
+            time_val,
+            json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            (),
+        ) = self.fields;
+        let _phantom = self._phantom;
+        InsertMappingBuilder {
+            fields: (
+                bool_val,
+                tinyint_val,
+                smallint_val,
+                int_val,
+                int_nullable_val,
+                bigint_val,
+                float_val,
+                double_val,
+                text_val,
+                blob_val,
+                timestamp_val,
+                datetime_val,
+                date_val,
+                time_val,
+                json_val,
+                year_val,
+                bit_val,
+                bit_8_val,
+                set_val,
+                geometry_val,
+                point_val,
+                decimal_val,
+                udecimal_val,
+                enum_val,
+            ),
+            _phantom,
+        }
+    }
+}
+impl<'a>
+    InsertMappingBuilder<
+        'a,
+        (
+            bool,
+            i8,
+            i16,
+            i32,
+            Option<i32>,
+            i64,
+            f32,
+            f64,
+            &'a str,
+            &'a [u8],
+            chrono::DateTime<chrono::Utc>,
+            chrono::NaiveDateTime,
+            chrono::NaiveDate,
+            sqlx::mysql::types::MySqlTime,
+            &'a serde_json::Value,
+            u16,
+            bool,
+            u64,
+            &'a MySqlSet,
+            &'a MySqlGeometry,
+            &'a MySqlGeometry,
+            &'a str,
+            &'a str,
+            MappingEnumVal,
         ),
     >
 {
@@ -1786,6 +4006,15 @@ impl<'a>
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         ) = self.fields;
         InsertMapping {
             bool_val,
@@ -1803,6 +4032,15 @@ impl<'a>
             date_val,
             time_val,
             json_val,
+            year_val,
+            bit_val,
+            bit_8_val,
+            set_val,
+            geometry_val,
+            point_val,
+            decimal_val,
+            udecimal_val,
+            enum_val,
         }
     }
 }
@@ -113,4 +113,13 @@ INSERT INTO mapping
     $33,
     $34,
     $35
-);
\ No newline at end of file
+);
+
+-- name: GetMatrix :one
+SELECT int_matrix_val, text_matrix_val FROM matrix;
+
+-- name: InsertMatrix :exec
+INSERT INTO matrix (int_matrix_val, text_matrix_val) VALUES ($1, $2);
+
+-- name: CountMapping :one
+SELECT count(*) FROM mapping WHERE $1 IS NULL OR int_val = $2;
@@ -48,4 +48,9 @@ CREATE TABLE mapping (
     circle_val CIRCLE NOT NULL,
 
     id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY
-);
\ No newline at end of file
+);
+
+CREATE TABLE matrix (
+    int_matrix_val INT[][] NOT NULL,
+    text_matrix_val TEXT[][]
+);
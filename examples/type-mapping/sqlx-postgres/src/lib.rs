@@ -129,5 +129,68 @@ mod tests {
         q.execute(pool).await.unwrap();
 
         let _row = queries::GetMapping.query_one(pool).await.unwrap();
+
+        // `$1` has no inferred type and accepts any bindable value
+        let row = queries::CountMapping::builder()
+            .column_1(None::<String>)
+            .int_val(0)
+            .build()
+            .query_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(row.count, 1);
+        let row = queries::CountMapping::builder()
+            .column_1("filter")
+            .int_val(0)
+            .build()
+            .query_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(row.count, 0);
+    }
+
+    #[test_context(SqlxPgContext)]
+    #[tokio::test]
+    async fn test_matrix(ctx: &mut SqlxPgContext) {
+        let pool = &ctx.pool;
+        migrate_db(pool).await;
+
+        let int_matrix_val = queries::PgNdArray::from_parts(
+            vec![1, 2, 3, 4, 5, 6],
+            vec![
+                queries::PgNdArrayDim {
+                    len: 2,
+                    lower_bound: 1,
+                },
+                queries::PgNdArrayDim {
+                    len: 3,
+                    lower_bound: 1,
+                },
+            ],
+        );
+        let text_matrix_val = queries::PgNdArray::from_parts(
+            vec!["a".to_string(), "b".to_string()],
+            vec![
+                queries::PgNdArrayDim {
+                    len: 1,
+                    lower_bound: 0,
+                },
+                queries::PgNdArrayDim {
+                    len: 2,
+                    lower_bound: 0,
+                },
+            ],
+        );
+
+        let q = queries::InsertMatrix::builder()
+            .int_matrix_val(&int_matrix_val)
+            .text_matrix_val(Some(&text_matrix_val))
+            .build();
+        q.execute(pool).await.unwrap();
+
+        let row = queries::GetMatrix.query_one(pool).await.unwrap();
+        assert_eq!(row.int_matrix_val, int_matrix_val);
+        assert_eq!(row.int_matrix_val.get(&[2, 1]), Some(&4));
+        assert_eq!(row.text_matrix_val, Some(text_matrix_val));
     }
 }
@@ -65,6 +65,157 @@ impl<C: std::ops::DerefMut<Target = sqlx::PgConnection>> CopyDataSink<C> {
         Ok(())
     }
 }
+/// Multi-dimensional array stored in row-major order
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgNdArray<T> {
+    dims: Vec<PgNdArrayDim>,
+    elements: Vec<T>,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgNdArrayDim {
+    pub len: usize,
+    pub lower_bound: i32,
+}
+/// Element types which can be decoded from a multi-dimensional array
+pub trait PgNdArrayElement: Sized {
+    fn decode_element(value: Option<&[u8]>) -> Result<Self, sqlx::error::BoxDynError>;
+}
+impl<T> PgNdArray<T> {
+    /// Create an array from row-major `elements`.
+    ///
+    /// Panics if the number of elements does not match `dims`.
+    pub fn from_parts(elements: Vec<T>, dims: Vec<PgNdArrayDim>) -> Self {
+        let expected = if dims.is_empty() {
+            0
+        } else {
+            dims.iter().map(|d| d.len).product()
+        };
+        assert_eq!(elements.len(), expected, "elements do not match dimensions");
+        Self { dims, elements }
+    }
+    pub fn dims(&self) -> &[PgNdArrayDim] {
+        &self.dims
+    }
+    pub fn elements(&self) -> &[T] {
+        &self.elements
+    }
+    pub fn into_elements(self) -> Vec<T> {
+        self.elements
+    }
+    /// Get an element by Postgres indices, which start at each dimension's lower bound
+    pub fn get(&self, indices: &[i32]) -> Option<&T> {
+        if indices.len() != self.dims.len() {
+            return None;
+        }
+        let mut offset = 0;
+        for (idx, dim) in indices.iter().zip(self.dims.iter()) {
+            let pos = usize::try_from(idx.checked_sub(dim.lower_bound)?).ok()?;
+            if pos >= dim.len {
+                return None;
+            }
+            offset = offset * dim.len + pos;
+        }
+        self.elements.get(offset)
+    }
+}
+impl<T: sqlx::postgres::PgHasArrayType> sqlx::Type<sqlx::Postgres> for PgNdArray<T> {
+    fn type_info() -> sqlx::postgres::PgTypeInfo {
+        T::array_type_info()
+    }
+    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+impl<'q, T> sqlx::Encode<'q, sqlx::Postgres> for PgNdArray<T>
+where
+    T: sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut sqlx::postgres::PgArgumentBuffer,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        let oid = T::type_info()
+            .oid()
+            .ok_or("multi-dimensional arrays require an element type with a known OID")?;
+        buf.extend(i32::try_from(self.dims.len())?.to_be_bytes());
+        buf.extend(0_i32.to_be_bytes());
+        buf.extend(oid.0.to_be_bytes());
+        for dim in &self.dims {
+            buf.extend(i32::try_from(dim.len)?.to_be_bytes());
+            buf.extend(dim.lower_bound.to_be_bytes());
+        }
+        for element in &self.elements {
+            let offset = buf.len();
+            buf.extend(0_i32.to_be_bytes());
+            let len = match element.encode_by_ref(buf)? {
+                sqlx::encode::IsNull::Yes => -1_i32,
+                sqlx::encode::IsNull::No => i32::try_from(buf.len() - offset - 4)?,
+            };
+            buf[offset..offset + 4].copy_from_slice(&len.to_be_bytes());
+        }
+        Ok(sqlx::encode::IsNull::No)
+    }
+}
+impl<'r, T: PgNdArrayElement> sqlx::Decode<'r, sqlx::Postgres> for PgNdArray<T> {
+    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        fn take<'a>(buf: &mut &'a [u8], n: usize) -> Result<&'a [u8], sqlx::error::BoxDynError> {
+            if buf.len() < n {
+                return Err("unexpected end of array data".into());
+            }
+            let (head, tail) = buf.split_at(n);
+            *buf = tail;
+            Ok(head)
+        }
+        fn take_i32(buf: &mut &[u8]) -> Result<i32, sqlx::error::BoxDynError> {
+            Ok(i32::from_be_bytes(take(buf, 4)?.try_into()?))
+        }
+        if value.format() != sqlx::postgres::PgValueFormat::Binary {
+            return Err("multi-dimensional arrays can only be decoded from binary format".into());
+        }
+        let mut buf = value.as_bytes()?;
+        let ndim = usize::try_from(take_i32(&mut buf)?)?;
+        let _flags = take_i32(&mut buf)?;
+        let _element_oid = take_i32(&mut buf)?;
+        let mut dims = Vec::with_capacity(ndim);
+        for _ in 0..ndim {
+            let len = usize::try_from(take_i32(&mut buf)?)?;
+            let lower_bound = take_i32(&mut buf)?;
+            dims.push(PgNdArrayDim { len, lower_bound });
+        }
+        let total = if dims.is_empty() {
+            0
+        } else {
+            dims.iter().map(|d| d.len).product()
+        };
+        let mut elements = Vec::with_capacity(total);
+        for _ in 0..total {
+            let len = take_i32(&mut buf)?;
+            let element = match usize::try_from(len) {
+                Ok(len) => Some(take(&mut buf, len)?),
+                Err(_) => None,
+            };
+            elements.push(T::decode_element(element)?);
+        }
+        Ok(Self { dims, elements })
+    }
+}
+impl<T: PgNdArrayElement> PgNdArrayElement for Option<T> {
+    fn decode_element(value: Option<&[u8]>) -> Result<Self, sqlx::error::BoxDynError> {
+        value.map(|v| T::decode_element(Some(v))).transpose()
+    }
+}
+impl PgNdArrayElement for i32 {
+    fn decode_element(value: Option<&[u8]>) -> Result<Self, sqlx::error::BoxDynError> {
+        let bytes = value.ok_or(sqlx::error::UnexpectedNullError)?;
+        Ok(<i32>::from_be_bytes(bytes.try_into()?))
+    }
+}
+impl PgNdArrayElement for String {
+    fn decode_element(value: Option<&[u8]>) -> Result<Self, sqlx::error::BoxDynError> {
+        let bytes = value.ok_or(sqlx::error::UnexpectedNullError)?;
+        Ok(std::str::from_utf8(bytes)?.to_owned())
+    }
+}
 #[derive(Debug, Clone, Copy, sqlx::Type)]
 #[sqlx(type_name = "mood")]
 pub enum Mood {
@@ -7746,3 +7897,272 @@ impl<'a>
         }
     }
 }
+#[derive(sqlx::FromRow)]
+pub struct GetMatrixRow {
+    #[sqlx(rename = "int_matrix_val")]
+    pub int_matrix_val: PgNdArray<i32>,
+    #[sqlx(rename = "text_matrix_val")]
+    pub text_matrix_val: Option<PgNdArray<String>>,
+}
+pub struct GetMatrix;
+impl GetMatrix {
+    pub const QUERY: &'static str = r"SELECT int_matrix_val, text_matrix_val FROM matrix";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetMatrix {
+    pub fn query_as<'a>(
+        &'a self,
+    ) -> sqlx::query::QueryAs<
+        'a,
+        sqlx::Postgres,
+        GetMatrixRow,
+        <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+    > {
+        let q = sqlx::query_as(self.query_str());
+        q
+    }
+    pub fn query_one<'a, 'b, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = Result<GetMatrixRow, sqlx::Error>> + Send + 'a
+    where
+        A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let val = self.query_as().fetch_one(&mut *conn).await?;
+            Ok(val)
+        }
+    }
+    pub fn query_opt<'a, 'b, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = Result<Option<GetMatrixRow>, sqlx::Error>> + Send + 'a
+    where
+        A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let val = self.query_as().fetch_optional(&mut *conn).await?;
+            Ok(val)
+        }
+    }
+}
+impl GetMatrix {
+    pub const fn builder() -> GetMatrixBuilder<'static, ()> {
+        GetMatrixBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetMatrixBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetMatrixBuilder<'a, ()> {
+    pub fn build(self) -> GetMatrix {
+        let () = self.fields;
+        GetMatrix {}
+    }
+}
+#[derive(sqlx::FromRow)]
+pub struct InsertMatrixRow {}
+pub struct InsertMatrix<'a> {
+    int_matrix_val: &'a PgNdArray<i32>,
+    text_matrix_val: Option<&'a PgNdArray<String>>,
+}
+impl<'a> InsertMatrix<'a> {
+    pub const QUERY: &'static str =
+        r"INSERT INTO matrix (int_matrix_val, text_matrix_val) VALUES ($1, $2)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> InsertMatrix<'a> {
+    pub fn query_as(
+        &'a self,
+    ) -> sqlx::query::QueryAs<
+        'a,
+        sqlx::Postgres,
+        InsertMatrixRow,
+        <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+    > {
+        let q = sqlx::query_as(self.query_str());
+        let q = q.bind(self.int_matrix_val);
+        let q = q.bind(self.text_matrix_val);
+        q
+    }
+    pub fn execute<'b, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = Result<<sqlx::Postgres as sqlx::Database>::QueryResult, sqlx::Error>>
+    + Send
+    + 'a
+    where
+        A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let q = sqlx::query(self.query_str());
+            let q = q.bind(self.int_matrix_val);
+            let q = q.bind(self.text_matrix_val);
+            q.execute(&mut *conn).await
+        }
+    }
+}
+impl<'a> InsertMatrix<'a> {
+    pub const fn builder() -> InsertMatrixBuilder<'a, ((), ())> {
+        InsertMatrixBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct InsertMatrixBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, TextMatrixVal> InsertMatrixBuilder<'a, ((), TextMatrixVal)> {
+    pub fn int_matrix_val(
+        self,
+        int_matrix_val: &'a PgNdArray<i32>,
+    ) -> InsertMatrixBuilder<'a, (&'a PgNdArray<i32>, TextMatrixVal)> {
+        let ((), text_matrix_val) = self.fields;
+        let _phantom = self._phantom;
+        InsertMatrixBuilder {
+            fields: (int_matrix_val, text_matrix_val),
+            _phantom,
+        }
+    }
+}
+impl<'a, IntMatrixVal> InsertMatrixBuilder<'a, (IntMatrixVal, ())> {
+    pub fn text_matrix_val(
+        self,
+        text_matrix_val: Option<&'a PgNdArray<String>>,
+    ) -> InsertMatrixBuilder<'a, (IntMatrixVal, Option<&'a PgNdArray<String>>)> {
+        let (int_matrix_val, ()) = self.fields;
+        let _phantom = self._phantom;
+        InsertMatrixBuilder {
+            fields: (int_matrix_val, text_matrix_val),
+            _phantom,
+        }
+    }
+}
+impl<'a> InsertMatrixBuilder<'a, (&'a PgNdArray<i32>, Option<&'a PgNdArray<String>>)> {
+    pub fn build(self) -> InsertMatrix<'a> {
+        let (int_matrix_val, text_matrix_val) = self.fields;
+        InsertMatrix {
+            int_matrix_val,
+            text_matrix_val,
+        }
+    }
+}
+#[derive(sqlx::FromRow)]
+pub struct CountMappingRow {
+    #[sqlx(rename = "count")]
+    pub count: i64,
+}
+pub struct CountMapping<Column1Value> {
+    column_1: Column1Value,
+    int_val: i32,
+}
+impl<Column1Value: for<'q> sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres> + Sync>
+    CountMapping<Column1Value>
+{
+    pub const QUERY: &'static str =
+        r"SELECT count(*) FROM mapping WHERE $1 IS NULL OR int_val = $2";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<Column1Value: for<'q> sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres> + Sync>
+    CountMapping<Column1Value>
+{
+    pub fn query_as<'a>(
+        &'a self,
+    ) -> sqlx::query::QueryAs<
+        'a,
+        sqlx::Postgres,
+        CountMappingRow,
+        <sqlx::Postgres as sqlx::Database>::Arguments<'a>,
+    > {
+        let q = sqlx::query_as(self.query_str());
+        let q = q.bind(&self.column_1);
+        let q = q.bind(self.int_val);
+        q
+    }
+    pub fn query_one<'a, 'b, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = Result<CountMappingRow, sqlx::Error>> + Send + 'a
+    where
+        A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let val = self.query_as().fetch_one(&mut *conn).await?;
+            Ok(val)
+        }
+    }
+    pub fn query_opt<'a, 'b, A>(
+        &'a self,
+        conn: A,
+    ) -> impl Future<Output = Result<Option<CountMappingRow>, sqlx::Error>> + Send + 'a
+    where
+        A: sqlx::Acquire<'b, Database = sqlx::Postgres> + Send + 'a,
+    {
+        async move {
+            let mut conn = conn.acquire().await?;
+            let val = self.query_as().fetch_optional(&mut *conn).await?;
+            Ok(val)
+        }
+    }
+}
+impl CountMapping<()> {
+    pub const fn builder() -> CountMappingBuilder<'static, ((), ())> {
+        CountMappingBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct CountMappingBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, IntVal> CountMappingBuilder<'a, ((), IntVal)> {
+    pub fn column_1<
+        Column1Value: for<'q> sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres> + Sync,
+    >(
+        self,
+        column_1: Column1Value,
+    ) -> CountMappingBuilder<'a, (Column1Value, IntVal)> {
+        let ((), int_val) = self.fields;
+        let _phantom = self._phantom;
+        CountMappingBuilder {
+            fields: (column_1, int_val),
+            _phantom,
+        }
+    }
+}
+impl<'a, Column1> CountMappingBuilder<'a, (Column1, ())> {
+    pub fn int_val(self, int_val: i32) -> CountMappingBuilder<'a, (Column1, i32)> {
+        let (column_1, ()) = self.fields;
+        let _phantom = self._phantom;
+        CountMappingBuilder {
+            fields: (column_1, int_val),
+            _phantom,
+        }
+    }
+}
+impl<'a, Column1Value: for<'q> sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres> + Sync>
+    CountMappingBuilder<'a, (Column1Value, i32)>
+{
+    pub fn build(self) -> CountMapping<Column1Value> {
+        let (column_1, int_val) = self.fields;
+        CountMapping { column_1, int_val }
+    }
+}
@@ -25,6 +25,8 @@ mod tests {
         let datetime_val = chrono::NaiveDateTime::from_str("2025-01-23T04:05:06").unwrap();
         let date_val = chrono::NaiveDate::from_ymd_opt(2025, 1, 23).unwrap();
         let time_val = chrono::NaiveTime::from_hms_opt(1, 23, 45).unwrap();
+        let numeric_val = queries::SqliteNumeric::Real(26.1);
+        let decimal_val = queries::SqliteNumeric::from(27.1);
 
         let q = queries::InsertMapping::builder()
             .aff_integer_val(1)
@@ -52,16 +54,19 @@ mod tests {
             .double_val(23.0)
             .double_precision_val(24.0)
             .float_val(25.0)
-            .numeric_val(26.1)
-            .decimal_10_5_val(27.1)
+            .numeric_val(&numeric_val)
+            .decimal_10_5_val(&decimal_val)
             .boolean_val(true)
-            .date_val(&date_val)
-            .time_val(&time_val)
-            .datetime_val(&datetime_val)
+            .date_val(date_val)
+            .time_val(time_val)
+            .datetime_val(datetime_val)
             .build();
 
         q.execute(pool).await.unwrap();
 
-        let _row = queries::GetMapping.query_one(pool).await.unwrap();
+        let row = queries::GetMapping.query_one(pool).await.unwrap();
+        assert_eq!(row.numeric_val.as_f64(), Some(26.1));
+        assert_eq!(row.decimal_10_5_val.as_f64(), Some(27.1));
+        assert_eq!(row.date_val, date_val);
     }
 }
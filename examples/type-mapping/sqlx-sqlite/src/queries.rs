@@ -2,6 +2,87 @@
 //! sqlc version: v1.28.0
 //! sqlc-gen-rust version: v0.1.12
 
+/// Value of a NUMERIC affinity column, kept in the storage class SQLite returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqliteNumeric {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+impl SqliteNumeric {
+    /// Convert to `f64`. Large integers and non-numeric text may lose precision or return `None`
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(v) => Some(*v as f64),
+            Self::Real(v) => Some(*v),
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+    /// Convert to `i64` if the value is integral
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(v) => Some(*v),
+            Self::Real(v) if v.fract() == 0.0 => Some(*v as i64),
+            Self::Real(_) => None,
+            Self::Text(v) => v.parse().ok(),
+        }
+    }
+}
+impl From<i64> for SqliteNumeric {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+impl From<f64> for SqliteNumeric {
+    fn from(value: f64) -> Self {
+        Self::Real(value)
+    }
+}
+impl From<String> for SqliteNumeric {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl sqlx::Type<sqlx::Sqlite> for SqliteNumeric {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <f64 as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+    fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+        <i64 as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+            || <f64 as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+            || <String as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+    }
+}
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for SqliteNumeric {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        match self {
+            Self::Integer(v) => <i64 as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+            Self::Real(v) => <f64 as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+            Self::Text(v) => <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+        }
+    }
+}
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for SqliteNumeric {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        use sqlx::{TypeInfo as _, ValueRef as _};
+        let storage_class = value.type_info().name().to_owned();
+        match storage_class.as_str() {
+            "INTEGER" => Ok(Self::Integer(<i64 as sqlx::Decode<sqlx::Sqlite>>::decode(
+                value,
+            )?)),
+            "REAL" => Ok(Self::Real(<f64 as sqlx::Decode<sqlx::Sqlite>>::decode(
+                value,
+            )?)),
+            "TEXT" => Ok(Self::Text(<String as sqlx::Decode<sqlx::Sqlite>>::decode(
+                value,
+            )?)),
+            _ => Err(format!("cannot decode {storage_class} as NUMERIC").into()),
+        }
+    }
+}
 #[derive(sqlx::FromRow)]
 pub struct GetMappingRow {
     #[sqlx(rename = "aff_integer_val")]
@@ -55,9 +136,9 @@ pub struct GetMappingRow {
     #[sqlx(rename = "float_val")]
     pub float_val: f64,
     #[sqlx(rename = "numeric_val")]
-    pub numeric_val: f64,
+    pub numeric_val: SqliteNumeric,
     #[sqlx(rename = "decimal10_5_val")]
-    pub decimal_10_5_val: f64,
+    pub decimal_10_5_val: SqliteNumeric,
     #[sqlx(rename = "boolean_val")]
     pub boolean_val: bool,
     #[sqlx(rename = "date_val")]
@@ -191,12 +272,12 @@ pub struct InsertMapping<'a> {
     double_val: f64,
     double_precision_val: f64,
     float_val: f64,
-    numeric_val: f64,
-    decimal_10_5_val: f64,
+    numeric_val: &'a SqliteNumeric,
+    decimal_10_5_val: &'a SqliteNumeric,
     boolean_val: bool,
-    date_val: &'a chrono::NaiveDate,
-    time_val: &'a chrono::NaiveTime,
-    datetime_val: &'a chrono::NaiveDateTime,
+    date_val: chrono::NaiveDate,
+    time_val: chrono::NaiveTime,
+    datetime_val: chrono::NaiveDateTime,
 }
 impl<'a> InsertMapping<'a> {
     pub const QUERY: &'static str = r"INSERT INTO mapping (
@@ -5069,7 +5150,7 @@ impl<
 {
     pub fn numeric_val(
         self,
-        numeric_val: f64,
+        numeric_val: &'a SqliteNumeric,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -5098,7 +5179,7 @@ impl<
             DoubleVal,
             DoublePrecisionVal,
             FloatVal,
-            f64,
+            &'a SqliteNumeric,
             Decimal105Val,
             BooleanVal,
             DateVal,
@@ -5250,7 +5331,7 @@ impl<
 {
     pub fn decimal_10_5_val(
         self,
-        decimal_10_5_val: f64,
+        decimal_10_5_val: &'a SqliteNumeric,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -5280,7 +5361,7 @@ impl<
             DoublePrecisionVal,
             FloatVal,
             NumericVal,
-            f64,
+            &'a SqliteNumeric,
             BooleanVal,
             DateVal,
             TimeVal,
@@ -5612,7 +5693,7 @@ impl<
 {
     pub fn date_val(
         self,
-        date_val: &'a chrono::NaiveDate,
+        date_val: chrono::NaiveDate,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -5644,7 +5725,7 @@ impl<
             NumericVal,
             Decimal105Val,
             BooleanVal,
-            &'a chrono::NaiveDate,
+            chrono::NaiveDate,
             TimeVal,
             DatetimeVal,
         ),
@@ -5793,7 +5874,7 @@ impl<
 {
     pub fn time_val(
         self,
-        time_val: &'a chrono::NaiveTime,
+        time_val: chrono::NaiveTime,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -5826,7 +5907,7 @@ impl<
             Decimal105Val,
             BooleanVal,
             DateVal,
-            &'a chrono::NaiveTime,
+            chrono::NaiveTime,
             DatetimeVal,
         ),
     > {
@@ -5974,7 +6055,7 @@ impl<
 {
     pub fn datetime_val(
         self,
-        datetime_val: &'a chrono::NaiveDateTime,
+        datetime_val: chrono::NaiveDateTime,
     ) -> InsertMappingBuilder<
         'a,
         (
@@ -6008,7 +6089,7 @@ impl<
             BooleanVal,
             DateVal,
             TimeVal,
-            &'a chrono::NaiveDateTime,
+            chrono::NaiveDateTime,
         ),
     > {
         let (
@@ -6112,12 +6193,12 @@ impl<'a>
             f64,
             f64,
             f64,
-            f64,
-            f64,
+            &'a SqliteNumeric,
+            &'a SqliteNumeric,
             bool,
-            &'a chrono::NaiveDate,
-            &'a chrono::NaiveTime,
-            &'a chrono::NaiveDateTime,
+            chrono::NaiveDate,
+            chrono::NaiveTime,
+            chrono::NaiveDateTime,
         ),
     >
 {
@@ -79,3 +79,9 @@ INSERT INTO mapping
     $23, 
     $24
 );
+
+-- name: GetMatrix :one
+SELECT int_matrix_val, text_matrix_val FROM matrix;
+
+-- name: InsertMatrix :exec
+INSERT INTO matrix (int_matrix_val, text_matrix_val) VALUES ($1, $2);
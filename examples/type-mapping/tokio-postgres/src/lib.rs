@@ -77,4 +77,38 @@ mod tests {
 
         let _row = queries::GetMapping.query_one(client).await.unwrap();
     }
+
+    #[test_context(PgTokioTestContext)]
+    #[tokio::test]
+    async fn test_matrix(ctx: &mut PgTokioTestContext) {
+        let client = &ctx.client;
+        migrate_db(client).await;
+
+        let mut int_matrix_val = postgres_array::Array::from_vec(vec![1, 2, 3], 0);
+        int_matrix_val.wrap(0);
+        int_matrix_val.push(postgres_array::Array::from_vec(vec![4, 5, 6], 0));
+        let text_matrix_val = postgres_array::Array::from_parts(
+            vec!["a".to_string(), "b".to_string()],
+            vec![
+                postgres_array::Dimension {
+                    len: 1,
+                    lower_bound: 1,
+                },
+                postgres_array::Dimension {
+                    len: 2,
+                    lower_bound: 1,
+                },
+            ],
+        );
+
+        let q = queries::InsertMatrix::builder()
+            .int_matrix_val(&int_matrix_val)
+            .text_matrix_val(Some(&text_matrix_val))
+            .build();
+        q.execute(client).await.unwrap();
+
+        let row = queries::GetMatrix.query_one(client).await.unwrap();
+        assert_eq!(row.int_matrix_val, int_matrix_val);
+        assert_eq!(row.text_matrix_val, Some(text_matrix_val));
+    }
 }
@@ -3964,3 +3964,160 @@ impl<'a>
         }
     }
 }
+pub struct GetMatrixRow {
+    pub int_matrix_val: postgres_array::Array<i32>,
+    pub text_matrix_val: Option<postgres_array::Array<String>>,
+}
+impl GetMatrixRow {
+    pub fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+        Ok(Self {
+            int_matrix_val: row.try_get(0)?,
+            text_matrix_val: row.try_get(1)?,
+        })
+    }
+}
+pub struct GetMatrix;
+impl GetMatrix {
+    pub const QUERY: &'static str = r"SELECT int_matrix_val, text_matrix_val FROM matrix";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl GetMatrix {
+    pub async fn query_one(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<GetMatrixRow, tokio_postgres::Error> {
+        let stmt = self.prepare(client).await?;
+        let row = client.query_one(&stmt, &self.as_params()).await?;
+        GetMatrixRow::from_row(&row)
+    }
+    pub async fn query_opt(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<Option<GetMatrixRow>, tokio_postgres::Error> {
+        let stmt = self.prepare(client).await?;
+        let row = client.query_opt(&stmt, &self.as_params()).await?;
+        match row {
+            Some(row) => Ok(Some(GetMatrixRow::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+    pub async fn prepare(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+        client.prepare(self.query_str()).await
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 0] {
+        []
+    }
+}
+impl GetMatrix {
+    pub const fn builder() -> GetMatrixBuilder<'static, ()> {
+        GetMatrixBuilder {
+            fields: (),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct GetMatrixBuilder<'a, Fields = ()> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a> GetMatrixBuilder<'a, ()> {
+    pub fn build(self) -> GetMatrix {
+        let () = self.fields;
+        GetMatrix {}
+    }
+}
+pub struct InsertMatrixRow {}
+impl InsertMatrixRow {
+    pub fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+        Ok(Self {})
+    }
+}
+pub struct InsertMatrix<'a> {
+    int_matrix_val: &'a postgres_array::Array<i32>,
+    text_matrix_val: Option<&'a postgres_array::Array<String>>,
+}
+impl<'a> InsertMatrix<'a> {
+    pub const QUERY: &'static str =
+        r"INSERT INTO matrix (int_matrix_val, text_matrix_val) VALUES ($1, $2)";
+    pub fn query_str(&self) -> &str {
+        Self::QUERY
+    }
+}
+impl<'a> InsertMatrix<'a> {
+    pub async fn execute(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<u64, tokio_postgres::Error> {
+        let stmt = self.prepare(client).await?;
+        client.execute(&stmt, &self.as_params()).await
+    }
+    pub async fn prepare(
+        &self,
+        client: &impl tokio_postgres::GenericClient,
+    ) -> Result<tokio_postgres::Statement, tokio_postgres::Error> {
+        client.prepare(self.query_str()).await
+    }
+    pub fn as_params(&self) -> [&(dyn ToSql + Sync); 2] {
+        [&self.int_matrix_val, &self.text_matrix_val]
+    }
+}
+impl<'a> InsertMatrix<'a> {
+    pub const fn builder() -> InsertMatrixBuilder<'a, ((), ())> {
+        InsertMatrixBuilder {
+            fields: ((), ()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+pub struct InsertMatrixBuilder<'a, Fields = ((), ())> {
+    fields: Fields,
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+impl<'a, TextMatrixVal> InsertMatrixBuilder<'a, ((), TextMatrixVal)> {
+    pub fn int_matrix_val(
+        self,
+        int_matrix_val: &'a postgres_array::Array<i32>,
+    ) -> InsertMatrixBuilder<'a, (&'a postgres_array::Array<i32>, TextMatrixVal)> {
+        let ((), text_matrix_val) = self.fields;
+        let _phantom = self._phantom;
+        InsertMatrixBuilder {
+            fields: (int_matrix_val, text_matrix_val),
+            _phantom,
+        }
+    }
+}
+impl<'a, IntMatrixVal> InsertMatrixBuilder<'a, (IntMatrixVal, ())> {
+    pub fn text_matrix_val(
+        self,
+        text_matrix_val: Option<&'a postgres_array::Array<String>>,
+    ) -> InsertMatrixBuilder<'a, (IntMatrixVal, Option<&'a postgres_array::Array<String>>)> {
+        let (int_matrix_val, ()) = self.fields;
+        let _phantom = self._phantom;
+        InsertMatrixBuilder {
+            fields: (int_matrix_val, text_matrix_val),
+            _phantom,
+        }
+    }
+}
+impl<'a>
+    InsertMatrixBuilder<
+        'a,
+        (
+            &'a postgres_array::Array<i32>,
+            Option<&'a postgres_array::Array<String>>,
+        ),
+    >
+{
+    pub fn build(self) -> InsertMatrix<'a> {
+        let (int_matrix_val, text_matrix_val) = self.fields;
+        InsertMatrix {
+            int_matrix_val,
+            text_matrix_val,
+        }
+    }
+}
@@ -0,0 +1,613 @@
+use super::{DbCrate, DbCrateConfig};
+use crate::{
+    query::{
+        Annotation, DbComposite, DbEnum, DbNewtype, Query, ReturningRows, RsColType, RsType,
+        TypeMapper,
+    },
+    value_ident,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Duckdb;
+
+impl<'de> serde::Deserialize<'de> for Duckdb {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.trim() {
+            "duckdb" => Ok(Self),
+            _ => Err(serde::de::Error::custom(format!(
+                "`{s}` is unsupported crate."
+            ))),
+        }
+    }
+}
+
+/// Nest `DuckdbList` `dim` times around `inner`
+fn list_type(dim: usize, inner: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    (0..dim).fold(inner, |typ, _| quote::quote! {DuckdbList<#typ>})
+}
+
+/// Convert a fetched `DuckdbList` of `dim` dimensions into nested `Vec`
+fn unwrap_list(dim: usize, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if dim <= 1 {
+        return quote::quote! {#value.0};
+    }
+    let inner = unwrap_list(dim - 1, quote::quote! {v});
+    quote::quote! {#value.0.into_iter().map(|v| #inner).collect()}
+}
+
+/// Wrap a slice parameter of `dim` dimensions into `DuckdbList`
+fn wrap_list(dim: usize, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let elements = if dim <= 1 {
+        quote::quote! {#value.iter().collect::<Vec<_>>()}
+    } else {
+        let inner = wrap_list(dim - 1, quote::quote! {v});
+        quote::quote! {#value.iter().map(|v| #inner).collect::<Vec<_>>()}
+    };
+    quote::quote! {DuckdbList(#elements)}
+}
+
+impl Duckdb {
+    /// Decode a column, `get` fetches the given `FromSql` type or the inferred one if `None`.
+    /// Lists are fetched through `DuckdbList` because duckdb only decodes them as `Value::List`
+    fn decode(
+        typ: &RsColType,
+        get: impl Fn(Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        if typ.is_array() {
+            let list = list_type(typ.dim(), quote::quote! {_});
+            if typ.is_optional() {
+                let value = get(Some(quote::quote! {Option<#list>}));
+                let unwrap = unwrap_list(typ.dim(), quote::quote! {v});
+                quote::quote! {#value.map(|v| #unwrap)}
+            } else {
+                unwrap_list(typ.dim(), get(Some(list)))
+            }
+        } else if typ.needs_conversion() {
+            typ.unwrap_fetched(get(Some(typ.to_fetch_tokens())))
+        } else {
+            get(None)
+        }
+    }
+
+    fn returning_row(row: &ReturningRows) -> proc_macro2::TokenStream {
+        let row_struct = super::make_return_row(row);
+
+        let ident = row.struct_ident();
+        let arg_ident = quote::format_ident!("row");
+
+        let from_fields = row.fields.iter().enumerate().map(|(idx, field)| {
+            let field_ident = &field.name;
+            let literal = proc_macro2::Literal::usize_unsuffixed(idx);
+            let value = Self::decode(&field.typ, |typ| match typ {
+                Some(typ) => quote::quote! {#arg_ident.get::<_, #typ>(#literal)?},
+                None => quote::quote! {#arg_ident.get(#literal)?},
+            });
+            quote::quote! {#field_ident:#value}
+        });
+
+        let from_tt = quote::quote! {
+            impl #ident {
+                pub fn from_row(#arg_ident: &duckdb::Row)->duckdb::Result<Self>{
+                    Ok(Self{
+                        #(#from_fields,)*
+                    })
+                }
+            }
+        };
+
+        quote::quote! {
+            #row_struct
+            #from_tt
+        }
+    }
+}
+
+impl DbCrate for Duckdb {
+    /// Creates a new `DbTypeMap` for queries parsed with the postgresql engine.
+    ///
+    /// See below
+    /// - https://duckdb.org/docs/sql/data_types/overview
+    /// - https://docs.rs/duckdb/1.1.1/duckdb/types/index.html
+    fn type_map(&self) -> Box<dyn TypeMapper> {
+        let copy_cheap = [
+            ("i8", vec!["tinyint", "int1"]),
+            (
+                "i16",
+                vec!["smallint", "int2", "pg_catalog.int2", "smallserial"],
+            ),
+            (
+                "i32",
+                vec!["integer", "int", "int4", "pg_catalog.int4", "serial"],
+            ),
+            (
+                "i64",
+                vec!["bigint", "int8", "pg_catalog.int8", "bigserial"],
+            ),
+            ("i128", vec!["hugeint", "int128"]),
+            ("u8", vec!["utinyint"]),
+            ("u16", vec!["usmallint"]),
+            ("u32", vec!["uinteger"]),
+            ("u64", vec!["ubigint"]),
+            ("f32", vec!["real", "float4", "pg_catalog.float4"]),
+            (
+                "f64",
+                vec![
+                    "double",
+                    "double precision",
+                    "float",
+                    "float8",
+                    "pg_catalog.float8",
+                ],
+            ),
+            ("bool", vec!["boolean", "bool", "pg_catalog.bool"]),
+            ("uuid::Uuid", vec!["uuid"]),
+            (
+                "rust_decimal::Decimal",
+                vec!["decimal", "numeric", "pg_catalog.numeric"],
+            ),
+            ("chrono::NaiveDate", vec!["date"]),
+            ("chrono::NaiveTime", vec!["time", "pg_catalog.time"]),
+            (
+                "chrono::NaiveDateTime",
+                vec!["timestamp", "pg_catalog.timestamp", "datetime"],
+            ),
+            (
+                "chrono::DateTime<chrono::Utc>",
+                vec!["timestamptz", "pg_catalog.timestamptz"],
+            ),
+        ];
+
+        let default_types = [
+            (
+                ("String", Some("str")),
+                vec![
+                    "text",
+                    "varchar",
+                    "pg_catalog.varchar",
+                    "pg_catalog.bpchar",
+                    "string",
+                    // duckdb returns JSON as text
+                    "json",
+                ],
+            ),
+            (
+                ("Vec<u8>", Some("[u8]")),
+                vec!["bytea", "blob", "pg_catalog.bytea"],
+            ),
+        ];
+
+        let mut map = crate::query::SimpleTypeMap::default();
+
+        for (owned_type, db_types) in copy_cheap {
+            let owned_type = syn::parse_str::<syn::Type>(owned_type).expect("Failed to parse type");
+
+            for db_type in db_types {
+                map.insert_db_type(db_type, RsType::new(owned_type.clone(), None, true));
+            }
+        }
+
+        for ((owned_type, slice_type), db_types) in default_types {
+            let owned_type = syn::parse_str::<syn::Type>(owned_type).expect("Failed to parse type");
+            let slice_type = slice_type
+                .map(|s| syn::parse_str::<syn::Type>(s).expect("Failed to parse slice type"));
+
+            for db_type in db_types {
+                map.insert_db_type(
+                    db_type,
+                    RsType::new(owned_type.clone(), slice_type.clone(), false),
+                );
+            }
+        }
+        Box::new(map)
+    }
+
+    fn init(&self) -> proc_macro2::TokenStream {
+        quote::quote! {
+            /// Convert a parameter into an owned value to nest it in a `LIST` or `STRUCT`
+            fn to_duckdb_value(value: &dyn duckdb::ToSql) -> duckdb::Result<duckdb::types::Value> {
+                match value.to_sql()? {
+                    duckdb::types::ToSqlOutput::Borrowed(v) => Ok(duckdb::types::Value::from(v)),
+                    duckdb::types::ToSqlOutput::Owned(v) => Ok(v),
+                }
+            }
+
+            /// Value of a DuckDB `LIST` column or parameter
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct DuckdbList<T>(pub Vec<T>);
+
+            impl<T: duckdb::types::FromSql> duckdb::types::FromSql for DuckdbList<T> {
+                fn column_result(value: duckdb::types::ValueRef<'_>) -> duckdb::types::FromSqlResult<Self> {
+                    match duckdb::types::Value::from(value) {
+                        duckdb::types::Value::List(values) => values
+                            .iter()
+                            .map(|v| T::column_result(duckdb::types::ValueRef::from(v)))
+                            .collect::<Result<Vec<_>, _>>()
+                            .map(Self),
+                        _ => Err(duckdb::types::FromSqlError::InvalidType),
+                    }
+                }
+            }
+
+            impl<T: duckdb::ToSql> duckdb::ToSql for DuckdbList<T> {
+                fn to_sql(&self) -> duckdb::Result<duckdb::types::ToSqlOutput<'_>> {
+                    let values = self
+                        .0
+                        .iter()
+                        .map(|v| to_duckdb_value(v))
+                        .collect::<duckdb::Result<Vec<_>>>()?;
+                    Ok(duckdb::types::ToSqlOutput::Owned(duckdb::types::Value::List(values)))
+                }
+            }
+        }
+    }
+
+    fn defined_enum(&self, enum_type: &DbEnum) -> proc_macro2::TokenStream {
+        let derives = &enum_type.derives;
+        let enum_name = enum_type.ident();
+        let idents = enum_type
+            .values
+            .iter()
+            .map(|v| value_ident(v))
+            .collect::<Vec<_>>();
+        let names = &enum_type.values;
+
+        quote::quote! {
+            #[derive(Debug, Clone, Copy, #(#derives),*)]
+            pub enum #enum_name {
+                #(#idents,)*
+            }
+
+            impl duckdb::ToSql for #enum_name {
+                fn to_sql(&self) -> duckdb::Result<duckdb::types::ToSqlOutput<'_>> {
+                    let value = match self {
+                        #(Self::#idents => #names,)*
+                    };
+                    value.to_sql()
+                }
+            }
+
+            impl duckdb::types::FromSql for #enum_name {
+                fn column_result(value: duckdb::types::ValueRef<'_>) -> duckdb::types::FromSqlResult<Self> {
+                    match duckdb::types::Value::from(value) {
+                        duckdb::types::Value::Enum(v) | duckdb::types::Value::Text(v) => match v.as_str() {
+                            #(#names => Ok(Self::#idents),)*
+                            _ => Err(duckdb::types::FromSqlError::InvalidType),
+                        },
+                        _ => Err(duckdb::types::FromSqlError::InvalidType),
+                    }
+                }
+            }
+        }
+    }
+
+    fn defined_newtype(&self, newtype: &DbNewtype) -> proc_macro2::TokenStream {
+        let ident = newtype.ident();
+        let inner = newtype.inner.owned();
+        let derives = super::newtype_derives(newtype);
+        quote::quote! {
+            #[derive(#derives)]
+            pub struct #ident(pub #inner);
+
+            impl duckdb::ToSql for #ident {
+                fn to_sql(&self) -> duckdb::Result<duckdb::types::ToSqlOutput<'_>> {
+                    self.0.to_sql()
+                }
+            }
+
+            impl duckdb::types::FromSql for #ident {
+                fn column_result(value: duckdb::types::ValueRef<'_>) -> duckdb::types::FromSqlResult<Self> {
+                    <#inner as duckdb::types::FromSql>::column_result(value).map(Self)
+                }
+            }
+        }
+    }
+
+    /// Tables used as composite types are read as DuckDB `STRUCT`s
+    fn defined_composite(&self, composite: &DbComposite) -> proc_macro2::TokenStream {
+        let ident = composite.ident();
+        let fields = composite.fields.iter().map(|field| {
+            let name = &field.name;
+            let typ = field.typ.to_row_tokens();
+            quote::quote! {pub #name: #typ}
+        });
+        let from_fields = composite.fields.iter().map(|field| {
+            let name = &field.name;
+            let original_name = &field.name_original;
+            let value = Self::decode(&field.typ, |typ| match typ {
+                Some(typ) => quote::quote! {
+                    <#typ as duckdb::types::FromSql>::column_result(field(#original_name))?
+                },
+                None => quote::quote! {
+                    duckdb::types::FromSql::column_result(field(#original_name))?
+                },
+            });
+            quote::quote! {#name: #value}
+        });
+        let to_fields = composite.fields.iter().map(|field| {
+            let name = &field.name;
+            let original_name = &field.name_original;
+            let dim = field.typ.dim();
+            let value = match (field.typ.is_array(), field.typ.is_optional()) {
+                (false, _) => quote::quote! {&self.#name},
+                (true, false) => {
+                    let list = wrap_list(dim, quote::quote! {self.#name});
+                    quote::quote! {&#list}
+                }
+                (true, true) => {
+                    let list = wrap_list(dim, quote::quote! {v});
+                    quote::quote! {&self.#name.as_ref().map(|v| #list)}
+                }
+            };
+            quote::quote! {(#original_name.to_string(), to_duckdb_value(#value)?)}
+        });
+
+        quote::quote! {
+            #[derive(Debug, Clone)]
+            pub struct #ident {
+                #(#fields,)*
+            }
+
+            impl duckdb::types::FromSql for #ident {
+                fn column_result(value: duckdb::types::ValueRef<'_>) -> duckdb::types::FromSqlResult<Self> {
+                    let duckdb::types::Value::Struct(fields) = duckdb::types::Value::from(value) else {
+                        return Err(duckdb::types::FromSqlError::InvalidType);
+                    };
+                    let field = |name: &str| {
+                        fields
+                            .iter()
+                            .find(|(k, _)| k == name)
+                            .map(|(_, v)| duckdb::types::ValueRef::from(v))
+                            .unwrap_or(duckdb::types::ValueRef::Null)
+                    };
+                    Ok(Self {
+                        #(#from_fields,)*
+                    })
+                }
+            }
+
+            impl duckdb::ToSql for #ident {
+                fn to_sql(&self) -> duckdb::Result<duckdb::types::ToSqlOutput<'_>> {
+                    let fields = Vec::from([#(#to_fields,)*]);
+                    Ok(duckdb::types::ToSqlOutput::Owned(duckdb::types::Value::Struct(
+                        duckdb::types::OrderedMap::from(fields),
+                    )))
+                }
+            }
+        }
+    }
+
+    fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
+        let row_tt = Self::returning_row(row);
+        let query_ast = super::QueryAst::new(query, crate::db_crates::DataBaseKind::Postgres);
+        let builder_tt = query_ast.make_builder();
+
+        // `duckdb::Transaction` derefs to `duckdb::Connection`
+        let client_ident = quote::format_ident!("conn");
+        let client_typ = quote::quote! {&duckdb::Connection};
+        let row_ident = row.struct_ident();
+
+        let query_fns = match query.annotation {
+            Annotation::One => {
+                quote::quote! {
+                    pub fn query_one(&self, #client_ident: #client_typ)->duckdb::Result<#row_ident>{
+                        self.prepare(#client_ident)?
+                            .query_row(self.as_params(), #row_ident::from_row)
+                    }
+
+                    pub fn query_opt(&self, #client_ident: #client_typ)->duckdb::Result<Option<#row_ident>>{
+                        self.prepare(#client_ident)?
+                            .query_map(self.as_params(), #row_ident::from_row)?
+                            .next()
+                            .transpose()
+                    }
+                }
+            }
+            Annotation::Many => {
+                quote::quote! {
+                    pub fn query_many(&self, #client_ident: #client_typ)->duckdb::Result<Vec<#row_ident>>{
+                        self.prepare(#client_ident)?
+                            .query_map(self.as_params(), #row_ident::from_row)?
+                            .collect()
+                    }
+                }
+            }
+            Annotation::Exec | Annotation::ExecResult | Annotation::ExecRows => {
+                quote::quote! {
+                    pub fn execute(&self, #client_ident: #client_typ)->duckdb::Result<usize>{
+                        self.prepare(#client_ident)?
+                            .execute(self.as_params())
+                    }
+                }
+            }
+            Annotation::CopyFrom => {
+                let table = query.insert_table.as_deref().unwrap_or("table");
+                let columns = query.fields.iter().map(|f| &f.name_original);
+                quote::quote! {
+                    /// Append the row to an `Appender` of the inserted columns
+                    pub fn append(&self, appender: &mut duckdb::Appender<'_>)->duckdb::Result<()>{
+                        appender.append_row(duckdb::appender_params_from_iter(self.param_values()))
+                    }
+
+                    /// Bulk load rows with `Appender`, which is much faster than `INSERT` per row.
+                    /// Columns missing from the `INSERT` get their default values
+                    pub fn copy_from(#client_ident: #client_typ, rows: &[Self])->duckdb::Result<()>{
+                        let mut appender = #client_ident.appender_with_columns(#table, &[#(#columns),*])?;
+                        for row in rows {
+                            row.append(&mut appender)?;
+                        }
+                        appender.flush()
+                    }
+                }
+            }
+            _ => {
+                quote::quote! {}
+            }
+        };
+
+        let fetch_tt = {
+            let imp_ident = query_ast.impl_ident();
+            let param_num = proc_macro2::Literal::usize_unsuffixed(query.fields.len());
+
+            // lists are converted into `DuckdbList`, which needs boxing to be borrowed as `dyn ToSql`
+            let (param_typ, values) = if query.fields.iter().any(|f| f.typ.is_array()) {
+                let values = query
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let name = &f.name;
+                        let dim = f.typ.dim();
+                        let value = match (f.typ.is_array(), f.typ.is_optional()) {
+                            (false, _) => quote::quote! {&self.#name},
+                            (true, false) => wrap_list(dim, quote::quote! {self.#name}),
+                            (true, true) => {
+                                let list = wrap_list(dim, quote::quote! {v});
+                                quote::quote! {self.#name.map(|v| #list)}
+                            }
+                        };
+                        quote::quote! {Box::new(#value)}
+                    })
+                    .collect::<Vec<_>>();
+                (quote::quote! {Box<dyn duckdb::ToSql + '_>}, values)
+            } else {
+                let values = query
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let name = &f.name;
+                        quote::quote! {&self.#name}
+                    })
+                    .collect::<Vec<_>>();
+                (quote::quote! {&dyn duckdb::ToSql}, values)
+            };
+
+            quote::quote! {
+                impl #imp_ident {
+                    #query_fns
+
+                    pub fn prepare<'conn>(&self, #client_ident: &'conn duckdb::Connection)->duckdb::Result<duckdb::Statement<'conn>>{
+                        #client_ident.prepare(self.query_str())
+                    }
+
+                    pub fn as_params(&self) -> impl duckdb::Params + '_ {
+                        duckdb::params_from_iter(self.param_values())
+                    }
+
+                    fn param_values(&self) -> [#param_typ; #param_num] {
+                        [#(#values,)*]
+                    }
+                }
+            }
+        };
+
+        quote::quote! {
+            #row_tt
+            #query_ast
+            #fetch_tt
+            #builder_tt
+        }
+    }
+}
+
+impl DbCrateConfig for Duckdb {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        db_crates::{DbCrateExt as _, snapshot},
+        plugin,
+    };
+
+    #[test]
+    fn test_duckdb_snapshot() {
+        let events = |name: &str, db_type: &str, not_null: bool| plugin::Column {
+            table: Some(plugin::Identifier {
+                name: "events".to_string(),
+                ..Default::default()
+            }),
+            ..snapshot::column(name, db_type, not_null)
+        };
+        let event_columns = || {
+            vec![
+                events("id", "hugeint", true),
+                events("amount", "pg_catalog.numeric", true),
+                events("created_at", "pg_catalog.timestamp", true),
+                plugin::Column {
+                    array_dims: 1,
+                    ..events("tags", "text", false)
+                },
+            ]
+        };
+        let queries = vec![
+            snapshot::query(
+                "GetEvent",
+                ":one",
+                "SELECT id, amount, created_at, tags FROM events WHERE id = $1",
+                event_columns(),
+                vec![events("id", "hugeint", true)],
+            ),
+            snapshot::query(
+                "ListEventsByTags",
+                ":many",
+                "SELECT id, amount, created_at, tags FROM events WHERE list_has_any(tags, $1)",
+                event_columns(),
+                vec![plugin::Column {
+                    array_dims: 1,
+                    ..events("tags", "text", true)
+                }],
+            ),
+            plugin::Query {
+                insert_into_table: Some(plugin::Identifier {
+                    name: "events".to_string(),
+                    ..Default::default()
+                }),
+                ..snapshot::query(
+                    "CopyEvents",
+                    ":copyfrom",
+                    "INSERT INTO events (id, amount, created_at) VALUES ($1, $2, $3)",
+                    vec![],
+                    vec![
+                        events("id", "hugeint", true),
+                        events("amount", "pg_catalog.numeric", true),
+                        events("created_at", "pg_catalog.timestamp", true),
+                    ],
+                )
+            },
+            snapshot::query(
+                "DeleteEvents",
+                ":execrows",
+                "DELETE FROM events",
+                vec![],
+                vec![],
+            ),
+        ];
+        let enum_tt = Duckdb.defined_enum(&DbEnum {
+            name: "event_kind".to_string(),
+            values: vec!["click".to_string(), "view".to_string()],
+            derives: vec![],
+        });
+        let table = plugin::Table {
+            rel: Some(plugin::Identifier {
+                name: "events".to_string(),
+                ..Default::default()
+            }),
+            columns: event_columns(),
+            ..Default::default()
+        };
+        let composite = DbComposite::from_table(&Duckdb.db_type_map(), &table, "").unwrap();
+        let composite_tt = Duckdb.defined_composite(&composite);
+        let queries_tt = snapshot::generate_queries(&Duckdb, &queries);
+        snapshot::assert_snapshot(
+            "duckdb",
+            quote::quote! {
+                #enum_tt
+                #composite_tt
+                #queries_tt
+            },
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use super::{DbCrate, DbCrateConfig};
+use crate::query::{
+    Annotation, DbComposite, DbEnum, DbNewtype, Query, ReturningRows, RsType, TypeMapper,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Libsql;
+
+impl<'de> serde::Deserialize<'de> for Libsql {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.trim() {
+            "libsql" => Ok(Self),
+            _ => Err(serde::de::Error::custom(format!(
+                "`{s}` is unsupported crate."
+            ))),
+        }
+    }
+}
+
+impl Libsql {
+    /// Declared types whose rusqlite default cannot be decoded by `libsql::FromValue`
+    fn override_types(&self) -> &[(&str, Option<&str>, &[&'static str])] {
+        const OVERRIDE_TYPE: &[(&str, Option<&str>, &[&str])] = &[
+            ("i32", None, &["tinyint", "smallint", "int2"]),
+            // libsql has no date/time, uuid or json conversion, values are kept as stored
+            (
+                "String",
+                Some("str"),
+                &["datetime", "timestamp", "date", "time", "json", "jsonb"],
+            ),
+            ("Vec<u8>", Some("[u8]"), &["uuid"]),
+        ];
+        OVERRIDE_TYPE
+    }
+
+    fn returning_row(row: &ReturningRows) -> proc_macro2::TokenStream {
+        let row_struct = super::make_return_row(row);
+
+        let ident = row.struct_ident();
+        let arg_ident = quote::format_ident!("row");
+
+        // `libsql::FromValue` is sealed, values are decoded by `FromLibsqlValue` defined in `init`
+        let from_fields = row.fields.iter().enumerate().map(|(idx, field)| {
+            let field_ident = &field.name;
+            let literal = proc_macro2::Literal::i32_unsuffixed(idx as i32);
+            let value = quote::quote! {#arg_ident.get_value(#literal)?};
+            if field.typ.needs_conversion() {
+                let fetch_typ = field.typ.to_fetch_tokens();
+                let value = field.typ.unwrap_fetched(
+                    quote::quote! {<#fetch_typ as FromLibsqlValue>::from_libsql_value(#value)?},
+                );
+                quote::quote! {#field_ident:#value}
+            } else {
+                quote::quote! {#field_ident:FromLibsqlValue::from_libsql_value(#value)?}
+            }
+        });
+
+        let from_tt = quote::quote! {
+            impl #ident {
+                pub fn from_row(#arg_ident: &libsql::Row)->Result<Self, libsql::Error>{
+                    Ok(Self{
+                        #(#from_fields,)*
+                    })
+                }
+            }
+        };
+
+        quote::quote! {
+            #row_struct
+            #from_tt
+        }
+    }
+}
+
+impl DbCrate for Libsql {
+    fn type_map(&self) -> Box<dyn crate::query::TypeMapper> {
+        let mut map = super::rusqlite::SqliteTypeMap::new();
+
+        for (owned_type, slice_type, db_types) in self.override_types() {
+            let owned_type = syn::parse_str::<syn::Type>(owned_type).expect("Failed to parse type");
+            let slice_type = slice_type
+                .map(|s| syn::parse_str::<syn::Type>(s).expect("Failed to parse slice type"));
+            let copy_cheap = slice_type.is_none();
+
+            for db_type in db_types.iter() {
+                map.insert_db_type(
+                    db_type,
+                    RsType::new(owned_type.clone(), slice_type.clone(), copy_cheap),
+                );
+            }
+        }
+        Box::new(map)
+    }
+
+    fn init(&self) -> proc_macro2::TokenStream {
+        let numeric_tt = super::sqlite_numeric_tokens();
+        quote::quote! {
+            #numeric_tt
+
+            /// Decode a column value. `libsql::FromValue` is sealed, so row fields are decoded by this trait
+            pub trait FromLibsqlValue: Sized {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self>;
+            }
+
+            impl FromLibsqlValue for libsql::Value {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    Ok(value)
+                }
+            }
+
+            impl<T: FromLibsqlValue> FromLibsqlValue for Option<T> {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    match value {
+                        libsql::Value::Null => Ok(None),
+                        value => T::from_libsql_value(value).map(Some),
+                    }
+                }
+            }
+
+            impl FromLibsqlValue for i64 {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    match value {
+                        libsql::Value::Integer(v) => Ok(v),
+                        libsql::Value::Null => Err(libsql::Error::NullValue),
+                        _ => Err(libsql::Error::InvalidColumnType),
+                    }
+                }
+            }
+
+            impl FromLibsqlValue for i32 {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    i64::from_libsql_value(value)?
+                        .try_into()
+                        .map_err(|_| libsql::Error::InvalidColumnType)
+                }
+            }
+
+            impl FromLibsqlValue for u32 {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    i64::from_libsql_value(value)?
+                        .try_into()
+                        .map_err(|_| libsql::Error::InvalidColumnType)
+                }
+            }
+
+            impl FromLibsqlValue for u64 {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    i64::from_libsql_value(value)?
+                        .try_into()
+                        .map_err(|_| libsql::Error::InvalidColumnType)
+                }
+            }
+
+            impl FromLibsqlValue for bool {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    match i64::from_libsql_value(value)? {
+                        0 => Ok(false),
+                        1 => Ok(true),
+                        _ => Err(libsql::Error::InvalidColumnType),
+                    }
+                }
+            }
+
+            impl FromLibsqlValue for f64 {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    match value {
+                        libsql::Value::Real(v) => Ok(v),
+                        libsql::Value::Integer(v) => Ok(v as f64),
+                        libsql::Value::Null => Err(libsql::Error::NullValue),
+                        _ => Err(libsql::Error::InvalidColumnType),
+                    }
+                }
+            }
+
+            impl FromLibsqlValue for String {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    match value {
+                        libsql::Value::Text(v) => Ok(v),
+                        libsql::Value::Null => Err(libsql::Error::NullValue),
+                        _ => Err(libsql::Error::InvalidColumnType),
+                    }
+                }
+            }
+
+            impl FromLibsqlValue for Vec<u8> {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    match value {
+                        libsql::Value::Blob(v) => Ok(v),
+                        libsql::Value::Null => Err(libsql::Error::NullValue),
+                        _ => Err(libsql::Error::InvalidColumnType),
+                    }
+                }
+            }
+
+            impl FromLibsqlValue for SqliteNumeric {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    match value {
+                        libsql::Value::Integer(v) => Ok(Self::Integer(v)),
+                        libsql::Value::Real(v) => Ok(Self::Real(v)),
+                        libsql::Value::Text(v) => Ok(Self::Text(v)),
+                        libsql::Value::Null => Err(libsql::Error::NullValue),
+                        _ => Err(libsql::Error::InvalidColumnType),
+                    }
+                }
+            }
+
+            impl From<SqliteNumeric> for libsql::Value {
+                fn from(value: SqliteNumeric) -> Self {
+                    match value {
+                        SqliteNumeric::Integer(v) => Self::Integer(v),
+                        SqliteNumeric::Real(v) => Self::Real(v),
+                        SqliteNumeric::Text(v) => Self::Text(v),
+                    }
+                }
+            }
+
+            impl From<&SqliteNumeric> for libsql::Value {
+                fn from(value: &SqliteNumeric) -> Self {
+                    value.clone().into()
+                }
+            }
+        }
+    }
+
+    fn defined_enum(&self, _enum_type: &DbEnum) -> proc_macro2::TokenStream {
+        quote::quote! {
+            compile_error!("sqlite do not support enum")
+        }
+    }
+
+    fn defined_newtype(&self, newtype: &DbNewtype) -> proc_macro2::TokenStream {
+        let ident = newtype.ident();
+        let inner = newtype.inner.owned();
+        let derives = super::newtype_derives(newtype);
+        // parameters hold newtypes which are not copy cheap by reference
+        quote::quote! {
+            #[derive(#derives)]
+            pub struct #ident(pub #inner);
+
+            impl From<#ident> for libsql::Value {
+                fn from(value: #ident) -> Self {
+                    value.0.into()
+                }
+            }
+
+            impl From<&#ident> for libsql::Value {
+                fn from(value: &#ident) -> Self {
+                    value.0.clone().into()
+                }
+            }
+
+            impl FromLibsqlValue for #ident {
+                fn from_libsql_value(value: libsql::Value) -> libsql::Result<Self> {
+                    <#inner as FromLibsqlValue>::from_libsql_value(value).map(Self)
+                }
+            }
+        }
+    }
+
+    fn defined_composite(&self, _composite: &DbComposite) -> proc_macro2::TokenStream {
+        quote::quote! {
+            compile_error!("sqlite do not support composite type")
+        }
+    }
+
+    fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
+        let row_tt = Self::returning_row(row);
+        let query_ast = super::QueryAst::new(query, crate::db_crates::DataBaseKind::Sqlite);
+        let builder_tt = query_ast.make_builder();
+
+        // `libsql::Transaction` derefs to `libsql::Connection`
+        let client_ident = quote::format_ident!("conn");
+        let client_typ = quote::quote! {&libsql::Connection};
+        let row_ident = row.struct_ident();
+
+        let query_fns = match query.annotation {
+            Annotation::One => {
+                quote::quote! {
+                    pub async fn query_one(&self, #client_ident: #client_typ)->Result<#row_ident, libsql::Error>{
+                        let row = #client_ident.prepare(self.query_str()).await?
+                            .query_row(self.as_params()).await?;
+                        #row_ident::from_row(&row)
+                    }
+
+                    pub async fn query_opt(&self, #client_ident: #client_typ)->Result<Option<#row_ident>, libsql::Error>{
+                        let mut rows = #client_ident.query(self.query_str(), self.as_params()).await?;
+                        match rows.next().await? {
+                            Some(row) => Ok(Some(#row_ident::from_row(&row)?)),
+                            None => Ok(None),
+                        }
+                    }
+                }
+            }
+            Annotation::Many => {
+                quote::quote! {
+                    pub async fn query_many(&self, #client_ident: #client_typ)->Result<Vec<#row_ident>, libsql::Error>{
+                        let mut rows = #client_ident.query(self.query_str(), self.as_params()).await?;
+                        let mut vals = Vec::new();
+                        while let Some(row) = rows.next().await? {
+                            vals.push(#row_ident::from_row(&row)?);
+                        }
+                        Ok(vals)
+                    }
+                }
+            }
+            Annotation::Exec | Annotation::ExecResult | Annotation::ExecRows => {
+                quote::quote! {
+                    pub async fn execute(&self, #client_ident: #client_typ)->Result<u64, libsql::Error>{
+                        #client_ident.execute(self.query_str(), self.as_params()).await
+                    }
+                }
+            }
+            Annotation::ExecLastId => {
+                quote::quote! {
+                    pub async fn execute(&self, #client_ident: #client_typ)->Result<i64, libsql::Error>{
+                        #client_ident.execute(self.query_str(), self.as_params()).await?;
+                        Ok(#client_ident.last_insert_rowid())
+                    }
+                }
+            }
+            _ => {
+                quote::quote! {}
+            }
+        };
+
+        let fetch_tt = {
+            let imp_ident = query_ast.impl_ident();
+
+            let to_value = |f: &crate::query::ColumnField| {
+                let name = &f.name;
+                if f.typ.via().is_some() {
+                    quote::quote! {libsql::Value::from(self.#name.clone())}
+                } else {
+                    quote::quote! {libsql::Value::from(self.#name)}
+                }
+            };
+
+            let params = if query.fields.is_empty() {
+                quote::quote! {libsql::params::Params::None}
+            } else if query_ast.need_expand_query() {
+                let param_it = query.fields.iter().map(|f| {
+                    let name = &f.name;
+                    if f.typ.is_array() {
+                        quote::quote! {
+                            self.#name.iter().cloned().map(libsql::Value::from)
+                        }
+                    } else {
+                        let value = to_value(f);
+                        quote::quote! {core::iter::once(#value)}
+                    }
+                });
+
+                quote::quote! {
+                    libsql::params::Params::Positional(
+                        core::iter::empty()
+                            #(.chain(#param_it))*
+                            .collect()
+                    )
+                }
+            } else {
+                let values = query.fields.iter().map(to_value);
+                quote::quote! {
+                    libsql::params::Params::Positional(Vec::from([#(#values,)*]))
+                }
+            };
+
+            quote::quote! {
+                impl #imp_ident {
+                    #query_fns
+
+                    pub fn as_params(&self) -> libsql::params::Params {
+                        #params
+                    }
+                }
+            }
+        };
+
+        quote::quote! {
+            #row_tt
+            #query_ast
+            #fetch_tt
+            #builder_tt
+        }
+    }
+}
+
+impl DbCrateConfig for Libsql {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db_crates::snapshot;
+
+    #[test]
+    fn test_libsql_snapshot() {
+        let queries_tt = snapshot::generate_queries(&Libsql, &snapshot::author_queries());
+        snapshot::assert_snapshot("libsql", queries_tt);
+    }
+}
@@ -1,23 +1,156 @@
-use crate::query::{self, DbEnum, DbTypeMap, Query, ReturningRows, TypeMapper};
+use crate::query::{
+    self, DbComposite, DbEnum, DbNewtype, DbTypeMap, Query, ReturningRows, TypeMapper, UntypedParam,
+};
 
+mod duckdb;
+mod libsql;
+mod mysql;
 mod postgres;
 mod rusqlite;
+#[cfg(test)]
+mod snapshot;
 mod sqlx;
+mod template;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 #[serde(untagged)]
-pub enum SupportedDbCrate {
+pub(crate) enum SupportedDbCrate {
     Postgres(postgres::Postgres),
     Sqlx(sqlx::Sqlx),
     Rusqlite(rusqlite::Rusqlite),
+    Mysql(mysql::Mysql),
+    Libsql(libsql::Libsql),
+    Duckdb(duckdb::Duckdb),
+    Template(template::Template),
 }
 
-pub(super) trait DbCrate {
+impl SupportedDbCrate {
+    /// Built-in crate named `name` in `db_crate`
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let de = serde::de::value::StrDeserializer::<serde::de::value::Error>::new(name);
+        serde::Deserialize::deserialize(de).ok()
+    }
+}
+
+/// `db_crate` option, a name or a list of names each generated behind a cargo feature
+#[derive(Debug, Clone)]
+pub(crate) struct DbCrateList(Vec<String>);
+
+impl DbCrateList {
+    /// Names as written in `db_crate`
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Default for DbCrateList {
+    fn default() -> Self {
+        Self(vec!["tokio-postgres".to_string()])
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DbCrateList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Names {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        let names = match Names::deserialize(deserializer)? {
+            Names::One(name) => vec![name],
+            Names::Many(names) => names,
+        };
+        if names.is_empty() {
+            return Err(serde::de::Error::custom("`db_crate` must not be empty."));
+        }
+        Ok(Self(names))
+    }
+}
+
+/// Connection pool whose pooled connections the generated code accepts
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Pool {
+    Bb8,
+    R2d2,
+}
+
+impl std::fmt::Display for Pool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pool::Bb8 => f.write_str("bb8"),
+            Pool::R2d2 => f.write_str("r2d2"),
+        }
+    }
+}
+
+/// Which side gets a name suffix when both sync and async methods are generated
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SyncAsync {
+    SuffixAsync,
+    SuffixSync,
+}
+
+/// `template` option of `db_crate: template`, minijinja templates rendered into Rust code
+#[derive(Debug, Clone, serde::Deserialize)]
+pub(crate) struct Templates {
+    /// Rendered once at the top of the file
+    #[serde(default)]
+    pub(crate) init: Option<String>,
+    /// Rendered for each query with `query`
+    pub(crate) query: String,
+    /// Rendered for each enum with `enum`. Plain Rust enums are generated without it
+    #[serde(default, rename = "enum")]
+    pub(crate) enum_type: Option<String>,
+}
+
+/// Code generation backend selected by the `db_crate` option
+///
+/// Built-in crates implement this trait, and custom backends are registered with
+/// [`GenerateOptions::with_db_crate`](crate::GenerateOptions::with_db_crate).
+pub trait DbCrate {
+    /// DB type name to Rust type mapping before `overrides` are applied
     fn type_map(&self) -> Box<dyn TypeMapper>;
 
-    // Generate DB type to Rust type mapping
-    fn db_type_map(&self) -> DbTypeMap {
-        DbTypeMap::from_dyn(self.type_map())
+    /// Wrapper type for multi-dimensional arrays. `None` nests `Vec`
+    fn nd_array_type(&self) -> Option<syn::Path> {
+        None
+    }
+
+    /// Element types `nd_array_type` can hold. `None` if it holds any type
+    fn nd_array_elements(&self) -> Option<Vec<syn::Type>> {
+        None
+    }
+
+    /// Generate the definition of `nd_array_type` for the used `elements`, only called when
+    /// some column is a multi-dimensional array
+    fn nd_array_definition(&self, _elements: &[syn::Type]) -> proc_macro2::TokenStream {
+        proc_macro2::TokenStream::new()
+    }
+
+    /// Wrapper type to encode and decode serde types as JSON. `None` if unsupported
+    fn json_wrapper_type(&self) -> Option<syn::Path> {
+        None
+    }
+
+    /// Form of parameters whose type sqlc cannot infer. `None` if unsupported
+    fn untyped_param(&self) -> Option<UntypedParam> {
+        None
+    }
+
+    /// Whether DB enums are generated as Rust enums. Otherwise columns of enum types cannot be mapped
+    fn supports_enum(&self) -> bool {
+        true
     }
 
     /// Generate top `use` or `fn`
@@ -27,16 +160,139 @@ pub(super) trait DbCrate {
 
     /// Generate enum
     fn defined_enum(&self, enum_type: &DbEnum) -> proc_macro2::TokenStream;
+    /// Generate newtype for key columns
+    fn defined_newtype(&self, newtype: &DbNewtype) -> proc_macro2::TokenStream;
+    /// Generate struct for table used as composite type
+    fn defined_composite(&self, composite: &DbComposite) -> proc_macro2::TokenStream;
     /// Generate returning row and query fn
     fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream;
 }
 
+/// Options of a built-in crate set from the plugin options before generation
+///
+/// Registered backends take the defaults, which reject `pools` and `sync_async`.
+pub(crate) trait DbCrateConfig: DbCrate {
+    /// Accept connections of `pools` in the generated code. `Err` if a pool is not integrated
+    fn set_pools(&mut self, pools: &[Pool]) -> Result<(), String> {
+        match pools.first() {
+            Some(pool) => Err(format!("`{pool}` pool is not supported by this db_crate.")),
+            None => Ok(()),
+        }
+    }
+
+    /// Adapt to the sqlc `engine` which parsed the queries. `Err` if the engine is not supported
+    fn set_engine(&mut self, _engine: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Generate both sync and async methods on each query struct
+    fn set_sync_async(&mut self, _sync_async: SyncAsync) -> Result<(), String> {
+        Err("sync_async is not supported by this db_crate.".into())
+    }
+
+    /// Templates to render code from. Crates not using templates ignore them
+    fn set_templates(&mut self, _templates: Option<&Templates>) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl DbCrateConfig for dyn DbCrate {}
+
+pub(crate) trait DbCrateExt: DbCrate {
+    // Generate DB type to Rust type mapping
+    fn db_type_map(&self) -> DbTypeMap {
+        DbTypeMap::from_dyn(self.type_map())
+            .with_nd_array(self.nd_array_type())
+            .with_nd_array_elements(self.nd_array_elements())
+            .with_json_wrapper(self.json_wrapper_type())
+            .with_untyped_param(self.untyped_param())
+    }
+}
+
+impl<T: DbCrate + ?Sized> DbCrateExt for T {}
+
 impl DbCrate for SupportedDbCrate {
     fn type_map(&self) -> Box<dyn TypeMapper> {
         match self {
             Self::Postgres(postgres) => postgres.type_map(),
             Self::Sqlx(sqlx) => sqlx.type_map(),
             Self::Rusqlite(rusqlite) => rusqlite.type_map(),
+            Self::Mysql(mysql) => mysql.type_map(),
+            Self::Libsql(libsql) => libsql.type_map(),
+            Self::Duckdb(duckdb) => duckdb.type_map(),
+            Self::Template(template) => template.type_map(),
+        }
+    }
+
+    fn nd_array_type(&self) -> Option<syn::Path> {
+        match self {
+            Self::Postgres(postgres) => postgres.nd_array_type(),
+            Self::Sqlx(sqlx) => sqlx.nd_array_type(),
+            Self::Rusqlite(rusqlite) => rusqlite.nd_array_type(),
+            Self::Mysql(mysql) => mysql.nd_array_type(),
+            Self::Libsql(libsql) => libsql.nd_array_type(),
+            Self::Duckdb(duckdb) => duckdb.nd_array_type(),
+            Self::Template(template) => template.nd_array_type(),
+        }
+    }
+
+    fn nd_array_elements(&self) -> Option<Vec<syn::Type>> {
+        match self {
+            Self::Postgres(postgres) => postgres.nd_array_elements(),
+            Self::Sqlx(sqlx) => sqlx.nd_array_elements(),
+            Self::Rusqlite(rusqlite) => rusqlite.nd_array_elements(),
+            Self::Mysql(mysql) => mysql.nd_array_elements(),
+            Self::Libsql(libsql) => libsql.nd_array_elements(),
+            Self::Duckdb(duckdb) => duckdb.nd_array_elements(),
+            Self::Template(template) => template.nd_array_elements(),
+        }
+    }
+
+    fn nd_array_definition(&self, elements: &[syn::Type]) -> proc_macro2::TokenStream {
+        match self {
+            Self::Postgres(postgres) => postgres.nd_array_definition(elements),
+            Self::Sqlx(sqlx) => sqlx.nd_array_definition(elements),
+            Self::Rusqlite(rusqlite) => rusqlite.nd_array_definition(elements),
+            Self::Mysql(mysql) => mysql.nd_array_definition(elements),
+            Self::Libsql(libsql) => libsql.nd_array_definition(elements),
+            Self::Duckdb(duckdb) => duckdb.nd_array_definition(elements),
+            Self::Template(template) => template.nd_array_definition(elements),
+        }
+    }
+
+    fn json_wrapper_type(&self) -> Option<syn::Path> {
+        match self {
+            Self::Postgres(postgres) => postgres.json_wrapper_type(),
+            Self::Sqlx(sqlx) => sqlx.json_wrapper_type(),
+            Self::Rusqlite(rusqlite) => rusqlite.json_wrapper_type(),
+            Self::Mysql(mysql) => mysql.json_wrapper_type(),
+            Self::Libsql(libsql) => libsql.json_wrapper_type(),
+            Self::Duckdb(duckdb) => duckdb.json_wrapper_type(),
+            Self::Template(template) => template.json_wrapper_type(),
+        }
+    }
+
+    fn untyped_param(&self) -> Option<UntypedParam> {
+        match self {
+            Self::Postgres(postgres) => postgres.untyped_param(),
+            Self::Sqlx(sqlx) => sqlx.untyped_param(),
+            Self::Rusqlite(rusqlite) => rusqlite.untyped_param(),
+            Self::Mysql(mysql) => mysql.untyped_param(),
+            Self::Libsql(libsql) => libsql.untyped_param(),
+            Self::Duckdb(duckdb) => duckdb.untyped_param(),
+            Self::Template(template) => template.untyped_param(),
+        }
+    }
+
+    fn supports_enum(&self) -> bool {
+        match self {
+            Self::Postgres(postgres) => postgres.supports_enum(),
+            Self::Sqlx(sqlx) => sqlx.supports_enum(),
+            Self::Rusqlite(rusqlite) => rusqlite.supports_enum(),
+            Self::Mysql(mysql) => mysql.supports_enum(),
+            Self::Libsql(libsql) => libsql.supports_enum(),
+            Self::Duckdb(duckdb) => duckdb.supports_enum(),
+            Self::Template(template) => template.supports_enum(),
         }
     }
 
@@ -45,6 +301,10 @@ impl DbCrate for SupportedDbCrate {
             Self::Postgres(postgres) => postgres.init(),
             Self::Sqlx(sqlx) => sqlx.init(),
             Self::Rusqlite(rusqlite) => rusqlite.init(),
+            Self::Mysql(mysql) => mysql.init(),
+            Self::Libsql(libsql) => libsql.init(),
+            Self::Duckdb(duckdb) => duckdb.init(),
+            Self::Template(template) => template.init(),
         }
     }
 
@@ -53,6 +313,34 @@ impl DbCrate for SupportedDbCrate {
             Self::Postgres(postgres) => postgres.defined_enum(enum_type),
             Self::Sqlx(sqlx) => sqlx.defined_enum(enum_type),
             Self::Rusqlite(rusqlite) => rusqlite.defined_enum(enum_type),
+            Self::Mysql(mysql) => mysql.defined_enum(enum_type),
+            Self::Libsql(libsql) => libsql.defined_enum(enum_type),
+            Self::Duckdb(duckdb) => duckdb.defined_enum(enum_type),
+            Self::Template(template) => template.defined_enum(enum_type),
+        }
+    }
+
+    fn defined_newtype(&self, newtype: &DbNewtype) -> proc_macro2::TokenStream {
+        match self {
+            Self::Postgres(postgres) => postgres.defined_newtype(newtype),
+            Self::Sqlx(sqlx) => sqlx.defined_newtype(newtype),
+            Self::Rusqlite(rusqlite) => rusqlite.defined_newtype(newtype),
+            Self::Mysql(mysql) => mysql.defined_newtype(newtype),
+            Self::Libsql(libsql) => libsql.defined_newtype(newtype),
+            Self::Duckdb(duckdb) => duckdb.defined_newtype(newtype),
+            Self::Template(template) => template.defined_newtype(newtype),
+        }
+    }
+
+    fn defined_composite(&self, composite: &DbComposite) -> proc_macro2::TokenStream {
+        match self {
+            Self::Postgres(postgres) => postgres.defined_composite(composite),
+            Self::Sqlx(sqlx) => sqlx.defined_composite(composite),
+            Self::Rusqlite(rusqlite) => rusqlite.defined_composite(composite),
+            Self::Mysql(mysql) => mysql.defined_composite(composite),
+            Self::Libsql(libsql) => libsql.defined_composite(composite),
+            Self::Duckdb(duckdb) => duckdb.defined_composite(composite),
+            Self::Template(template) => template.defined_composite(composite),
         }
     }
 
@@ -61,17 +349,72 @@ impl DbCrate for SupportedDbCrate {
             Self::Postgres(postgres) => postgres.generate_query(row, query),
             Self::Sqlx(sqlx) => sqlx.generate_query(row, query),
             Self::Rusqlite(rusqlite) => rusqlite.generate_query(row, query),
+            Self::Mysql(mysql) => mysql.generate_query(row, query),
+            Self::Libsql(libsql) => libsql.generate_query(row, query),
+            Self::Duckdb(duckdb) => duckdb.generate_query(row, query),
+            Self::Template(template) => template.generate_query(row, query),
+        }
+    }
+}
+
+impl DbCrateConfig for SupportedDbCrate {
+    fn set_pools(&mut self, pools: &[Pool]) -> Result<(), String> {
+        match self {
+            Self::Postgres(postgres) => postgres.set_pools(pools),
+            Self::Sqlx(sqlx) => sqlx.set_pools(pools),
+            Self::Rusqlite(rusqlite) => rusqlite.set_pools(pools),
+            Self::Mysql(mysql) => mysql.set_pools(pools),
+            Self::Libsql(libsql) => libsql.set_pools(pools),
+            Self::Duckdb(duckdb) => duckdb.set_pools(pools),
+            Self::Template(template) => template.set_pools(pools),
+        }
+    }
+
+    fn set_engine(&mut self, engine: &str) -> Result<(), String> {
+        match self {
+            Self::Postgres(postgres) => postgres.set_engine(engine),
+            Self::Sqlx(sqlx) => sqlx.set_engine(engine),
+            Self::Rusqlite(rusqlite) => rusqlite.set_engine(engine),
+            Self::Mysql(mysql) => mysql.set_engine(engine),
+            Self::Libsql(libsql) => libsql.set_engine(engine),
+            Self::Duckdb(duckdb) => duckdb.set_engine(engine),
+            Self::Template(template) => template.set_engine(engine),
+        }
+    }
+
+    fn set_sync_async(&mut self, sync_async: SyncAsync) -> Result<(), String> {
+        match self {
+            Self::Postgres(postgres) => postgres.set_sync_async(sync_async),
+            Self::Sqlx(sqlx) => sqlx.set_sync_async(sync_async),
+            Self::Rusqlite(rusqlite) => rusqlite.set_sync_async(sync_async),
+            Self::Mysql(mysql) => mysql.set_sync_async(sync_async),
+            Self::Libsql(libsql) => libsql.set_sync_async(sync_async),
+            Self::Duckdb(duckdb) => duckdb.set_sync_async(sync_async),
+            Self::Template(template) => template.set_sync_async(sync_async),
+        }
+    }
+
+    fn set_templates(&mut self, templates: Option<&Templates>) -> Result<(), String> {
+        match self {
+            Self::Postgres(postgres) => postgres.set_templates(templates),
+            Self::Sqlx(sqlx) => sqlx.set_templates(templates),
+            Self::Rusqlite(rusqlite) => rusqlite.set_templates(templates),
+            Self::Mysql(mysql) => mysql.set_templates(templates),
+            Self::Libsql(libsql) => libsql.set_templates(templates),
+            Self::Duckdb(duckdb) => duckdb.set_templates(templates),
+            Self::Template(template) => template.set_templates(templates),
         }
     }
 }
 
 impl Default for SupportedDbCrate {
     fn default() -> Self {
-        Self::Postgres(postgres::Postgres::Tokio)
+        Self::Postgres(postgres::Postgres::default())
     }
 }
 
-fn make_return_row(row: &query::ReturningRows) -> proc_macro2::TokenStream {
+/// Generate the struct of `row`
+pub fn make_return_row(row: &query::ReturningRows) -> proc_macro2::TokenStream {
     let ident = &row.struct_ident();
     let row_attribute = &row.attributes;
     let fields = row.fields.iter().map(|field| {
@@ -91,21 +434,101 @@ fn make_return_row(row: &query::ReturningRows) -> proc_macro2::TokenStream {
     }
 }
 
-enum DataBaseKind {
+/// Derives shared by newtypes of all crates
+fn newtype_derives(newtype: &DbNewtype) -> proc_macro2::TokenStream {
+    if newtype.is_copy_cheap() {
+        quote::quote! {Debug, Clone, Copy, PartialEq}
+    } else {
+        quote::quote! {Debug, Clone, PartialEq}
+    }
+}
+
+/// Value of a NUMERIC affinity column, which SQLite may store as INTEGER, REAL or TEXT
+///
+/// See https://www.sqlite.org/datatype3.html#type_affinity
+fn sqlite_numeric_tokens() -> proc_macro2::TokenStream {
+    quote::quote! {
+        /// Value of a NUMERIC affinity column, kept in the storage class SQLite returned
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum SqliteNumeric {
+            Integer(i64),
+            Real(f64),
+            Text(String),
+        }
+
+        impl SqliteNumeric {
+            /// Convert to `f64`. Large integers and non-numeric text may lose precision or return `None`
+            pub fn as_f64(&self) -> Option<f64> {
+                match self {
+                    Self::Integer(v) => Some(*v as f64),
+                    Self::Real(v) => Some(*v),
+                    Self::Text(v) => v.parse().ok(),
+                }
+            }
+
+            /// Convert to `i64` if the value is integral
+            pub fn as_i64(&self) -> Option<i64> {
+                match self {
+                    Self::Integer(v) => Some(*v),
+                    Self::Real(v) if v.fract() == 0.0 => Some(*v as i64),
+                    Self::Real(_) => None,
+                    Self::Text(v) => v.parse().ok(),
+                }
+            }
+        }
+
+        impl From<i64> for SqliteNumeric {
+            fn from(value: i64) -> Self {
+                Self::Integer(value)
+            }
+        }
+
+        impl From<f64> for SqliteNumeric {
+            fn from(value: f64) -> Self {
+                Self::Real(value)
+            }
+        }
+
+        impl From<String> for SqliteNumeric {
+            fn from(value: String) -> Self {
+                Self::Text(value)
+            }
+        }
+    }
+}
+
+fn contains_ident(tokens: &proc_macro2::TokenStream, ident: &syn::Ident) -> bool {
+    tokens.clone().into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(i) => &i == ident,
+        proc_macro2::TokenTree::Group(g) => contains_ident(&g.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Database whose placeholder syntax the query string uses
+#[derive(Debug, Clone, Copy)]
+pub enum DataBaseKind {
+    /// PostgreSQL, which binds slice parameters as arrays
     Postgres,
+    /// MySQL
     MySql,
+    /// SQLite
     Sqlite,
 }
 
-struct QueryAst<'a> {
+/// Parameter struct of a query, its `QUERY` string and builder
+pub struct QueryAst<'a> {
+    /// Name of the parameter struct
     pub ident: syn::Ident,
+    /// Lifetime of borrowed parameters
     pub lifetime: syn::Lifetime,
     query: &'a Query,
     kind: DataBaseKind,
 }
 
 impl<'a> QueryAst<'a> {
-    fn new(query: &'a Query, kind: DataBaseKind) -> Self {
+    /// Parameter struct of `query` run on a database of `kind`
+    pub fn new(query: &'a Query, kind: DataBaseKind) -> Self {
         let ident = crate::value_ident(&query.query_name);
         let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
         Self {
@@ -116,15 +539,73 @@ impl<'a> QueryAst<'a> {
         }
     }
 
-    fn fields(&self) -> impl Iterator<Item = &query::ColumnField> {
+    /// Parameter fields
+    pub fn fields(&self) -> impl Iterator<Item = &query::ColumnField> {
         self.query.fields.iter()
     }
 
-    fn need_lifetime(&self) -> bool {
+    /// Whether the parameter struct borrows its parameters for `lifetime`
+    pub fn need_lifetime(&self) -> bool {
         self.fields().any(|f| f.typ.need_lifetime())
     }
 
-    fn need_expand_query(&self) -> bool {
+    /// Generic type parameters of untyped parameters and their bounds
+    fn generic_params(&self) -> Vec<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+        self.fields()
+            .filter_map(|f| f.typ.generic_param())
+            .map(|(ident, bounds)| (ident, bounds.clone()))
+            .collect()
+    }
+
+    /// Whether the parameter struct has a lifetime or generic params of untyped parameters
+    fn is_generic(&self) -> bool {
+        self.need_lifetime() || self.fields().any(|f| f.typ.generic_param().is_some())
+    }
+
+    /// Generics of the query struct, e.g. `<'a, Column1Value>`
+    fn type_generics(&self) -> proc_macro2::TokenStream {
+        let lifetime = self.need_lifetime().then_some(&self.lifetime);
+        let params = lifetime
+            .map(|l| quote::quote! {#l})
+            .into_iter()
+            .chain(self.generic_params().into_iter().map(|(ident, _)| ident))
+            .collect::<Vec<_>>();
+        if params.is_empty() {
+            quote::quote! {}
+        } else {
+            quote::quote! {<#(#params),*>}
+        }
+    }
+
+    /// Generics of `impl` blocks of the query struct, e.g. `<'a, Column1Value: Bounds>`
+    fn impl_generics(&self) -> proc_macro2::TokenStream {
+        let lifetime = self.need_lifetime().then_some(&self.lifetime);
+        let params = lifetime
+            .map(|l| quote::quote! {#l})
+            .into_iter()
+            .chain(
+                self.generic_params()
+                    .into_iter()
+                    .map(|(ident, bounds)| quote::quote! {#ident: #bounds}),
+            )
+            .collect::<Vec<_>>();
+        if params.is_empty() {
+            quote::quote! {}
+        } else {
+            quote::quote! {<#(#params),*>}
+        }
+    }
+
+    /// Target of `impl` blocks of the query struct, e.g. `<'a> GetAuthor<'a>`
+    pub fn impl_ident(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let impl_generics = self.impl_generics();
+        let type_generics = self.type_generics();
+        quote::quote! {#impl_generics #ident #type_generics}
+    }
+
+    /// Slice parameters are expanded into the query string at runtime
+    pub fn need_expand_query(&self) -> bool {
         if matches!(self.kind, DataBaseKind::Postgres) {
             return false;
         }
@@ -155,10 +636,17 @@ impl<'a> QueryAst<'a> {
             .map(|typ| typ.to_param_tokens(lifetime))
             .collect::<Vec<_>>();
 
+        // generic params are fixed so that `GetXXX::builder()` can be inferred
+        let unit_generics = self
+            .generic_params()
+            .into_iter()
+            .map(|_| quote::quote! {()})
+            .collect::<Vec<_>>();
+
         // implement `GetXXX::builder`
         let impl_struct_tt = if self.need_lifetime() {
             quote::quote! {
-                impl <#lifetime> #struct_ident<#lifetime>{
+                impl <#lifetime> #struct_ident<#lifetime #(,#unit_generics)*>{
                     pub const fn builder()->#builder_ident<#lifetime, (#(#fields_tuple,)*)>{
                         #builder_ident{
                             fields: (#(#fields_tuple,)*),
@@ -168,8 +656,13 @@ impl<'a> QueryAst<'a> {
                 }
             }
         } else {
+            let type_generics = if unit_generics.is_empty() {
+                quote::quote! {}
+            } else {
+                quote::quote! {<#(#unit_generics),*>}
+            };
             quote::quote! {
-                impl #struct_ident{
+                impl #struct_ident #type_generics{
                     pub const fn builder()->#builder_ident<'static, (#(#fields_tuple,)*)>{
                         #builder_ident{
                             fields: (#(#fields_tuple,)*),
@@ -193,11 +686,28 @@ impl<'a> QueryAst<'a> {
             let typ_generics = self
                 .fields()
                 .map(|f| &f.name)
-                .map(|n| crate::value_ident(&n.to_string()))
+                .map(|n| {
+                    let generic = crate::value_ident(&n.to_string());
+                    // do not shadow a field type of the same name, e.g. newtype `AuthorId` of `author_id`
+                    if typ_list.iter().any(|typ| contains_ident(typ, &generic)) {
+                        quote::format_ident!("{}Field", generic)
+                    } else {
+                        generic
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let generic_list = self
+                .fields()
+                .map(|f| f.typ.generic_param())
                 .collect::<Vec<_>>();
 
             let mut result = quote::quote! {};
             for (idx, (typ, name)) in typ_list.iter().zip(field_list.iter()).enumerate() {
+                // untyped parameters accept any value satisfying the bounds
+                let fn_generics = generic_list[idx]
+                    .as_ref()
+                    .map(|(ident, bounds)| quote::quote! {<#ident: #bounds>});
                 let (generics_head, rest) = typ_generics.split_at(idx);
                 let generics_tail = if rest.is_empty() { &[] } else { &rest[1..] };
 
@@ -206,7 +716,7 @@ impl<'a> QueryAst<'a> {
 
                 let tt = quote::quote! {
                     impl <#lifetime,#(#generics_head,)* #(#generics_tail,)*> #builder_ident<#lifetime,(#(#generics_head,)* (), #(#generics_tail,)*)>{
-                        pub fn #name(self, #name:#typ)->#builder_ident<#lifetime,(#(#generics_head,)* #typ, #(#generics_tail,)*)>{
+                        pub fn #name #fn_generics(self, #name:#typ)->#builder_ident<#lifetime,(#(#generics_head,)* #typ, #(#generics_tail,)*)>{
                             let (#(#field_head,)* (), #(#field_tail,)*) = self.fields;
                             let _phantom = self._phantom;
 
@@ -250,11 +760,28 @@ impl<'a> QueryAst<'a> {
             .map(|typ| typ.to_param_tokens(lifetime))
             .collect::<Vec<_>>();
 
-        let build_struct = if self.need_lifetime() {
-            quote::quote! {#struct_ident<#lifetime>}
-        } else {
-            quote::quote! {#struct_ident}
-        };
+        // JSON values are stored wrapped and `via` values converted
+        let field_inits = self
+            .fields()
+            .map(|f| {
+                let name = &f.name;
+                if f.typ.needs_conversion() {
+                    let value = f.typ.wrap_param(name);
+                    quote::quote! {#name: #value}
+                } else {
+                    quote::quote! {#name}
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let type_generics = self.type_generics();
+        let build_struct = quote::quote! {#struct_ident #type_generics};
+        // the builder always has the lifetime
+        let generics = self
+            .generic_params()
+            .into_iter()
+            .map(|(ident, bounds)| quote::quote! {#ident: #bounds});
+        let impl_generics = quote::quote! {<#lifetime #(,#generics)*>};
 
         if self.need_expand_query() {
             let query_ident = quote::format_ident!("__query");
@@ -284,7 +811,7 @@ impl<'a> QueryAst<'a> {
                         });
 
             quote::quote! {
-                  impl <#lifetime> #builder_ident<#lifetime,(#(#typ_list,)*)>{
+                  impl #impl_generics #builder_ident<#lifetime,(#(#typ_list,)*)>{
                     pub fn build(self)->#build_struct{
                         let (#(#field_list,)*) = self.fields;
 
@@ -292,7 +819,7 @@ impl<'a> QueryAst<'a> {
                         #(#query_builder)*
 
                         #struct_ident{
-                            #(#field_list,)*
+                            #(#field_inits,)*
                             __query: #query_ident.into()
                         }
                     }
@@ -300,11 +827,11 @@ impl<'a> QueryAst<'a> {
             }
         } else {
             quote::quote! {
-                  impl <#lifetime> #builder_ident<#lifetime,(#(#typ_list,)*)>{
+                  impl #impl_generics #builder_ident<#lifetime,(#(#typ_list,)*)>{
                     pub fn build(self)->#build_struct{
                         let (#(#field_list,)*) = self.fields;
                         #struct_ident{
-                            #(#field_list,)*
+                            #(#field_inits,)*
                         }
                     }
                 }
@@ -312,7 +839,8 @@ impl<'a> QueryAst<'a> {
         }
     }
 
-    fn make_builder(&self) -> proc_macro2::TokenStream {
+    /// Generate the typestate builder of the query struct
+    pub fn make_builder(&self) -> proc_macro2::TokenStream {
         let setter_tt = self.make_builder_setter();
         let build_tt = self.make_builder_build();
 
@@ -327,24 +855,25 @@ impl<'a> quote::ToTokens for QueryAst<'a> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let fields = self.fields().map(|f| {
             let name = &f.name;
-            let typ = f.typ.to_param_tokens(&self.lifetime);
+            let typ = f.typ.to_stored_param_tokens(&self.lifetime);
             quote::quote! {#name:#typ}
         });
         let ident = &self.ident;
-        let lifetime = &self.lifetime;
+        let type_generics = self.type_generics();
+        let impl_ident = self.impl_ident();
 
         let query_str = self.query.query_str();
 
-        let tt = match (self.need_lifetime(), !self.query.fields.is_empty()) {
+        let tt = match (self.is_generic(), !self.query.fields.is_empty()) {
             (true, _) => {
                 if self.need_expand_query() {
                     quote::quote! {
-                        pub struct #ident<#lifetime>{
+                        pub struct #ident #type_generics{
                             #(#fields,)*
                             __query: String
                         }
 
-                        impl <#lifetime> #ident<#lifetime>{
+                        impl #impl_ident{
                             pub const QUERY : &'static str = #query_str;
                             pub fn query_str(&self)->&str{
                                 &self.__query
@@ -353,11 +882,11 @@ impl<'a> quote::ToTokens for QueryAst<'a> {
                     }
                 } else {
                     quote::quote! {
-                        pub struct #ident<#lifetime>{
+                        pub struct #ident #type_generics{
                             #(#fields,)*
                         }
 
-                        impl <#lifetime> #ident<#lifetime>{
+                        impl #impl_ident{
                             pub const QUERY : &'static str = #query_str;
                             pub fn query_str(&self)->&str{
                                 Self::QUERY
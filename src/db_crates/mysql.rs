@@ -0,0 +1,465 @@
+use super::{DbCrate, DbCrateConfig};
+use crate::{
+    query::{
+        Annotation, DbComposite, DbEnum, DbNewtype, Query, ReturningRows, RsType, TypeMapper,
+        UntypedParam,
+    },
+    value_ident,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) enum Mysql {
+    Sync,
+    #[default]
+    Async,
+}
+
+impl<'de> serde::Deserialize<'de> for Mysql {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.trim() {
+            "mysql" => Ok(Self::Sync),
+            "mysql_async" => Ok(Self::Async),
+            _ => Err(serde::de::Error::custom(format!(
+                "`{s}` is unsupported crate."
+            ))),
+        }
+    }
+}
+
+impl Mysql {
+    fn crate_path(&self) -> syn::Path {
+        match self {
+            Mysql::Sync => syn::parse_quote! {mysql},
+            Mysql::Async => syn::parse_quote! {mysql_async},
+        }
+    }
+
+    fn generic_client_type(&self) -> syn::Type {
+        let krate = self.crate_path();
+        syn::parse_quote! {&mut impl #krate::prelude::Queryable}
+    }
+
+    fn async_part(&self) -> proc_macro2::TokenStream {
+        match self {
+            Mysql::Sync => quote::quote! {},
+            Mysql::Async => quote::quote! {async},
+        }
+    }
+
+    fn await_part(&self) -> proc_macro2::TokenStream {
+        match self {
+            Mysql::Sync => quote::quote! {},
+            Mysql::Async => quote::quote! {.await},
+        }
+    }
+
+    fn returning_row(&self, row: &ReturningRows) -> proc_macro2::TokenStream {
+        let row_struct = super::make_return_row(row);
+
+        let krate = self.crate_path();
+        let ident = row.struct_ident();
+        let arg_ident = quote::format_ident!("row");
+        let from_fields = row.fields.iter().enumerate().map(|(idx, field)| {
+            let field_ident = &field.name;
+            let literal = proc_macro2::Literal::usize_unsuffixed(idx);
+            let fetch_typ = if field.typ.needs_conversion() {
+                field.typ.to_fetch_tokens()
+            } else {
+                field.typ.to_row_tokens()
+            };
+            // `FromRowError` hands the row back to the caller, so it cannot be built with `?`
+            let fetched = quote::quote! {
+                match #arg_ident.get_opt::<#fetch_typ, _>(#literal) {
+                    Some(Ok(v)) => v,
+                    _ => return Err(#krate::FromRowError(#arg_ident)),
+                }
+            };
+            let value = if field.typ.needs_conversion() {
+                field.typ.unwrap_fetched(fetched)
+            } else {
+                fetched
+            };
+            quote::quote! {#field_ident:#value}
+        });
+
+        quote::quote! {
+            #row_struct
+
+            impl #krate::prelude::FromRow for #ident {
+                fn from_row_opt(#arg_ident: #krate::Row) -> Result<Self, #krate::FromRowError> {
+                    Ok(Self{
+                        #(#from_fields,)*
+                    })
+                }
+            }
+        }
+    }
+
+    fn copy_cheap_types(&self) -> &[(&str, &[&str])] {
+        const COPY_CHEAP: &[(&str, &[&str])] = &[
+            ("bool", &["bool", "boolean"]),
+            // int type is handle in `MySqlTypeMap::find_column_type`
+            ("u16", &["year"]),
+            ("f32", &["float"]),
+            ("f64", &["double", "double precision", "real"]),
+            // `mysql_common` has no zoned date time, `TIMESTAMP` is returned in the session time zone
+            ("chrono::NaiveDateTime", &["timestamp", "datetime"]),
+            ("chrono::NaiveDate", &["date"]),
+            ("chrono::NaiveTime", &["time"]),
+        ];
+        COPY_CHEAP
+    }
+
+    fn default_types(&self) -> &[(&str, Option<&str>, &[&'static str])] {
+        /// https://github.com/sqlc-dev/sqlc/blob/v1.29.0/internal/codegen/golang/mysql_type.go
+        /// https://docs.rs/mysql_common/latest/mysql_common/value/convert/index.html
+        const DEFAULT_TYPE: &[(&str, Option<&str>, &[&str])] = &[
+            (
+                "String",
+                Some("str"),
+                &[
+                    "varchar",
+                    "text",
+                    "char",
+                    "tinytext",
+                    "mediumtext",
+                    "longtext",
+                ],
+            ),
+            (
+                "Vec<u8>",
+                Some("[u8]"),
+                &[
+                    "blob",
+                    "binary",
+                    "varbinary",
+                    "tinyblob",
+                    "mediumblob",
+                    "longblob",
+                ],
+            ),
+            ("serde_json::Value", None, &["json"]),
+            // `enum` is handled in `MySqlTypeMap::find_column_type`
+            ("String", Some("str"), &["decimal", "dec", "fixed"]),
+            // comma separated members
+            ("String", Some("str"), &["set"]),
+            (
+                "Vec<u8>",
+                Some("[u8]"),
+                &[
+                    "geometry",
+                    "point",
+                    "linestring",
+                    "polygon",
+                    "multipoint",
+                    "multilinestring",
+                    "multipolygon",
+                    "geometrycollection",
+                ],
+            ),
+        ];
+        DEFAULT_TYPE
+    }
+
+    /// `Params::Positional` of the query, expanding `sqlc.slice` parameters in place
+    fn params(&self, query_ast: &super::QueryAst) -> proc_macro2::TokenStream {
+        let krate = self.crate_path();
+        if query_ast.fields().next().is_none() {
+            return quote::quote! {#krate::Params::Empty};
+        }
+
+        let to_value = |f: &crate::query::ColumnField| {
+            let name = &f.name;
+            if f.typ.is_trait_object() {
+                quote::quote! {#krate::prelude::ToValue::to_value(self.#name)}
+            } else {
+                quote::quote! {#krate::prelude::ToValue::to_value(&self.#name)}
+            }
+        };
+
+        if query_ast.need_expand_query() {
+            let param_it = query_ast.fields().map(|f| {
+                let name = &f.name;
+                if f.typ.is_array() {
+                    quote::quote! {self.#name.iter().map(#krate::prelude::ToValue::to_value)}
+                } else {
+                    let value = to_value(f);
+                    quote::quote! {core::iter::once(#value)}
+                }
+            });
+            quote::quote! {
+                #krate::Params::Positional(
+                    core::iter::empty()
+                        #(.chain(#param_it))*
+                        .collect()
+                )
+            }
+        } else {
+            let values = query_ast.fields().map(to_value);
+            quote::quote! {
+                #krate::Params::Positional(Vec::from([#(#values,)*]))
+            }
+        }
+    }
+}
+
+impl DbCrate for Mysql {
+    fn type_map(&self) -> Box<dyn TypeMapper> {
+        let mut map: Box<dyn TypeMapper> = Box::new(super::sqlx::MySqlTypeMap::default());
+
+        for (owned_type, db_types) in self.copy_cheap_types() {
+            let owned_type = syn::parse_str::<syn::Type>(owned_type).expect("Failed to parse type");
+
+            for db_type in db_types.iter() {
+                map.insert_db_type(db_type, RsType::new(owned_type.clone(), None, true));
+            }
+        }
+
+        for (owned_type, slice_type, db_types) in self.default_types() {
+            let owned_type = syn::parse_str::<syn::Type>(owned_type).expect("Failed to parse type");
+            let slice_type = slice_type
+                .map(|s| syn::parse_str::<syn::Type>(s).expect("Failed to parse slice type"));
+
+            for db_type in db_types.iter() {
+                map.insert_db_type(
+                    db_type,
+                    RsType::new(owned_type.clone(), slice_type.clone(), false),
+                );
+            }
+        }
+        map
+    }
+
+    fn untyped_param(&self) -> Option<UntypedParam> {
+        match self {
+            Mysql::Sync => Some(UntypedParam::TraitObject(
+                syn::parse_quote! {dyn mysql::prelude::ToValue},
+            )),
+            // futures hold `&self` across `.await`, so the value must be `Sync` to keep them `Send`
+            Mysql::Async => Some(UntypedParam::TraitObject(
+                syn::parse_quote! {(dyn mysql_async::prelude::ToValue + Sync)},
+            )),
+        }
+    }
+
+    fn defined_enum(&self, enum_type: &DbEnum) -> proc_macro2::TokenStream {
+        let krate = self.crate_path();
+        let derives = &enum_type.derives;
+        let enum_name = enum_type.ident();
+        let idents = enum_type
+            .values
+            .iter()
+            .map(|v| value_ident(v))
+            .collect::<Vec<_>>();
+        let names = &enum_type.values;
+        let bytes = enum_type
+            .values
+            .iter()
+            .map(|v| proc_macro2::Literal::byte_string(v.as_bytes()));
+
+        quote::quote! {
+            #[derive(Debug, Clone, Copy, #(#derives),*)]
+            pub enum #enum_name {
+                #(#idents,)*
+            }
+
+            impl From<#enum_name> for #krate::Value {
+                fn from(value: #enum_name) -> Self {
+                    let value = match value {
+                        #(#enum_name::#idents => #names,)*
+                    };
+                    #krate::Value::Bytes(value.as_bytes().to_vec())
+                }
+            }
+
+            impl TryFrom<#krate::Value> for #enum_name {
+                type Error = #krate::FromValueError;
+
+                fn try_from(value: #krate::Value) -> Result<Self, Self::Error> {
+                    let variant = match &value {
+                        #krate::Value::Bytes(bytes) => match bytes.as_slice() {
+                            #(#bytes => Some(Self::#idents),)*
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    variant.ok_or(#krate::FromValueError(value))
+                }
+            }
+
+            impl #krate::prelude::FromValue for #enum_name {
+                type Intermediate = Self;
+            }
+        }
+    }
+
+    fn defined_newtype(&self, newtype: &DbNewtype) -> proc_macro2::TokenStream {
+        let krate = self.crate_path();
+        let ident = newtype.ident();
+        let inner = newtype.inner.owned();
+        let derives = super::newtype_derives(newtype);
+        quote::quote! {
+            #[derive(#derives)]
+            pub struct #ident(pub #inner);
+
+            impl From<#ident> for #krate::Value {
+                fn from(value: #ident) -> Self {
+                    value.0.into()
+                }
+            }
+
+            impl TryFrom<#krate::Value> for #ident {
+                type Error = #krate::FromValueError;
+
+                fn try_from(value: #krate::Value) -> Result<Self, Self::Error> {
+                    <#inner as #krate::prelude::FromValue>::from_value_opt(value).map(Self)
+                }
+            }
+
+            impl #krate::prelude::FromValue for #ident {
+                type Intermediate = Self;
+            }
+        }
+    }
+
+    fn defined_composite(&self, _composite: &DbComposite) -> proc_macro2::TokenStream {
+        quote::quote! {
+            compile_error!("composite types are only supported by postgres")
+        }
+    }
+
+    fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
+        let query_ast = super::QueryAst::new(query, crate::db_crates::DataBaseKind::MySql);
+
+        let krate = self.crate_path();
+        let client_ident = quote::format_ident!("conn");
+        let client_typ = self.generic_client_type();
+        let error_typ = quote::quote! {#krate::Error};
+        let row_ident = row.struct_ident();
+        let async_part = self.async_part();
+        let await_part = self.await_part();
+
+        let query_fns = match query.annotation {
+            // `mysql::Error` has no variant for a missing row, so only `query_opt` is generated
+            Annotation::One => {
+                quote::quote! {
+                    pub #async_part fn query_opt(&self, #client_ident: #client_typ)->Result<Option<#row_ident>, #error_typ>{
+                        #client_ident.exec_first(self.query_str(), self.as_params()) #await_part
+                    }
+                }
+            }
+            Annotation::Many => {
+                // `mysql_async` maps rows on the connection's task
+                let map_bounds = match self {
+                    Mysql::Sync => quote::quote! {F: FnMut(#row_ident) -> T},
+                    Mysql::Async => {
+                        quote::quote! {F: FnMut(#row_ident) -> T + Send + 'static, T: Send + 'static}
+                    }
+                };
+                quote::quote! {
+                    pub #async_part fn query_many(&self, #client_ident: #client_typ)->Result<Vec<#row_ident>, #error_typ>{
+                        self.query_map(#client_ident, |row| row) #await_part
+                    }
+
+                    pub #async_part fn query_map<T, F>(&self, #client_ident: #client_typ, f: F)->Result<Vec<T>, #error_typ>
+                    where
+                        #map_bounds
+                    {
+                        #client_ident.exec_map(self.query_str(), self.as_params(), f) #await_part
+                    }
+                }
+            }
+            Annotation::Exec => {
+                quote::quote! {
+                    pub #async_part fn execute(&self, #client_ident: #client_typ)->Result<(), #error_typ>{
+                        #client_ident.exec_drop(self.query_str(), self.as_params()) #await_part
+                    }
+                }
+            }
+            Annotation::ExecResult | Annotation::ExecRows | Annotation::ExecLastId => {
+                let (output_typ, output) = if matches!(query.annotation, Annotation::ExecLastId) {
+                    (quote::quote! {Option<u64>}, quote::quote! {last_insert_id})
+                } else {
+                    (quote::quote! {u64}, quote::quote! {affected_rows})
+                };
+                let fn_body = match self {
+                    Mysql::Sync => quote::quote! {
+                        let result = #client_ident.exec_iter(self.query_str(), self.as_params())?;
+                        Ok(result.#output())
+                    },
+                    Mysql::Async => quote::quote! {
+                        let result = #client_ident.exec_iter(self.query_str(), self.as_params()).await?;
+                        let #output = result.#output();
+                        result.drop_result().await?;
+                        Ok(#output)
+                    },
+                };
+                quote::quote! {
+                    pub #async_part fn execute(&self, #client_ident: #client_typ)->Result<#output_typ, #error_typ>{
+                        #fn_body
+                    }
+                }
+            }
+            _ => quote::quote! {},
+        };
+
+        let fetch_tt = {
+            let imp_ident = query_ast.impl_ident();
+            let params = self.params(&query_ast);
+
+            quote::quote! {
+                impl #imp_ident {
+                    #query_fns
+
+                    pub fn as_params(&self) -> #krate::Params {
+                        #params
+                    }
+                }
+            }
+        };
+
+        let returning_row = self.returning_row(row);
+        let builder_tt = query_ast.make_builder();
+        quote::quote! {
+            #returning_row
+            #query_ast
+            #fetch_tt
+            #builder_tt
+        }
+    }
+}
+
+impl DbCrateConfig for Mysql {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db_crates::snapshot;
+
+    fn generate(db_crate: Mysql) -> proc_macro2::TokenStream {
+        let enum_tt = db_crate.defined_enum(&DbEnum {
+            name: "authors_status".to_string(),
+            values: vec!["active".to_string(), "retired".to_string()],
+            derives: vec![],
+        });
+        let queries_tt = snapshot::generate_queries(&db_crate, &snapshot::author_queries());
+        quote::quote! {
+            #enum_tt
+            #queries_tt
+        }
+    }
+
+    #[test]
+    fn test_mysql_snapshot() {
+        snapshot::assert_snapshot("mysql", generate(Mysql::Sync));
+    }
+
+    #[test]
+    fn test_mysql_async_snapshot() {
+        snapshot::assert_snapshot("mysql_async", generate(Mysql::Async));
+    }
+}
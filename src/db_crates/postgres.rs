@@ -1,20 +1,32 @@
 use quote::ToTokens;
 
-use super::DbCrate;
+use super::{DbCrate, DbCrateConfig, Pool, SyncAsync};
 use crate::{
-    query::{Annotation, DbEnum, Query, ReturningRows, RsType, TypeMapper},
+    query::{
+        Annotation, DbComposite, DbEnum, DbNewtype, Query, ReturningRows, RsType, TypeMapper,
+        UntypedParam,
+    },
     value_ident,
 };
 
 #[derive(Debug, Clone, Copy, Default)]
-pub(crate) enum Postgres {
+pub(crate) enum Driver {
     Sync,
     #[default]
     Tokio,
     DeadPool,
 }
 
-impl<'de> serde::Deserialize<'de> for Postgres {
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Postgres {
+    driver: Driver,
+    /// Pools whose connections the query methods accept through `PostgresClient`
+    pools: Vec<Pool>,
+    /// Generate the methods of both `postgres` and `tokio-postgres`
+    sync_async: Option<SyncAsync>,
+}
+
+impl<'de> serde::Deserialize<'de> for Driver {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -31,53 +43,282 @@ impl<'de> serde::Deserialize<'de> for Postgres {
     }
 }
 
+impl<'de> serde::Deserialize<'de> for Postgres {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let driver = Driver::deserialize(deserializer)?;
+        Ok(Self {
+            driver,
+            ..Default::default()
+        })
+    }
+}
+
 impl Postgres {
     fn generic_client_type(&self, lifetime: Option<&syn::Lifetime>) -> syn::Type {
         let l = lifetime.map(|s| s.to_token_stream()).unwrap_or_default();
-        match self {
-            Postgres::Sync => syn::parse_quote! {&#l mut impl postgres::GenericClient},
-            Postgres::Tokio => syn::parse_quote! {&#l impl tokio_postgres::GenericClient},
-            Postgres::DeadPool => syn::parse_quote! {&#l impl deadpool_postgres::GenericClient},
+        match self.driver {
+            Driver::Sync => syn::parse_quote! {&#l mut impl postgres::GenericClient},
+            Driver::Tokio => syn::parse_quote! {&#l impl tokio_postgres::GenericClient},
+            Driver::DeadPool => syn::parse_quote! {&#l impl deadpool_postgres::GenericClient},
+        }
+    }
+
+    /// Client of the query methods, which also accepts pooled connections if `pools` are set
+    fn query_client_type(&self, lifetime: Option<&syn::Lifetime>) -> syn::Type {
+        if self.pools.is_empty() {
+            return self.generic_client_type(lifetime);
+        }
+        let l = lifetime.map(|s| s.to_token_stream()).unwrap_or_default();
+        match self.driver {
+            Driver::Sync => syn::parse_quote! {&#l mut impl PostgresClient},
+            Driver::Tokio | Driver::DeadPool => syn::parse_quote! {&#l impl PostgresClient},
+        }
+    }
+
+    /// Take the `GenericClient` out of a `PostgresClient`
+    fn unwrap_client(&self, client: &syn::Ident) -> proc_macro2::TokenStream {
+        if self.pools.is_empty() {
+            proc_macro2::TokenStream::new()
+        } else {
+            quote::quote! {let #client = #client.client();}
+        }
+    }
+
+    /// `PostgresClient` implemented for the crate's clients and the pooled connections of `pools`
+    fn pool_client(&self) -> proc_macro2::TokenStream {
+        let (krate, mutability) = match self.driver {
+            Driver::Sync => (quote::quote! {postgres}, quote::quote! {mut}),
+            Driver::Tokio | Driver::DeadPool => (quote::quote! {tokio_postgres}, quote::quote! {}),
+        };
+        let pool_impls = self.pools.iter().map(|pool| {
+            let pooled = match pool {
+                Pool::Bb8 => quote::quote! {
+                    impl<M> PostgresClient for bb8::PooledConnection<'_, M>
+                    where
+                        M: bb8::ManageConnection<Connection = #krate::Client>,
+                },
+                Pool::R2d2 => quote::quote! {
+                    impl<M> PostgresClient for r2d2::PooledConnection<M>
+                    where
+                        M: r2d2::ManageConnection<Connection = #krate::Client>,
+                },
+            };
+            quote::quote! {
+                #pooled
+                {
+                    type Client = #krate::Client;
+                    fn client(&#mutability self) -> &#mutability #krate::Client {
+                        self
+                    }
+                }
+            }
+        });
+        quote::quote! {
+            use #krate::GenericClient as _;
+
+            /// Client of the query methods, implemented for clients, transactions and pooled connections
+            pub trait PostgresClient {
+                type Client: #krate::GenericClient;
+                fn client(&#mutability self) -> &#mutability Self::Client;
+            }
+
+            impl PostgresClient for #krate::Client {
+                type Client = Self;
+                fn client(&#mutability self) -> &#mutability Self {
+                    self
+                }
+            }
+
+            impl PostgresClient for #krate::Transaction<'_> {
+                type Client = Self;
+                fn client(&#mutability self) -> &#mutability Self {
+                    self
+                }
+            }
+
+            #(#pool_impls)*
         }
     }
 
     fn row_type(&self) -> syn::Type {
-        match self {
-            Postgres::Sync => syn::parse_quote! {postgres::Row},
-            Postgres::Tokio => syn::parse_quote! {tokio_postgres::Row},
-            Postgres::DeadPool => syn::parse_quote! {deadpool_postgres::tokio_postgres::Row},
+        match self.driver {
+            Driver::Sync => syn::parse_quote! {postgres::Row},
+            Driver::Tokio => syn::parse_quote! {tokio_postgres::Row},
+            Driver::DeadPool => syn::parse_quote! {deadpool_postgres::tokio_postgres::Row},
         }
     }
 
     fn error_type(&self) -> syn::Type {
-        match self {
-            Postgres::Sync => syn::parse_quote! {postgres::Error},
-            Postgres::Tokio => syn::parse_quote! {tokio_postgres::Error},
-            Postgres::DeadPool => syn::parse_quote! { deadpool_postgres::tokio_postgres::Error},
+        match self.driver {
+            Driver::Sync => syn::parse_quote! {postgres::Error},
+            Driver::Tokio => syn::parse_quote! {tokio_postgres::Error},
+            Driver::DeadPool => syn::parse_quote! { deadpool_postgres::tokio_postgres::Error},
         }
     }
 
     fn async_part(&self) -> proc_macro2::TokenStream {
-        match self {
-            Postgres::Sync => quote::quote! {},
-            Postgres::Tokio => quote::quote! {async},
-            Postgres::DeadPool => quote::quote! {async},
+        match self.driver {
+            Driver::Sync => quote::quote! {},
+            Driver::Tokio => quote::quote! {async},
+            Driver::DeadPool => quote::quote! {async},
         }
     }
 
     fn await_part(&self) -> proc_macro2::TokenStream {
-        match self {
-            Postgres::Sync => quote::quote! {},
-            Postgres::Tokio => quote::quote! {.await},
-            Postgres::DeadPool => quote::quote! {.await},
+        match self.driver {
+            Driver::Sync => quote::quote! {},
+            Driver::Tokio => quote::quote! {.await},
+            Driver::DeadPool => quote::quote! {.await},
         }
     }
 
     fn stmt_type(&self) -> syn::Type {
-        match self {
-            Postgres::Sync => syn::parse_quote! {postgres::Statement},
-            Postgres::Tokio => syn::parse_quote! {tokio_postgres::Statement},
-            Postgres::DeadPool => syn::parse_quote! {deadpool_postgres::tokio_postgres::Statement},
+        match self.driver {
+            Driver::Sync => syn::parse_quote! {postgres::Statement},
+            Driver::Tokio => syn::parse_quote! {tokio_postgres::Statement},
+            Driver::DeadPool => syn::parse_quote! {deadpool_postgres::tokio_postgres::Statement},
+        }
+    }
+
+    /// Query methods and `prepare` of the driver, with `suffix` appended to their names
+    fn query_methods(
+        &self,
+        row: &ReturningRows,
+        query: &Query,
+        suffix: &str,
+    ) -> proc_macro2::TokenStream {
+        let client_ident = quote::format_ident!("client");
+        let client_typ = self.generic_client_type(None);
+        let query_client_typ = self.query_client_type(None);
+        let iter_client_typ = self.query_client_type(Some(&syn::parse_quote! {'row_iter}));
+        let unwrap_client = self.unwrap_client(&client_ident);
+        let error_typ = self.error_type();
+        let row_ident = row.struct_ident();
+        let async_part = self.async_part();
+        let method = |name: &str| quote::format_ident!("{name}{suffix}");
+        let prepare = method("prepare");
+        let (query_one, query_opt, query_many, execute) = (
+            method("query_one"),
+            method("query_opt"),
+            method("query_many"),
+            method("execute"),
+        );
+        let (query_iter, query_stream) = (method("query_iter"), method("query_stream"));
+        let await_part = self.await_part();
+
+        let query_fns = match query.annotation {
+            Annotation::One => {
+                quote::quote! {
+                    pub #async_part fn #query_one(&self,#client_ident: #query_client_typ)->Result<#row_ident,#error_typ>{
+                        #unwrap_client
+                        let stmt = self.#prepare(#client_ident) #await_part?;
+                        let row = #client_ident.query_one(&stmt, &self.as_params()) #await_part?;
+                        #row_ident::from_row(&row)
+                    }
+
+                    pub #async_part fn #query_opt(&self,#client_ident: #query_client_typ)->Result<Option<#row_ident>,#error_typ>{
+                        #unwrap_client
+                        let stmt = self.#prepare(#client_ident) #await_part?;
+                        let row = #client_ident.query_opt(&stmt, &self.as_params()) #await_part?;
+                        match row {
+                            Some(row) => Ok(Some(#row_ident::from_row(&row)?)),
+                            None => Ok(None)
+                        }
+                    }
+                }
+            }
+            Annotation::Many => {
+                let stream_fetch = {
+                    match self.driver {
+                        Driver::Sync => {
+                            quote::quote! {
+                                pub fn #query_iter<'row_iter>(&self,#client_ident: #iter_client_typ)
+                                ->Result<postgres::RowIter<'row_iter>,#error_typ>
+                                {
+                                    #unwrap_client
+                                    let stmt = self.#prepare(#client_ident)?;
+                                    #client_ident.query_raw(&stmt, self.as_params())
+                                }
+                            }
+                        }
+                        Driver::Tokio => {
+                            quote::quote! {
+                                pub async fn #query_stream(&self,#client_ident: #query_client_typ)
+                                ->Result<tokio_postgres::RowStream,#error_typ>{
+                                    #unwrap_client
+                                    let stmt = self.#prepare(#client_ident).await?;
+                                    let st = #client_ident.query_raw(&stmt, self.as_params()).await?;
+                                    Ok(st)
+                                }
+                            }
+                        }
+                        Driver::DeadPool => {
+                            quote::quote! {
+                                pub async fn #query_stream(&self,#client_ident: #query_client_typ)
+                                ->Result<deadpool_postgres::tokio_postgres::RowStream,#error_typ>{
+                                    #unwrap_client
+                                    let stmt = self.#prepare(#client_ident).await?;
+                                    let st = #client_ident.query_raw(&stmt, self.as_params()).await?;
+                                    Ok(st)
+                                }
+                            }
+                        }
+                    }
+                };
+
+                let vec_fetch = quote::quote! {
+                    pub #async_part fn #query_many(&self,#client_ident: #query_client_typ)->Result<Vec<#row_ident>,#error_typ>{
+                        #unwrap_client
+                        let stmt = self.#prepare(#client_ident) #await_part?;
+                        let rows = #client_ident.query(&stmt, &self.as_params()) #await_part?;
+                        rows.into_iter().map(|r|#row_ident::from_row(&r)).collect()
+                    }
+                };
+
+                quote::quote! {
+                    #stream_fetch
+                    #vec_fetch
+                }
+            }
+            Annotation::Exec | Annotation::ExecResult | Annotation::ExecRows => {
+                quote::quote! {
+                    pub #async_part fn #execute(&self,#client_ident: #query_client_typ)->Result<u64,#error_typ>{
+                        #unwrap_client
+                        let stmt = self.#prepare(#client_ident) #await_part?;
+                        #client_ident.execute(&stmt, &self.as_params()) #await_part
+                    }
+                }
+            }
+            _ => quote::quote! {},
+        };
+
+        let stmt_typ = self.stmt_type();
+
+        let prepare_fn = match self.driver {
+            Driver::Sync => quote::quote! {
+                pub fn #prepare(&self,#client_ident: #client_typ) -> Result<#stmt_typ, #error_typ> {
+                    #client_ident.prepare(self.query_str())
+                }
+            },
+            Driver::Tokio => quote::quote! {
+                pub async fn #prepare(&self,#client_ident: #client_typ) -> Result<#stmt_typ, #error_typ> {
+                    #client_ident.prepare(self.query_str()).await
+                }
+            },
+            Driver::DeadPool => quote::quote! {
+                pub async fn #prepare(&self,#client_ident: #client_typ) -> Result<#stmt_typ, #error_typ> {
+                    #client_ident.prepare_cached(self.query_str()).await
+                }
+            },
+        };
+
+        quote::quote! {
+            #query_fns
+
+            #prepare_fn
         }
     }
 
@@ -91,14 +332,34 @@ impl Postgres {
         let from_fields = row.fields.iter().enumerate().map(|(idx, field)| {
             let field_ident = &field.name;
             let literal = proc_macro2::Literal::usize_unsuffixed(idx);
-            quote::quote! {#field_ident:#arg_ident.try_get(#literal)?}
+            if field.typ.needs_conversion() {
+                let fetch_typ = field.typ.to_fetch_tokens();
+                let value = field
+                    .typ
+                    .unwrap_fetched(quote::quote! {#arg_ident.try_get::<_, #fetch_typ>(#literal)?});
+                quote::quote! {#field_ident:#value}
+            } else {
+                quote::quote! {#field_ident:#arg_ident.try_get(#literal)?}
+            }
         });
-        let from_tt = quote::quote! {
-            impl #ident {
-                pub fn from_row(#arg_ident: &#row_typ)->Result<Self,#error_typ>{
-                    Ok(Self{
-                        #(#from_fields,)*
-                    })
+        let from_tt = if self.sync_async.is_some() {
+            quote::quote! {
+                impl FromPostgresRow for #ident {
+                    fn from_row(#arg_ident: &tokio_postgres::Row)->Result<Self,tokio_postgres::Error>{
+                        Ok(Self{
+                            #(#from_fields,)*
+                        })
+                    }
+                }
+            }
+        } else {
+            quote::quote! {
+                impl #ident {
+                    pub fn from_row(#arg_ident: &#row_typ)->Result<Self,#error_typ>{
+                        Ok(Self{
+                            #(#from_fields,)*
+                        })
+                    }
                 }
             }
         };
@@ -198,17 +459,45 @@ impl DbCrate for Postgres {
         Box::new(map)
     }
 
+    fn json_wrapper_type(&self) -> Option<syn::Path> {
+        Some(syn::parse_quote! {postgres_types::Json})
+    }
+
+    fn untyped_param(&self) -> Option<UntypedParam> {
+        Some(UntypedParam::TraitObject(
+            syn::parse_quote! {(dyn ToSql + Sync)},
+        ))
+    }
+
+    fn nd_array_type(&self) -> Option<syn::Path> {
+        Some(syn::parse_quote! {postgres_array::Array})
+    }
+
     fn init(&self) -> proc_macro2::TokenStream {
-        let use_tosql = match self {
-            Postgres::Sync => quote::quote! {use postgres::types::ToSql;},
-            Postgres::Tokio => quote::quote! {use tokio_postgres::types::ToSql;},
-            Postgres::DeadPool => {
+        if self.sync_async.is_some() {
+            // `postgres` re-exports the types of `tokio_postgres`
+            return quote::quote! {
+                use tokio_postgres::types::ToSql;
+
+                /// Rows decoded by both the `postgres` and `tokio_postgres` methods.
+                /// `postgres::Row` is this `tokio_postgres::Row` only if both crates use the same `tokio-postgres`
+                pub trait FromPostgresRow: Sized {
+                    fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error>;
+                }
+            };
+        }
+        let use_tosql = match self.driver {
+            Driver::Sync => quote::quote! {use postgres::types::ToSql;},
+            Driver::Tokio => quote::quote! {use tokio_postgres::types::ToSql;},
+            Driver::DeadPool => {
                 quote::quote! {use deadpool_postgres::tokio_postgres::types::ToSql;}
             }
         };
+        let client_tt = (!self.pools.is_empty()).then(|| self.pool_client());
 
         quote::quote! {
             #use_tosql
+            #client_tt
         }
     }
     fn defined_enum(&self, enum_type: &DbEnum) -> proc_macro2::TokenStream {
@@ -240,137 +529,82 @@ impl DbCrate for Postgres {
             }
         }
     }
-    fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
-        let query_ast = super::QueryAst::new(query, crate::db_crates::DataBaseKind::Postgres);
-
-        let client_ident = quote::format_ident!("client");
-        let client_typ = self.generic_client_type(None);
-        let error_typ = self.error_type();
-        let row_ident = row.struct_ident();
-        let async_part = self.async_part();
-        let await_part = self.await_part();
+    fn defined_newtype(&self, newtype: &DbNewtype) -> proc_macro2::TokenStream {
+        let ident = newtype.ident();
+        let inner = newtype.inner.owned();
+        let derives = super::newtype_derives(newtype);
+        quote::quote! {
+            #[derive(#derives, postgres_types::ToSql, postgres_types::FromSql)]
+            #[postgres(transparent)]
+            pub struct #ident(pub #inner);
+        }
+    }
 
-        let query_fns = match query.annotation {
-            Annotation::One => {
-                quote::quote! {
-                    pub #async_part fn query_one(&self,#client_ident: #client_typ)->Result<#row_ident,#error_typ>{
-                        let stmt = self.prepare(#client_ident) #await_part?;
-                        let row = #client_ident.query_one(&stmt, &self.as_params()) #await_part?;
-                        #row_ident::from_row(&row)
-                    }
+    fn defined_composite(&self, composite: &DbComposite) -> proc_macro2::TokenStream {
+        let fields = composite.fields.iter().map(|field| {
+            let name = &field.name;
+            let original_name = &field.name_original;
+            let typ = field.typ.to_row_tokens();
+            quote::quote! {
+                #[postgres(name = #original_name)]
+                pub #name: #typ
+            }
+        });
 
-                    pub #async_part fn query_opt(&self,#client_ident: #client_typ)->Result<Option<#row_ident>,#error_typ>{
-                        let stmt = self.prepare(#client_ident) #await_part?;
-                        let row = #client_ident.query_opt(&stmt, &self.as_params()) #await_part?;
-                        match row {
-                            Some(row) => Ok(Some(#row_ident::from_row(&row)?)),
-                            None => Ok(None)
-                        }
-                    }
-                }
+        let original_name = &composite.name;
+        let ident = composite.ident();
+        quote::quote! {
+            #[derive(Debug, Clone, postgres_types::ToSql, postgres_types::FromSql)]
+            #[postgres(name = #original_name)]
+            pub struct #ident {
+                #(#fields,)*
             }
-            Annotation::Many => {
-                let stream_fetch = {
-                    match self {
-                        Postgres::Sync => {
-                            quote::quote! {
-                                pub fn query_iter<'row_iter>(&self,#client_ident:&'row_iter mut  impl postgres::GenericClient)
-                                ->Result<postgres::RowIter<'row_iter>,#error_typ>
-                                {
-                                    let stmt = self.prepare(#client_ident)?;
-                                    #client_ident.query_raw(&stmt, self.as_params())
-                                }
-                            }
-                        }
-                        Postgres::Tokio => {
-                            quote::quote! {
-                                pub async fn query_stream(&self,#client_ident: #client_typ)
-                                ->Result<tokio_postgres::RowStream,#error_typ>{
-                                    let stmt = self.prepare(#client_ident).await?;
-                                    let st = #client_ident.query_raw(&stmt, self.as_params()).await?;
-                                    Ok(st)
-                                }
-                            }
-                        }
-                        Postgres::DeadPool => {
-                            quote::quote! {
-                                 pub async fn query_stream(&self,#client_ident: #client_typ)
-                                ->Result<deadpool_postgres::tokio_postgres::RowStream,#error_typ>{
-                                    let stmt = self.prepare(#client_ident).await?;
-                                    let st = #client_ident.query_raw(&stmt, self.as_params()).await?;
-                                    Ok(st)
-                                }
-                            }
-                        }
-                    }
-                };
+        }
+    }
 
-                let vec_fetch = quote::quote! {
-                    pub #async_part fn query_many(&self,#client_ident: #client_typ)->Result<Vec<#row_ident>,#error_typ>{
-                        let stmt = self.prepare(#client_ident) #await_part?;
-                        let rows = #client_ident.query(&stmt, &self.as_params()) #await_part?;
-                        rows.into_iter().map(|r|#row_ident::from_row(&r)).collect()
-                    }
-                };
+    fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
+        let query_ast = super::QueryAst::new(query, crate::db_crates::DataBaseKind::Postgres);
 
+        let methods = match self.sync_async {
+            None => self.query_methods(row, query, ""),
+            Some(sync_async) => {
+                let (sync_suffix, async_suffix) = match sync_async {
+                    SyncAsync::SuffixAsync => ("", "_async"),
+                    SyncAsync::SuffixSync => ("_sync", ""),
+                };
+                let driver = |driver| Self {
+                    driver,
+                    ..self.clone()
+                };
+                let sync_tt = driver(Driver::Sync).query_methods(row, query, sync_suffix);
+                let async_tt = driver(Driver::Tokio).query_methods(row, query, async_suffix);
                 quote::quote! {
-                    #stream_fetch
-                    #vec_fetch
-                }
-            }
-            Annotation::Exec | Annotation::ExecResult | Annotation::ExecRows => {
-                quote::quote! {
-                    pub #async_part fn execute(&self,#client_ident: #client_typ)->Result<u64,#error_typ>{
-                        let stmt = self.prepare(#client_ident) #await_part?;
-                        #client_ident.execute(&stmt, &self.as_params()) #await_part
-                    }
+                    #sync_tt
+                    #async_tt
                 }
             }
-            _ => quote::quote! {},
         };
 
         let fetch_tt = {
-            let struct_ident = &query_ast.ident;
-            let imp_ident = if query_ast.need_lifetime() {
-                let lifetime = &query_ast.lifetime;
-                quote::quote! {<#lifetime> #struct_ident<#lifetime>}
-            } else {
-                quote::quote! {#struct_ident}
-            };
+            let imp_ident = query_ast.impl_ident();
 
             let param_num = proc_macro2::Literal::usize_unsuffixed(query.fields.len());
             let params = query_ast.fields().map(|f| {
                 let name = &f.name;
-                quote::quote! {
-                    &self.#name
-                }
-            });
-
-            let stmt_typ = self.stmt_type();
-
-            let prepare_fn = match self {
-                Postgres::Sync => quote::quote! {
-                    pub fn prepare(&self,#client_ident: #client_typ) -> Result<#stmt_typ, #error_typ> {
-                        #client_ident.prepare(self.query_str())
+                if f.typ.is_trait_object() {
+                    quote::quote! {
+                        self.#name
                     }
-                },
-                Postgres::Tokio => quote::quote! {
-                    pub async fn prepare(&self,#client_ident: #client_typ) -> Result<#stmt_typ, #error_typ> {
-                        #client_ident.prepare(self.query_str()).await
+                } else {
+                    quote::quote! {
+                        &self.#name
                     }
-                },
-                Postgres::DeadPool => quote::quote! {
-                    pub async fn prepare(&self,#client_ident: #client_typ) -> Result<#stmt_typ, #error_typ> {
-                        #client_ident.prepare_cached(self.query_str()).await
-                    }
-                },
-            };
+                }
+            });
 
             quote::quote! {
                 impl #imp_ident {
-                    #query_fns
-
-                    #prepare_fn
+                    #methods
 
                     pub fn as_params(&self) -> [&(dyn ToSql + Sync); #param_num] {
                         [ #(#params,)* ]
@@ -389,3 +623,153 @@ impl DbCrate for Postgres {
         }
     }
 }
+
+impl DbCrateConfig for Postgres {
+    fn set_pools(&mut self, pools: &[Pool]) -> Result<(), String> {
+        // deadpool-postgres has its own `GenericClient` for pooled connections
+        let supported = match self.driver {
+            Driver::Sync => Some(Pool::R2d2),
+            Driver::Tokio => Some(Pool::Bb8),
+            Driver::DeadPool => None,
+        };
+        if let Some(pool) = pools.iter().find(|pool| Some(**pool) != supported) {
+            return Err(format!("`{pool}` pool is not supported by this db_crate."));
+        }
+        self.pools = pools.to_vec();
+        Ok(())
+    }
+
+    fn set_sync_async(&mut self, sync_async: SyncAsync) -> Result<(), String> {
+        if !matches!(self.driver, Driver::Sync | Driver::Tokio) {
+            return Err("sync_async is supported by postgres and tokio-postgres.".to_string());
+        }
+        if !self.pools.is_empty() {
+            return Err("sync_async cannot be combined with pools.".to_string());
+        }
+        self.sync_async = Some(sync_async);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db_crates::snapshot;
+
+    fn generate(db_crate: &Postgres) -> proc_macro2::TokenStream {
+        let author_columns = || {
+            vec![
+                snapshot::column("id", "bigint", true),
+                snapshot::column("name", "pg_catalog.varchar", true),
+                snapshot::column("bio", "text", false),
+            ]
+        };
+        let queries = vec![
+            snapshot::query(
+                "GetAuthor",
+                ":one",
+                "SELECT id, name, bio FROM authors WHERE id = $1",
+                author_columns(),
+                vec![snapshot::column("id", "bigint", true)],
+            ),
+            snapshot::query(
+                "ListAuthors",
+                ":many",
+                "SELECT id, name, bio FROM authors ORDER BY name",
+                author_columns(),
+                vec![],
+            ),
+            snapshot::query(
+                "DeleteAuthors",
+                ":execrows",
+                "DELETE FROM authors",
+                vec![],
+                vec![],
+            ),
+        ];
+        let init_tt = db_crate.init();
+        let queries_tt = snapshot::generate_queries(db_crate, &queries);
+        quote::quote! {
+            #init_tt
+            #queries_tt
+        }
+    }
+
+    #[test]
+    fn test_postgres_r2d2_snapshot() {
+        let mut db_crate = Postgres {
+            driver: Driver::Sync,
+            ..Default::default()
+        };
+        db_crate.set_pools(&[Pool::R2d2]).unwrap();
+        snapshot::assert_snapshot("postgres_r2d2", generate(&db_crate));
+    }
+
+    #[test]
+    fn test_tokio_postgres_bb8_snapshot() {
+        let mut db_crate = Postgres::default();
+        db_crate.set_pools(&[Pool::Bb8]).unwrap();
+        snapshot::assert_snapshot("tokio_postgres_bb8", generate(&db_crate));
+    }
+
+    #[test]
+    fn test_unsupported_pools() {
+        assert!(Postgres::default().set_pools(&[Pool::R2d2]).is_err());
+        let mut db_crate = Postgres {
+            driver: Driver::DeadPool,
+            ..Default::default()
+        };
+        assert!(db_crate.set_pools(&[Pool::Bb8]).is_err());
+    }
+
+    #[test]
+    fn test_postgres_sync_async_snapshot() {
+        let mut db_crate = Postgres::default();
+        db_crate.set_sync_async(SyncAsync::SuffixAsync).unwrap();
+        snapshot::assert_snapshot("postgres_sync_async", generate(&db_crate));
+    }
+
+    #[test]
+    fn test_unsupported_sync_async() {
+        let mut db_crate = Postgres {
+            driver: Driver::DeadPool,
+            ..Default::default()
+        };
+        assert!(db_crate.set_sync_async(SyncAsync::SuffixSync).is_err());
+        let mut db_crate = Postgres::default();
+        db_crate.set_pools(&[Pool::Bb8]).unwrap();
+        assert!(db_crate.set_sync_async(SyncAsync::SuffixSync).is_err());
+    }
+
+    #[test]
+    fn test_postgres_composite_snapshot() {
+        use crate::db_crates::DbCrateExt as _;
+
+        let db_crate = Postgres::default();
+        let users = |schema: &str| crate::plugin::Table {
+            rel: Some(crate::plugin::Identifier {
+                schema: schema.to_string(),
+                name: "users".to_string(),
+                ..Default::default()
+            }),
+            columns: vec![
+                snapshot::column("id", "bigint", true),
+                snapshot::column("name", "text", false),
+            ],
+            ..Default::default()
+        };
+        let composites_tt = ["public", "audit"].map(|schema| {
+            let composite =
+                DbComposite::from_table(&db_crate.db_type_map(), &users(schema), "public");
+            db_crate.defined_composite(&composite.unwrap())
+        });
+        snapshot::assert_snapshot("postgres_composite", quote::quote! {#(#composites_tt)*});
+    }
+
+    #[test]
+    fn test_postgres_untyped_snapshot() {
+        let queries_tt =
+            snapshot::generate_queries(&Postgres::default(), &snapshot::untyped_queries());
+        snapshot::assert_snapshot("postgres_untyped", queries_tt);
+    }
+}
@@ -1,14 +1,14 @@
 use crate::{
-    db_crates::DbCrate,
-    query::{Annotation, RsType, TypeMapper},
+    db_crates::{DbCrate, DbCrateConfig, Pool},
+    query::{Annotation, RsType, TypeMapper, UntypedParam},
 };
 
-struct SqliteTypeMap {
+pub(super) struct SqliteTypeMap {
     type_map: std::collections::BTreeMap<String, RsType>,
 }
 
 impl SqliteTypeMap {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         const COPY_CHEAP: &[(&str, &[&str])] = &[
             ("bool", &["bool", "boolean"]),
             ("i8", &["tinyint"]),
@@ -16,12 +16,18 @@ impl SqliteTypeMap {
             ("i32", &["mediumint", "int4"]),
             ("i64", &["int", "integer", "bigint", "int8"]),
             ("f64", &["real", "double", "doubleprecision", "float"]),
-            // NUMERIC affinity
-            ("f64", &["numeric"]),
+            // declared types, see https://docs.rs/rusqlite/latest/rusqlite/types/index.html
+            ("chrono::NaiveDateTime", &["datetime", "timestamp"]),
+            ("chrono::NaiveDate", &["date"]),
+            ("chrono::NaiveTime", &["time"]),
+            ("uuid::Uuid", &["uuid"]),
         ];
         const DEFAULT_TYPE: &[(&str, Option<&str>, &[&str])] = &[
             ("String", Some("str"), &["text", "clob"]),
             ("Vec<u8>", Some("[u8]"), &["blob"]),
+            ("serde_json::Value", None, &["json", "jsonb"]),
+            // NUMERIC affinity
+            ("SqliteNumeric", None, &["numeric"]),
         ];
 
         let mut m = Self {
@@ -67,6 +73,13 @@ impl TypeMapper for SqliteTypeMap {
             return Some(rs_type.clone());
         };
 
+        // declared type with arguments, e.g. `DATETIME(3)`
+        if let Some((base_type, _)) = col_type.split_once('(')
+            && let Some(rs_type) = self.find_rs_type(base_type.trim())
+        {
+            return Some(rs_type.clone());
+        }
+
         // Rust type determine by affinity
         // See https://www.sqlite.org/datatype3.html
         if col_type.contains("int") {
@@ -94,16 +107,36 @@ impl TypeMapper for SqliteTypeMap {
 }
 
 #[derive(Debug, Clone, Copy, Default)]
-pub(crate) struct Rusqlite;
+pub(crate) enum Driver {
+    #[default]
+    Sync,
+    Owned(OwnedDriver),
+}
 
-impl<'de> serde::Deserialize<'de> for Rusqlite {
+/// Async drivers which run the statement on a connection thread and need owned parameters
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum OwnedDriver {
+    Tokio,
+    DeadPool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Rusqlite {
+    driver: Driver,
+    /// Pools whose connections implement `RusqliteClient`
+    pools: Vec<Pool>,
+}
+
+impl<'de> serde::Deserialize<'de> for Driver {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
         match s.trim() {
-            "rusqlite" => Ok(Self),
+            "rusqlite" => Ok(Self::Sync),
+            "tokio-rusqlite" => Ok(Self::Owned(OwnedDriver::Tokio)),
+            "deadpool-sqlite" => Ok(Self::Owned(OwnedDriver::DeadPool)),
             _ => Err(serde::de::Error::custom(format!(
                 "`{s}` is unsupported crate."
             ))),
@@ -111,6 +144,19 @@ impl<'de> serde::Deserialize<'de> for Rusqlite {
     }
 }
 
+impl<'de> serde::Deserialize<'de> for Rusqlite {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let driver = Driver::deserialize(deserializer)?;
+        Ok(Self {
+            driver,
+            pools: Vec::new(),
+        })
+    }
+}
+
 impl Rusqlite {
     fn returning_row(row: &crate::ReturningRows) -> proc_macro2::TokenStream {
         let row_struct = super::make_return_row(row);
@@ -121,7 +167,15 @@ impl Rusqlite {
         let from_fields = row.fields.iter().enumerate().map(|(idx, field)| {
             let field_ident = &field.name;
             let literal = proc_macro2::Literal::usize_unsuffixed(idx);
-            quote::quote! {#field_ident:#arg_ident.get(#literal)?}
+            if field.typ.needs_conversion() {
+                let fetch_typ = field.typ.to_fetch_tokens();
+                let value = field
+                    .typ
+                    .unwrap_fetched(quote::quote! {#arg_ident.get::<_, #fetch_typ>(#literal)?});
+                quote::quote! {#field_ident:#value}
+            } else {
+                quote::quote! {#field_ident:#arg_ident.get(#literal)?}
+            }
         });
 
         let from_tt = quote::quote! {
@@ -141,13 +195,239 @@ impl Rusqlite {
     }
 }
 
+impl Rusqlite {
+    /// Statement logic of the query as `(name, output, body)`. `body` runs `receiver`'s statement on `client`
+    fn statement_fns(
+        row: &crate::ReturningRows,
+        query: &crate::query::Query,
+        receiver: &proc_macro2::TokenStream,
+        client: &syn::Ident,
+    ) -> Vec<(
+        syn::Ident,
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+    )> {
+        let row_ident = row.struct_ident();
+        match query.annotation {
+            Annotation::One => vec![
+                (
+                    quote::format_ident!("query_one"),
+                    quote::quote! {#row_ident},
+                    quote::quote! {
+                        #receiver.prepare(#client)?
+                            .query_row(#receiver.as_params(), #row_ident::from_row)
+                    },
+                ),
+                (
+                    quote::format_ident!("query_opt"),
+                    quote::quote! {Option<#row_ident>},
+                    quote::quote! {
+                        #receiver.prepare(#client)?
+                            .query_map(#receiver.as_params(), #row_ident::from_row)?
+                            .next()
+                            .transpose()
+                    },
+                ),
+            ],
+            Annotation::Many => vec![(
+                quote::format_ident!("query_many"),
+                quote::quote! {Vec<#row_ident>},
+                quote::quote! {
+                    #receiver.prepare(#client)?
+                        .query_map(#receiver.as_params(), #row_ident::from_row)?
+                        .collect()
+                },
+            )],
+            Annotation::Exec | Annotation::ExecResult | Annotation::ExecRows => vec![(
+                quote::format_ident!("execute"),
+                quote::quote! {usize},
+                quote::quote! {
+                    #receiver.prepare(#client)?
+                        .execute(#receiver.as_params())
+                },
+            )],
+            _ => vec![],
+        }
+    }
+
+    /// `async fn`s which move owned copies of the parameters into `tokio_rusqlite::Connection::call`
+    /// or `deadpool_sqlite::Object::interact` and run the statement logic on a query struct borrowing them
+    fn owned_fns(
+        driver: OwnedDriver,
+        row: &crate::ReturningRows,
+        query_ast: &super::QueryAst,
+    ) -> proc_macro2::TokenStream {
+        let ident = &query_ast.ident;
+        let client_ident = quote::format_ident!("conn");
+        let receiver = quote::quote! {query};
+        let statement_fns = Self::statement_fns(row, query_ast.query, &receiver, &client_ident);
+
+        let names = query_ast.fields().map(|f| &f.name).collect::<Vec<_>>();
+        let owned = query_ast
+            .fields()
+            .map(|f| {
+                let name = &f.name;
+                match (
+                    f.typ.via().is_some(),
+                    f.typ.need_lifetime(),
+                    f.typ.is_optional(),
+                ) {
+                    (true, _, _) => quote::quote! {self.#name.clone()},
+                    (false, true, true) => quote::quote! {self.#name.map(ToOwned::to_owned)},
+                    (false, true, false) => quote::quote! {ToOwned::to_owned(self.#name)},
+                    (false, false, _) => quote::quote! {self.#name},
+                }
+            })
+            .collect::<Vec<_>>();
+        let borrowed = query_ast.fields().map(|f| {
+            let name = &f.name;
+            match (
+                f.typ.via().is_some(),
+                f.typ.need_lifetime(),
+                f.typ.is_optional(),
+            ) {
+                (false, true, true) => {
+                    quote::quote! {#name: #name.as_ref().map(std::borrow::Borrow::borrow)}
+                }
+                (false, true, false) => quote::quote! {#name: &#name},
+                _ => quote::quote! {#name},
+            }
+        });
+        let (query_owned, query_field) = if query_ast.need_expand_query() {
+            (
+                Some(quote::quote! {let __query = self.__query.clone();}),
+                Some(quote::quote! {__query}),
+            )
+        } else {
+            (None, None)
+        };
+        let rebuild = quote::quote! {
+            let (#(#names,)*) = (#(#owned,)*);
+            #query_owned
+        };
+        let query_struct = quote::quote! {
+            #ident {
+                #(#borrowed,)*
+                #query_field
+            }
+        };
+
+        statement_fns
+            .into_iter()
+            .map(|(name, output, body)| match driver {
+                OwnedDriver::DeadPool => quote::quote! {
+                    pub async fn #name(&self, #client_ident: &deadpool_sqlite::Object)->Result<#output, DeadpoolSqliteError>{
+                        #rebuild
+                        let result = #client_ident.interact(move |#client_ident| -> rusqlite::Result<#output> {
+                            let #receiver = #query_struct;
+                            #body
+                        }).await?;
+                        Ok(result?)
+                    }
+                },
+                // `Into::into` lets the closure error be inferred, which is `tokio_rusqlite::Error`
+                // for 0.5 / 0.6 and `rusqlite::Error` for the generic `call` of 0.7
+                OwnedDriver::Tokio => quote::quote! {
+                    pub async fn #name(&self, #client_ident: &tokio_rusqlite::Connection)->Result<#output, tokio_rusqlite::Error>{
+                        #rebuild
+                        #client_ident.call(move |#client_ident| {
+                            let #receiver = #query_struct;
+                            let result: rusqlite::Result<#output> = #body;
+                            result.map_err(Into::into)
+                        }).await
+                    }
+                },
+            })
+            .collect()
+    }
+}
+
 impl DbCrate for Rusqlite {
     fn type_map(&self) -> Box<dyn crate::query::TypeMapper> {
         Box::new(SqliteTypeMap::new())
     }
 
+    fn untyped_param(&self) -> Option<UntypedParam> {
+        match self.driver {
+            Driver::Sync => Some(UntypedParam::TraitObject(
+                syn::parse_quote! {dyn rusqlite::ToSql},
+            )),
+            // borrowed trait objects cannot be moved into `Connection::call` or `Object::interact`
+            Driver::Owned(_) => None,
+        }
+    }
+
     fn init(&self) -> proc_macro2::TokenStream {
+        let numeric_tt = super::sqlite_numeric_tokens();
+        let r2d2_tt = self.pools.contains(&Pool::R2d2).then(|| {
+            quote::quote! {
+                impl<M> RusqliteClient for r2d2::PooledConnection<M>
+                where
+                    M: r2d2::ManageConnection<Connection = rusqlite::Connection>,
+                {
+                    fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>> {
+                        rusqlite::Connection::prepare(self, sql)
+                    }
+                }
+            }
+        });
+        let error_tt = matches!(self.driver, Driver::Owned(OwnedDriver::DeadPool)).then(|| {
+            quote::quote! {
+                /// Error of a query run with `deadpool_sqlite::Object::interact`
+                #[derive(Debug)]
+                pub enum DeadpoolSqliteError {
+                    Interact(deadpool_sqlite::InteractError),
+                    Rusqlite(rusqlite::Error),
+                }
+
+                impl std::fmt::Display for DeadpoolSqliteError {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        match self {
+                            Self::Interact(e) => write!(f, "{e}"),
+                            Self::Rusqlite(e) => write!(f, "{e}"),
+                        }
+                    }
+                }
+
+                impl std::error::Error for DeadpoolSqliteError {}
+
+                impl From<deadpool_sqlite::InteractError> for DeadpoolSqliteError {
+                    fn from(value: deadpool_sqlite::InteractError) -> Self {
+                        Self::Interact(value)
+                    }
+                }
+
+                impl From<rusqlite::Error> for DeadpoolSqliteError {
+                    fn from(value: rusqlite::Error) -> Self {
+                        Self::Rusqlite(value)
+                    }
+                }
+            }
+        });
         quote::quote! {
+            #numeric_tt
+
+            impl rusqlite::types::FromSql for SqliteNumeric {
+                fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+                    match value {
+                        rusqlite::types::ValueRef::Integer(v) => Ok(Self::Integer(v)),
+                        rusqlite::types::ValueRef::Real(v) => Ok(Self::Real(v)),
+                        rusqlite::types::ValueRef::Text(_) => value.as_str().map(|v| Self::Text(v.to_owned())),
+                        _ => Err(rusqlite::types::FromSqlError::InvalidType),
+                    }
+                }
+            }
+
+            impl rusqlite::ToSql for SqliteNumeric {
+                fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+                    match self {
+                        Self::Integer(v) => v.to_sql(),
+                        Self::Real(v) => v.to_sql(),
+                        Self::Text(v) => v.to_sql(),
+                    }
+                }
+            }
+
             pub trait RusqliteClient {
                 fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>>;
             }
@@ -163,6 +443,16 @@ impl DbCrate for Rusqlite {
                     rusqlite::Connection::prepare(&self, sql)
                 }
             }
+
+            impl RusqliteClient for rusqlite::Savepoint<'_> {
+                fn prepare(&self, sql: &str) -> rusqlite::Result<rusqlite::Statement<'_>> {
+                    rusqlite::Connection::prepare(self, sql)
+                }
+            }
+
+            #r2d2_tt
+
+            #error_tt
         }
     }
 
@@ -172,6 +462,37 @@ impl DbCrate for Rusqlite {
         }
     }
 
+    fn defined_newtype(&self, newtype: &crate::query::DbNewtype) -> proc_macro2::TokenStream {
+        let ident = newtype.ident();
+        let inner = newtype.inner.owned();
+        let derives = super::newtype_derives(newtype);
+        quote::quote! {
+            #[derive(#derives)]
+            pub struct #ident(pub #inner);
+
+            impl rusqlite::types::ToSql for #ident {
+                fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+                    self.0.to_sql()
+                }
+            }
+
+            impl rusqlite::types::FromSql for #ident {
+                fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+                    <#inner as rusqlite::types::FromSql>::column_result(value).map(Self)
+                }
+            }
+        }
+    }
+
+    fn defined_composite(
+        &self,
+        _composite: &crate::query::DbComposite,
+    ) -> proc_macro2::TokenStream {
+        quote::quote! {
+            compile_error!("sqlite do not support composite type")
+        }
+    }
+
     fn generate_query(
         &self,
         row: &crate::query::ReturningRows,
@@ -181,53 +502,25 @@ impl DbCrate for Rusqlite {
         let query_ast = super::QueryAst::new(query, crate::db_crates::DataBaseKind::Sqlite);
         let builder_tt = query_ast.make_builder();
 
-        let query_fns = match query.annotation {
-            Annotation::One => {
-                let row_ident = row.struct_ident();
-                quote::quote! {
-                    pub fn query_one(&self, client: &impl RusqliteClient)->rusqlite::Result<#row_ident>{
-                            self.prepare(client)?
-                                .query_row(self.as_params(), #row_ident::from_row)
-                    }
-                    pub fn query_opt(&self, client: &impl RusqliteClient)->rusqlite::Result<Option<#row_ident>>{
-                            self.prepare(client)?
-                                .query_map(self.as_params(), #row_ident::from_row)?
-                                .next()
-                                .transpose()
-                    }
-                }
-            }
-            Annotation::Many => {
-                let row_ident = row.struct_ident();
-                quote::quote! {
-                    pub fn query_many(&self, client: &impl RusqliteClient)->rusqlite::Result<Vec<#row_ident>>{
-                        self.prepare(client)?
-                            .query_map(self.as_params(), #row_ident::from_row)?
-                            .collect()
-                    }
-                }
-            }
-            Annotation::Exec | Annotation::ExecResult | Annotation::ExecRows => {
-                quote::quote! {
-                    pub fn execute(&self, client: &impl RusqliteClient)->rusqlite::Result<usize>{
-                        self.prepare(client)?
-                            .execute(self.as_params())
-                    }
-                }
-            }
-            _ => {
-                quote::quote! {}
+        let query_fns = match self.driver {
+            Driver::Sync => {
+                let client_ident = quote::format_ident!("client");
+                Self::statement_fns(row, query, &quote::quote! {self}, &client_ident)
+                    .into_iter()
+                    .map(|(name, output, body)| {
+                        quote::quote! {
+                            pub fn #name(&self, #client_ident: &impl RusqliteClient)->rusqlite::Result<#output>{
+                                #body
+                            }
+                        }
+                    })
+                    .collect()
             }
+            Driver::Owned(driver) => Self::owned_fns(driver, row, &query_ast),
         };
 
         let fetch_tt = {
-            let struct_ident = &query_ast.ident;
-            let imp_ident = if query_ast.need_lifetime() {
-                let lifetime = &query_ast.lifetime;
-                quote::quote! {<#lifetime> #struct_ident<#lifetime>}
-            } else {
-                quote::quote! {#struct_ident}
-            };
+            let imp_ident = query_ast.impl_ident();
 
             let params: proc_macro2::TokenStream = if query_ast.need_expand_query() {
                 let param_it = query.fields.iter().map(|f| {
@@ -250,7 +543,11 @@ impl DbCrate for Rusqlite {
             } else {
                 let param_it = query.fields.iter().map(|f| {
                     let name = &f.name;
-                    quote::quote! {self.#name}
+                    if f.typ.via().is_some() {
+                        quote::quote! {&self.#name}
+                    } else {
+                        quote::quote! {self.#name}
+                    }
                 });
                 quote::quote! {
                     ( #(#param_it,)* )
@@ -280,3 +577,66 @@ impl DbCrate for Rusqlite {
         }
     }
 }
+
+impl DbCrateConfig for Rusqlite {
+    fn set_pools(&mut self, pools: &[Pool]) -> Result<(), String> {
+        // deadpool-sqlite is the `db_crate` itself
+        let supported = match self.driver {
+            Driver::Sync => Some(Pool::R2d2),
+            Driver::Owned(_) => None,
+        };
+        if let Some(pool) = pools.iter().find(|pool| Some(**pool) != supported) {
+            return Err(format!("`{pool}` pool is not supported by this db_crate."));
+        }
+        self.pools = pools.to_vec();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db_crates::snapshot;
+
+    #[test]
+    fn test_tokio_rusqlite_snapshot() {
+        let queries_tt = snapshot::generate_queries(
+            &Rusqlite {
+                driver: Driver::Owned(OwnedDriver::Tokio),
+                pools: Vec::new(),
+            },
+            &snapshot::author_queries(),
+        );
+        snapshot::assert_snapshot("tokio_rusqlite", queries_tt);
+    }
+
+    #[test]
+    fn test_deadpool_sqlite_snapshot() {
+        let db_crate = Rusqlite {
+            driver: Driver::Owned(OwnedDriver::DeadPool),
+            pools: Vec::new(),
+        };
+        let init_tt = db_crate.init();
+        let queries_tt = snapshot::generate_queries(&db_crate, &snapshot::author_queries());
+        snapshot::assert_snapshot(
+            "deadpool_sqlite",
+            quote::quote! {
+                #init_tt
+                #queries_tt
+            },
+        );
+    }
+
+    #[test]
+    fn test_set_pools() {
+        let mut db_crate = Rusqlite::default();
+        assert!(db_crate.set_pools(&[Pool::R2d2]).is_ok());
+        assert!(db_crate.init().to_string().contains("r2d2"));
+
+        let mut db_crate = Rusqlite {
+            driver: Driver::Owned(OwnedDriver::DeadPool),
+            pools: Vec::new(),
+        };
+        assert!(db_crate.set_pools(&[Pool::R2d2]).is_err());
+    }
+}
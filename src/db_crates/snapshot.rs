@@ -0,0 +1,159 @@
+//! Helpers to compare generated code with the files under `snapshots/`
+use super::{DbCrate, DbCrateExt as _};
+use crate::{
+    plugin,
+    query::{DbTypeMap, Query, ReturningRows},
+};
+
+pub(super) fn column(name: &str, db_type: &str, not_null: bool) -> plugin::Column {
+    plugin::Column {
+        name: name.to_string(),
+        not_null,
+        table: Some(plugin::Identifier {
+            name: "authors".to_string(),
+            ..Default::default()
+        }),
+        r#type: Some(plugin::Identifier {
+            name: db_type.to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+pub(super) fn query(
+    name: &str,
+    cmd: &str,
+    text: &str,
+    columns: Vec<plugin::Column>,
+    params: Vec<plugin::Column>,
+) -> plugin::Query {
+    plugin::Query {
+        name: name.to_string(),
+        cmd: cmd.to_string(),
+        text: text.to_string(),
+        columns,
+        params: params
+            .into_iter()
+            .enumerate()
+            .map(|(idx, column)| plugin::Parameter {
+                number: idx as i32 + 1,
+                column: Some(column),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// `:one`, `:many` with `sqlc.slice`, `:execlastid` and `:execrows` queries on `authors`
+pub(super) fn author_queries() -> Vec<plugin::Query> {
+    let author_columns = || {
+        vec![
+            column("id", "bigint", true),
+            column("name", "varchar", true),
+            column("bio", "text", false),
+        ]
+    };
+    vec![
+        query(
+            "GetAuthor",
+            ":one",
+            "SELECT id, name, bio FROM authors WHERE id = ?",
+            author_columns(),
+            vec![column("id", "bigint", true)],
+        ),
+        query(
+            "ListAuthorsByIds",
+            ":many",
+            "SELECT id, name, bio FROM authors WHERE id IN (/*SLICE:ids*/?) ORDER BY name",
+            author_columns(),
+            vec![plugin::Column {
+                is_sqlc_slice: true,
+                ..column("ids", "bigint", true)
+            }],
+        ),
+        query(
+            "CreateAuthor",
+            ":execlastid",
+            "INSERT INTO authors (name, bio) VALUES (?, ?)",
+            vec![],
+            vec![
+                column("name", "varchar", true),
+                column("bio", "text", false),
+            ],
+        ),
+        query(
+            "DeleteAuthors",
+            ":execrows",
+            "DELETE FROM authors",
+            vec![],
+            vec![],
+        ),
+    ]
+}
+
+/// Postgres queries with parameters whose type sqlc cannot infer, next to typed ones
+pub(super) fn untyped_queries() -> Vec<plugin::Query> {
+    let untyped = |name: &str| plugin::Column {
+        table: None,
+        ..column(name, "unknown", false)
+    };
+    vec![
+        query(
+            "UpdateBio",
+            ":exec",
+            "UPDATE authors SET bio = $1 WHERE id = $2",
+            vec![],
+            vec![untyped("bio"), column("id", "bigint", true)],
+        ),
+        query(
+            "EchoName",
+            ":one",
+            "SELECT name, $1 AS tag FROM authors WHERE name = $2",
+            vec![column("name", "text", true)],
+            vec![untyped("column_1"), column("name", "text", true)],
+        ),
+    ]
+}
+
+/// Generate the rows and query structs of `queries`
+pub(super) fn generate_queries(
+    db_crate: &impl DbCrate,
+    queries: &[plugin::Query],
+) -> proc_macro2::TokenStream {
+    generate_queries_with(db_crate, &db_crate.db_type_map(), queries)
+}
+
+/// Generate the rows and query structs of `queries` with `db_type`, e.g. with overrides
+pub(super) fn generate_queries_with(
+    db_crate: &impl DbCrate,
+    db_type: &DbTypeMap,
+    queries: &[plugin::Query],
+) -> proc_macro2::TokenStream {
+    queries
+        .iter()
+        .map(|q| {
+            let row = ReturningRows::from_query(db_type, &Default::default(), q).unwrap();
+            let query = Query::from_query(db_type, q).unwrap();
+            db_crate.generate_query(&row, &query)
+        })
+        .collect()
+}
+
+/// Compare with `snapshots/{name}.snap`. Set `UPDATE_SNAPSHOTS=1` to rewrite it
+pub(super) fn assert_snapshot(name: &str, tokens: proc_macro2::TokenStream) {
+    let actual = prettyplease::unparse(&syn::parse2(tokens).unwrap());
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/db_crates/snapshots")
+        .join(format!("{name}.snap"));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        expected == actual,
+        "{} differs from the generated code, rerun with `UPDATE_SNAPSHOTS=1` if the change is intended\n{actual}",
+        path.display()
+    );
+}
@@ -1,6 +1,11 @@
-use super::DbCrate;
+use quote::ToTokens as _;
+
+use super::{DbCrate, DbCrateConfig};
 use crate::{
-    query::{Annotation, DbEnum, Query, ReturningRows, RsType, SimpleTypeMap, TypeMapper},
+    query::{
+        Annotation, DbComposite, DbEnum, DbNewtype, Query, ReturningRows, RsType, SimpleTypeMap,
+        TypeMapper, UntypedParam,
+    },
     value_ident,
 };
 
@@ -25,7 +30,42 @@ impl TypeMapper for MySqlTypeMap {
             return Some(rs_type.clone());
         };
 
-        match col_type.as_str() {
+        // `decimal(10, 2) unsigned` and the like share the mapping of their base type
+        let base_type = col_type
+            .split(['(', ' '])
+            .next()
+            .unwrap_or(&col_type)
+            .trim();
+        if base_type != col_type
+            && let Some(rs_type) = self.find_rs_type(base_type)
+        {
+            return Some(rs_type.clone());
+        }
+
+        match base_type {
+            // sqlc registers `ENUM(...)` columns as `{table}_{column}` enums in the catalog
+            "enum" => {
+                let inline_enum = column.table.as_ref().and_then(|table| {
+                    let column_name = if column.original_name.is_empty() {
+                        &column.name
+                    } else {
+                        &column.original_name
+                    };
+                    self.find_rs_type(&format!("{}_{}", table.name, column_name))
+                });
+                Some(inline_enum.cloned().unwrap_or_else(|| {
+                    RsType::new(
+                        syn::parse_str("String").unwrap(),
+                        Some(syn::parse_str("str").unwrap()),
+                        false,
+                    )
+                }))
+            }
+            // MySQL limits `bit(n)` to 64 bits, `bit` alone means `bit(1)`
+            "bit" => match column.length {
+                0 | 1 => Some(RsType::new(syn::parse_str("bool").unwrap(), None, true)),
+                _ => Some(RsType::new(syn::parse_str("u64").unwrap(), None, true)),
+            },
             "tinyint" => match (column.length, column.unsigned) {
                 (1, _) => Some(RsType::new(syn::parse_str("bool").unwrap(), None, true)),
                 (_, true) => Some(RsType::new(syn::parse_str("u8").unwrap(), None, true)),
@@ -61,6 +101,28 @@ impl TypeMapper for MySqlTypeMap {
     }
 }
 
+/// Restricts a type map to the columns `sqlx::Any` can bind, it has no array types
+pub struct AnyTypeMap {
+    inner: Box<dyn TypeMapper>,
+}
+
+impl TypeMapper for AnyTypeMap {
+    fn find_rs_type(&self, db_type_name: &str) -> Option<&RsType> {
+        self.inner.find_rs_type(db_type_name)
+    }
+
+    fn find_column_type(&self, column: &crate::plugin::Column) -> Option<RsType> {
+        if column.array_dims != 0 {
+            return None;
+        }
+        self.inner.find_column_type(column)
+    }
+
+    fn insert_db_type(&mut self, db_type: &str, rs_type: RsType) {
+        self.inner.insert_db_type(db_type, rs_type);
+    }
+}
+
 #[derive(Default)]
 pub struct SqliteTypeMap {
     /// db_type to rust type
@@ -82,6 +144,13 @@ impl TypeMapper for SqliteTypeMap {
             return Some(rs_type.clone());
         };
 
+        // declared type with arguments, e.g. `DATETIME(3)`
+        if let Some((base_type, _)) = col_type.split_once('(')
+            && let Some(rs_type) = self.find_rs_type(base_type.trim())
+        {
+            return Some(rs_type.clone());
+        }
+
         // Rust type determine by affinity
         // See https://www.sqlite.org/datatype3.html
         if col_type.contains("int") {
@@ -219,12 +288,302 @@ impl CopyDataSink {
     }
 }
 
+/// Multi-dimensional array support, because sqlx only decodes one-dimensional arrays
+struct PgNdArray;
+
+impl PgNdArray {
+    fn ident() -> syn::Ident {
+        quote::format_ident!("PgNdArray")
+    }
+
+    fn element_ident() -> syn::Ident {
+        quote::format_ident!("PgNdArrayElement")
+    }
+
+    fn box_error() -> syn::Type {
+        syn::parse_quote! {
+            sqlx::error::BoxDynError
+        }
+    }
+
+    fn struct_tokens() -> proc_macro2::TokenStream {
+        let ident = Self::ident();
+        let element_ident = Self::element_ident();
+        let error_type = Self::box_error();
+        quote::quote! {
+            /// Multi-dimensional array stored in row-major order
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct #ident<T> {
+                dims: Vec<PgNdArrayDim>,
+                elements: Vec<T>,
+            }
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct PgNdArrayDim {
+                pub len: usize,
+                pub lower_bound: i32,
+            }
+
+            /// Element types which can be decoded from a multi-dimensional array
+            pub trait #element_ident: Sized {
+                fn decode_element(value: Option<&[u8]>) -> Result<Self, #error_type>;
+            }
+        }
+    }
+
+    fn impl_fn() -> proc_macro2::TokenStream {
+        let ident = Self::ident();
+        let element_ident = Self::element_ident();
+        let error_type = Self::box_error();
+        quote::quote! {
+            impl<T> #ident<T> {
+                /// Create an array from row-major `elements`.
+                ///
+                /// Panics if the number of elements does not match `dims`.
+                pub fn from_parts(elements: Vec<T>, dims: Vec<PgNdArrayDim>) -> Self {
+                    let expected = if dims.is_empty() {
+                        0
+                    } else {
+                        dims.iter().map(|d| d.len).product()
+                    };
+                    assert_eq!(elements.len(), expected, "elements do not match dimensions");
+                    Self { dims, elements }
+                }
+
+                pub fn dims(&self) -> &[PgNdArrayDim] {
+                    &self.dims
+                }
+
+                pub fn elements(&self) -> &[T] {
+                    &self.elements
+                }
+
+                pub fn into_elements(self) -> Vec<T> {
+                    self.elements
+                }
+
+                /// Get an element by Postgres indices, which start at each dimension's lower bound
+                pub fn get(&self, indices: &[i32]) -> Option<&T> {
+                    if indices.len() != self.dims.len() {
+                        return None;
+                    }
+                    let mut offset = 0;
+                    for (idx, dim) in indices.iter().zip(self.dims.iter()) {
+                        let pos = usize::try_from(idx.checked_sub(dim.lower_bound)?).ok()?;
+                        if pos >= dim.len {
+                            return None;
+                        }
+                        offset = offset * dim.len + pos;
+                    }
+                    self.elements.get(offset)
+                }
+            }
+
+            impl<T: sqlx::postgres::PgHasArrayType> sqlx::Type<sqlx::Postgres> for #ident<T> {
+                fn type_info() -> sqlx::postgres::PgTypeInfo {
+                    T::array_type_info()
+                }
+
+                fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+                    T::array_compatible(ty)
+                }
+            }
+
+            impl<'q, T> sqlx::Encode<'q, sqlx::Postgres> for #ident<T>
+            where
+                T: sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>,
+            {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut sqlx::postgres::PgArgumentBuffer,
+                ) -> Result<sqlx::encode::IsNull, #error_type> {
+                    let oid = T::type_info()
+                        .oid()
+                        .ok_or("multi-dimensional arrays require an element type with a known OID")?;
+
+                    buf.extend(i32::try_from(self.dims.len())?.to_be_bytes());
+                    buf.extend(0_i32.to_be_bytes());
+                    buf.extend(oid.0.to_be_bytes());
+                    for dim in &self.dims {
+                        buf.extend(i32::try_from(dim.len)?.to_be_bytes());
+                        buf.extend(dim.lower_bound.to_be_bytes());
+                    }
+
+                    for element in &self.elements {
+                        let offset = buf.len();
+                        buf.extend(0_i32.to_be_bytes());
+                        let len = match element.encode_by_ref(buf)? {
+                            sqlx::encode::IsNull::Yes => -1_i32,
+                            sqlx::encode::IsNull::No => i32::try_from(buf.len() - offset - 4)?,
+                        };
+                        buf[offset..offset + 4].copy_from_slice(&len.to_be_bytes());
+                    }
+
+                    Ok(sqlx::encode::IsNull::No)
+                }
+            }
+
+            impl<'r, T: #element_ident> sqlx::Decode<'r, sqlx::Postgres> for #ident<T> {
+                fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, #error_type> {
+                    fn take<'a>(buf: &mut &'a [u8], n: usize) -> Result<&'a [u8], #error_type> {
+                        if buf.len() < n {
+                            return Err("unexpected end of array data".into());
+                        }
+                        let (head, tail) = buf.split_at(n);
+                        *buf = tail;
+                        Ok(head)
+                    }
+                    fn take_i32(buf: &mut &[u8]) -> Result<i32, #error_type> {
+                        Ok(i32::from_be_bytes(take(buf, 4)?.try_into()?))
+                    }
+
+                    if value.format() != sqlx::postgres::PgValueFormat::Binary {
+                        return Err("multi-dimensional arrays can only be decoded from binary format".into());
+                    }
+                    let mut buf = value.as_bytes()?;
+
+                    let ndim = usize::try_from(take_i32(&mut buf)?)?;
+                    let _flags = take_i32(&mut buf)?;
+                    let _element_oid = take_i32(&mut buf)?;
+
+                    let mut dims = Vec::with_capacity(ndim);
+                    for _ in 0..ndim {
+                        let len = usize::try_from(take_i32(&mut buf)?)?;
+                        let lower_bound = take_i32(&mut buf)?;
+                        dims.push(PgNdArrayDim { len, lower_bound });
+                    }
+
+                    let total = if dims.is_empty() {
+                        0
+                    } else {
+                        dims.iter().map(|d| d.len).product()
+                    };
+                    let mut elements = Vec::with_capacity(total);
+                    for _ in 0..total {
+                        let len = take_i32(&mut buf)?;
+                        let element = match usize::try_from(len) {
+                            Ok(len) => Some(take(&mut buf, len)?),
+                            Err(_) => None,
+                        };
+                        elements.push(T::decode_element(element)?);
+                    }
+
+                    Ok(Self { dims, elements })
+                }
+            }
+
+            impl<T: #element_ident> #element_ident for Option<T> {
+                fn decode_element(value: Option<&[u8]>) -> Result<Self, #error_type> {
+                    value.map(|v| T::decode_element(Some(v))).transpose()
+                }
+            }
+        }
+    }
+
+    /// Element types which can be decoded, with the expression decoding the non-null `bytes`
+    /// of an element in the binary format
+    fn element_decoders() -> Vec<(syn::Type, proc_macro2::TokenStream)> {
+        let from_be_bytes = |typ: syn::Type| {
+            let decode = quote::quote! {<#typ>::from_be_bytes(bytes.try_into()?)};
+            (typ, decode)
+        };
+        // dates and times count from 2000-01-01 00:00:00
+        let epoch = quote::quote! {
+            chrono::NaiveDate::from_ymd_opt(2000, 1, 1)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .ok_or("invalid epoch")?
+        };
+        let micros = quote::quote! {
+            chrono::Duration::microseconds(i64::from_be_bytes(bytes.try_into()?))
+        };
+        vec![
+            from_be_bytes(syn::parse_quote! {i8}),
+            from_be_bytes(syn::parse_quote! {i16}),
+            from_be_bytes(syn::parse_quote! {i32}),
+            from_be_bytes(syn::parse_quote! {i64}),
+            from_be_bytes(syn::parse_quote! {f32}),
+            from_be_bytes(syn::parse_quote! {f64}),
+            (
+                syn::parse_quote! {bool},
+                quote::quote! {bytes.first().ok_or("invalid bool element")? != &0},
+            ),
+            (
+                syn::parse_quote! {String},
+                quote::quote! {std::str::from_utf8(bytes)?.to_owned()},
+            ),
+            (syn::parse_quote! {Vec<u8>}, quote::quote! {bytes.to_vec()}),
+            (
+                syn::parse_quote! {sqlx::postgres::types::Oid},
+                quote::quote! {sqlx::postgres::types::Oid(u32::from_be_bytes(bytes.try_into()?))},
+            ),
+            (
+                syn::parse_quote! {uuid::Uuid},
+                quote::quote! {uuid::Uuid::from_slice(bytes)?},
+            ),
+            (
+                syn::parse_quote! {chrono::NaiveDate},
+                quote::quote! {
+                    #epoch
+                        .date()
+                        .checked_add_signed(chrono::Duration::days(
+                            i32::from_be_bytes(bytes.try_into()?).into(),
+                        ))
+                        .ok_or("date out of range")?
+                },
+            ),
+            (
+                syn::parse_quote! {chrono::NaiveTime},
+                quote::quote! {chrono::NaiveTime::default() + #micros},
+            ),
+            (
+                syn::parse_quote! {chrono::NaiveDateTime},
+                quote::quote! {
+                    #epoch.checked_add_signed(#micros).ok_or("timestamp out of range")?
+                },
+            ),
+            (
+                syn::parse_quote! {chrono::DateTime<chrono::Utc>},
+                quote::quote! {
+                    #epoch
+                        .checked_add_signed(#micros)
+                        .ok_or("timestamp out of range")?
+                        .and_utc()
+                },
+            ),
+        ]
+    }
+
+    /// `PgNdArrayElement` impls of the used `elements`
+    fn element_impls(elements: &[syn::Type]) -> proc_macro2::TokenStream {
+        let element_ident = Self::element_ident();
+        let error_type = Self::box_error();
+        let decoders = Self::element_decoders();
+        let impls = elements.iter().filter_map(|element| {
+            let element_str = element.to_token_stream().to_string();
+            let (_, decode) = decoders
+                .iter()
+                .find(|(typ, _)| typ.to_token_stream().to_string() == element_str)?;
+            Some(quote::quote! {
+                impl #element_ident for #element {
+                    fn decode_element(value: Option<&[u8]>) -> Result<Self, #error_type> {
+                        let bytes = value.ok_or(sqlx::error::UnexpectedNullError)?;
+                        Ok(#decode)
+                    }
+                }
+            })
+        });
+        quote::quote! {#(#impls)*}
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub(crate) enum Sqlx {
     #[default]
     Postgres,
     MySql,
     Sqlite,
+    /// `sqlx::Any` with queries parsed by the engine of the kind
+    Any(super::DataBaseKind),
 }
 
 impl<'de> serde::Deserialize<'de> for Sqlx {
@@ -237,6 +596,7 @@ impl<'de> serde::Deserialize<'de> for Sqlx {
             "sqlx-postgres" => Ok(Self::Postgres),
             "sqlx-mysql" => Ok(Self::MySql),
             "sqlx-sqlite" => Ok(Self::Sqlite),
+            "sqlx-any" => Ok(Self::Any(crate::db_crates::DataBaseKind::Postgres)),
             _ => Err(serde::de::Error::custom(format!(
                 "`{s}` is unsupported crate."
             ))),
@@ -250,20 +610,42 @@ impl From<Sqlx> for crate::db_crates::DataBaseKind {
             Sqlx::Postgres => Self::Postgres,
             Sqlx::MySql => Self::MySql,
             Sqlx::Sqlite => Self::Sqlite,
+            Sqlx::Any(kind) => kind,
         }
     }
 }
 
 impl Sqlx {
+    fn row_type(&self) -> syn::Path {
+        match self {
+            Sqlx::Postgres => syn::parse_quote! {sqlx::postgres::PgRow},
+            Sqlx::MySql => syn::parse_quote! {sqlx::mysql::MySqlRow},
+            Sqlx::Sqlite => syn::parse_quote! {sqlx::sqlite::SqliteRow},
+            Sqlx::Any(_) => syn::parse_quote! {sqlx::any::AnyRow},
+        }
+    }
+
     fn returning_row(&self, row: &ReturningRows) -> proc_macro2::TokenStream {
+        // `via` conversions cannot be expressed with the derive macro
+        if row.fields.iter().any(|f| f.typ.via().is_some()) {
+            return self.returning_row_manual(row);
+        }
         let mut row = row.clone();
 
         for field in row.fields.iter_mut() {
             let original = &field.name_original;
             let att = &field.attribute;
+            let json_att = field.typ.json_wrapper().map(|_| {
+                if field.typ.is_optional() {
+                    quote::quote! {#[sqlx(json(nullable))]}
+                } else {
+                    quote::quote! {#[sqlx(json)]}
+                }
+            });
             let attribute = quote::quote! {
                 #att
                 #[sqlx(rename = #original)]
+                #json_att
             };
             field.attribute = Some(attribute);
         }
@@ -276,6 +658,36 @@ impl Sqlx {
         }
     }
 
+    fn returning_row_manual(&self, row: &ReturningRows) -> proc_macro2::TokenStream {
+        let struct_tt = super::make_return_row(row);
+
+        let ident = row.struct_ident();
+        let row_typ = self.row_type();
+        let arg_ident = quote::format_ident!("row");
+        let from_fields = row.fields.iter().map(|field| {
+            let field_ident = &field.name;
+            let original = &field.name_original;
+            let fetch_typ = field.typ.to_fetch_tokens();
+            let value = field
+                .typ
+                .unwrap_fetched(quote::quote! {#arg_ident.try_get::<#fetch_typ, _>(#original)?});
+            quote::quote! {#field_ident:#value}
+        });
+
+        quote::quote! {
+            #struct_tt
+
+            impl<'r> sqlx::FromRow<'r, #row_typ> for #ident {
+                fn from_row(#arg_ident: &'r #row_typ) -> Result<Self, sqlx::Error> {
+                    use sqlx::Row;
+                    Ok(Self{
+                        #(#from_fields,)*
+                    })
+                }
+            }
+        }
+    }
+
     fn copy_cheap_types(&self) -> &[(&str, &[&str])] {
         match self {
             Sqlx::Postgres => {
@@ -302,10 +714,13 @@ impl Sqlx {
                 const COPY_CHEAP: &[(&str, &[&str])] = &[
                     ("bool", &["bool", "boolean"]),
                     // int type is handle in `get_column_type`
-                    ("int16", &["year"]),
+                    ("u16", &["year"]),
                     ("f32", &["float"]),
                     ("f64", &["double", "double precision", "real"]),
                     ("sqlx::mysql::types::MySqlTime", &["time"]),
+                    ("chrono::DateTime<chrono::Utc>", &["timestamp"]),
+                    ("chrono::NaiveDateTime", &["datetime"]),
+                    ("chrono::NaiveDate", &["date"]),
                 ];
                 COPY_CHEAP
             }
@@ -317,8 +732,39 @@ impl Sqlx {
                     ("i32", &["mediumint", "int4"]),
                     ("i64", &["int", "integer", "bigint", "int8"]),
                     ("f64", &["real", "double", "doubleprecision", "float"]),
-                    // NUMERIC affinity
-                    ("f64", &["numeric"]),
+                    // declared types, see https://docs.rs/sqlx/latest/sqlx/sqlite/types/index.html
+                    ("chrono::NaiveDateTime", &["datetime", "timestamp"]),
+                    ("chrono::NaiveDate", &["date"]),
+                    ("chrono::NaiveTime", &["time"]),
+                    ("uuid::Uuid", &["uuid"]),
+                ];
+                COPY_CHEAP
+            }
+            // https://docs.rs/sqlx/latest/sqlx/any/index.html
+            Sqlx::Any(crate::db_crates::DataBaseKind::Postgres) => {
+                const COPY_CHEAP: &[(&str, &[&str])] = &[
+                    ("i16", &["smallint", "int2", "pg_catalog.int2"]),
+                    ("i32", &["serial", "serial4", "pg_catalog.serial4"]),
+                    ("i64", &["bigserial", "serial8", "pg_catalog.serial8"]),
+                    ("i16", &["smallserial", "serial2", "pg_catalog.serial2"]),
+                    ("i32", &["integer", "int", "int4", "pg_catalog.int4"]),
+                    ("i64", &["bigint", "int8", "pg_catalog.int8"]),
+                    (
+                        "f64",
+                        &["float", "double precision", "float8", "pg_catalog.float8"],
+                    ),
+                    ("f32", &["real", "float4", "pg_catalog.float4"]),
+                    ("bool", &["boolean", "bool", "pg_catalog.bool"]),
+                ];
+                COPY_CHEAP
+            }
+            Sqlx::Any(_) => {
+                const COPY_CHEAP: &[(&str, &[&str])] = &[
+                    ("bool", &["bool", "boolean"]),
+                    ("i16", &["tinyint", "smallint", "int2"]),
+                    ("i32", &["mediumint", "int4"]),
+                    ("i64", &["int", "integer", "bigint", "int8"]),
+                    ("f64", &["real", "double", "doubleprecision", "float"]),
                 ];
                 COPY_CHEAP
             }
@@ -412,13 +858,62 @@ impl Sqlx {
                         ],
                     ),
                     ("serde_json::Value", None, &["json"]),
-                    ("String", Some("str"), &["decimal", "dec", "fixed", "enum"]),
+                    // `enum` is handled in `get_column_type`
+                    ("String", Some("str"), &["decimal", "dec", "fixed"]),
+                    ("MySqlSet", None, &["set"]),
+                    (
+                        "MySqlGeometry",
+                        None,
+                        &[
+                            "geometry",
+                            "point",
+                            "linestring",
+                            "polygon",
+                            "multipoint",
+                            "multilinestring",
+                            "multipolygon",
+                            "geometrycollection",
+                        ],
+                    ),
                 ];
                 DEFAULT_TYPE
             }
             Sqlx::Sqlite => {
                 /// https://github.com/sqlc-dev/sqlc/blob/v1.29.0/internal/codegen/golang/sqlite_type.go
                 /// https://docs.rs/sqlx/latest/sqlx/sqlite/types/index.html
+                const DEFAULT_TYPE: &[(&str, Option<&str>, &[&str])] = &[
+                    ("String", Some("str"), &["text", "clob"]),
+                    ("Vec<u8>", Some("[u8]"), &["blob"]),
+                    ("serde_json::Value", None, &["json", "jsonb"]),
+                    // NUMERIC affinity
+                    ("SqliteNumeric", None, &["numeric"]),
+                ];
+                DEFAULT_TYPE
+            }
+            Sqlx::Any(crate::db_crates::DataBaseKind::Postgres) => {
+                const DEFAULT_TYPE: &[(&str, Option<&str>, &[&str])] = &[
+                    (
+                        "String",
+                        Some("str"),
+                        &[
+                            "text",
+                            "pg_catalog.varchar",
+                            "pg_catalog.bpchar",
+                            "string",
+                            "citext",
+                            "name",
+                        ],
+                    ),
+                    (
+                        "Vec<u8>",
+                        Some("[u8]"),
+                        &["bytea", "blob", "pg_catalog.bytea"],
+                    ),
+                ];
+                DEFAULT_TYPE
+            }
+            Sqlx::Any(_) => {
+                // no NUMERIC affinity, `numeric` and undeclared date types are not mapped
                 const DEFAULT_TYPE: &[(&str, Option<&str>, &[&str])] = &[
                     ("String", Some("str"), &["text", "clob"]),
                     ("Vec<u8>", Some("[u8]"), &["blob"]),
@@ -433,22 +928,29 @@ impl Sqlx {
             Sqlx::Postgres => syn::parse_quote! {sqlx::Postgres},
             Sqlx::MySql => syn::parse_quote! {sqlx::MySql},
             Sqlx::Sqlite => syn::parse_quote! {sqlx::Sqlite},
+            Sqlx::Any(_) => syn::parse_quote! {sqlx::Any},
         }
     }
 
     fn query_bind(&self, query: &Query, query_ident: syn::Ident) -> proc_macro2::TokenStream {
         match self {
-            Self::Postgres => query
+            Self::Postgres | Self::Any(crate::db_crates::DataBaseKind::Postgres) => query
                 .fields
                 .iter()
                 .map(|f| {
                     let name = &f.name;
-                    quote::quote! {
-                        let #query_ident =  #query_ident.bind(self.#name);
+                    if f.typ.via().is_some() || f.typ.generic_param().is_some() {
+                        quote::quote! {
+                            let #query_ident =  #query_ident.bind(&self.#name);
+                        }
+                    } else {
+                        quote::quote! {
+                            let #query_ident =  #query_ident.bind(self.#name);
+                        }
                     }
                 })
                 .collect(),
-            Self::MySql | Self::Sqlite => query
+            Self::MySql | Self::Sqlite | Self::Any(_) => query
                 .fields
                 .iter()
                 .map(|f| {
@@ -458,6 +960,10 @@ impl Sqlx {
                         quote::quote! {
                             let #query_ident =  self.#name.iter().fold(#query_ident, |q, item| q.bind(item));
                         }
+                    } else if f.typ.via().is_some() || f.typ.generic_param().is_some() {
+                        quote::quote! {
+                            let #query_ident =  #query_ident.bind(&self.#name);
+                        }
                     } else {
                         quote::quote! {
                             let #query_ident =  #query_ident.bind(self.#name);
@@ -480,6 +986,12 @@ impl DbCrate for Sqlx {
             Sqlx::Postgres => Box::new(SimpleTypeMap::default()),
             Sqlx::MySql => Box::new(MySqlTypeMap::default()),
             Sqlx::Sqlite => Box::new(SqliteTypeMap::default()),
+            Sqlx::Any(crate::db_crates::DataBaseKind::Postgres) => Box::new(AnyTypeMap {
+                inner: Box::new(SimpleTypeMap::default()),
+            }),
+            Sqlx::Any(_) => Box::new(AnyTypeMap {
+                inner: Box::new(SqliteTypeMap::default()),
+            }),
         };
 
         for (owned_type, pg_types) in copy_cheap {
@@ -505,6 +1017,58 @@ impl DbCrate for Sqlx {
         map
     }
 
+    fn json_wrapper_type(&self) -> Option<syn::Path> {
+        match self {
+            Sqlx::Any(_) => None,
+            _ => Some(syn::parse_quote! {sqlx::types::Json}),
+        }
+    }
+
+    fn untyped_param(&self) -> Option<UntypedParam> {
+        let database_ident = self.database_ident();
+        Some(UntypedParam::Generic(quote::quote! {
+            for<'q> sqlx::Encode<'q, #database_ident> + sqlx::Type<#database_ident> + Sync
+        }))
+    }
+
+    /// `sqlx::Any` cannot bind or decode custom types
+    fn supports_enum(&self) -> bool {
+        !matches!(self, Sqlx::Any(_))
+    }
+
+    fn nd_array_type(&self) -> Option<syn::Path> {
+        match self {
+            Sqlx::Postgres => {
+                let ident = PgNdArray::ident();
+                Some(syn::parse_quote! {#ident})
+            }
+            Sqlx::MySql | Sqlx::Sqlite | Sqlx::Any(_) => None,
+        }
+    }
+
+    fn nd_array_elements(&self) -> Option<Vec<syn::Type>> {
+        Some(
+            PgNdArray::element_decoders()
+                .into_iter()
+                .map(|(typ, _)| typ)
+                .collect(),
+        )
+    }
+
+    fn nd_array_definition(&self, elements: &[syn::Type]) -> proc_macro2::TokenStream {
+        if !matches!(self, Sqlx::Postgres) {
+            return proc_macro2::TokenStream::new();
+        }
+        let struct_tt = PgNdArray::struct_tokens();
+        let impl_fn = PgNdArray::impl_fn();
+        let element_impls = PgNdArray::element_impls(elements);
+        quote::quote! {
+            #struct_tt
+            #impl_fn
+            #element_impls
+        }
+    }
+
     fn init(&self) -> proc_macro2::TokenStream {
         match self {
             Sqlx::Postgres => {
@@ -520,7 +1084,121 @@ impl DbCrate for Sqlx {
                     #copy_data_sync
                 }
             }
-            _ => quote::quote! {},
+            Sqlx::Sqlite => {
+                let numeric_tt = super::sqlite_numeric_tokens();
+                quote::quote! {
+                    #numeric_tt
+
+                    impl sqlx::Type<sqlx::Sqlite> for SqliteNumeric {
+                        fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+                            <f64 as sqlx::Type<sqlx::Sqlite>>::type_info()
+                        }
+
+                        fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+                            <i64 as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+                                || <f64 as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+                                || <String as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+                        }
+                    }
+
+                    impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for SqliteNumeric {
+                        fn encode_by_ref(
+                            &self,
+                            buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+                        ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                            match self {
+                                Self::Integer(v) => <i64 as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+                                Self::Real(v) => <f64 as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+                                Self::Text(v) => <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode_by_ref(v, buf),
+                            }
+                        }
+                    }
+
+                    impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for SqliteNumeric {
+                        fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+                            use sqlx::{TypeInfo as _, ValueRef as _};
+
+                            let storage_class = value.type_info().name().to_owned();
+                            match storage_class.as_str() {
+                                "INTEGER" => Ok(Self::Integer(<i64 as sqlx::Decode<sqlx::Sqlite>>::decode(value)?)),
+                                "REAL" => Ok(Self::Real(<f64 as sqlx::Decode<sqlx::Sqlite>>::decode(value)?)),
+                                "TEXT" => Ok(Self::Text(<String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?)),
+                                _ => Err(format!("cannot decode {storage_class} as NUMERIC").into()),
+                            }
+                        }
+                    }
+                }
+            }
+            Sqlx::MySql => quote::quote! {
+                /// Members of a MySQL `SET` column.
+                #[derive(Debug, Clone, Default, PartialEq, Eq)]
+                pub struct MySqlSet(pub std::collections::HashSet<String>);
+
+                impl sqlx::Type<sqlx::MySql> for MySqlSet {
+                    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+                        <str as sqlx::Type<sqlx::MySql>>::type_info()
+                    }
+
+                    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+                        use sqlx::TypeInfo as _;
+                        ty.name() == "SET" || <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
+                    }
+                }
+
+                impl<'q> sqlx::Encode<'q, sqlx::MySql> for MySqlSet {
+                    fn encode_by_ref(
+                        &self,
+                        buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+                    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                        let members = self.0.iter().map(String::as_str).collect::<Vec<_>>().join(",");
+                        <String as sqlx::Encode<'q, sqlx::MySql>>::encode(members, buf)
+                    }
+                }
+
+                impl<'r> sqlx::Decode<'r, sqlx::MySql> for MySqlSet {
+                    fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+                        let members = <&str as sqlx::Decode<sqlx::MySql>>::decode(value)?;
+                        Ok(Self(
+                            members
+                                .split(',')
+                                .filter(|member| !member.is_empty())
+                                .map(str::to_owned)
+                                .collect(),
+                        ))
+                    }
+                }
+
+                /// Geometry value in MySQL's internal format: a 4-byte SRID followed by WKB.
+                #[derive(Debug, Clone, Default, PartialEq, Eq)]
+                pub struct MySqlGeometry(pub Vec<u8>);
+
+                impl sqlx::Type<sqlx::MySql> for MySqlGeometry {
+                    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+                        <[u8] as sqlx::Type<sqlx::MySql>>::type_info()
+                    }
+
+                    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+                        use sqlx::TypeInfo as _;
+                        ty.name() == "GEOMETRY" || <[u8] as sqlx::Type<sqlx::MySql>>::compatible(ty)
+                    }
+                }
+
+                impl<'q> sqlx::Encode<'q, sqlx::MySql> for MySqlGeometry {
+                    fn encode_by_ref(
+                        &self,
+                        buf: &mut <sqlx::MySql as sqlx::Database>::ArgumentBuffer<'q>,
+                    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                        <&[u8] as sqlx::Encode<'q, sqlx::MySql>>::encode(self.0.as_slice(), buf)
+                    }
+                }
+
+                impl<'r> sqlx::Decode<'r, sqlx::MySql> for MySqlGeometry {
+                    fn decode(value: sqlx::mysql::MySqlValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+                        Ok(Self(<&[u8] as sqlx::Decode<sqlx::MySql>>::decode(value)?.to_vec()))
+                    }
+                }
+            },
+            Sqlx::Any(_) => proc_macro2::TokenStream::new(),
         }
     }
 
@@ -536,6 +1214,26 @@ impl DbCrate for Sqlx {
 
         let original_name = &enum_type.name;
         let enum_name = enum_type.ident();
+        if matches!(self, Sqlx::MySql) {
+            // MySQL reports ENUM columns as strings with extra column flags, which the derived
+            // `sqlx::Type` rejects, so accept anything a `str` accepts instead.
+            return quote::quote! {
+                #[derive(Debug,Clone,Copy, sqlx::Encode, sqlx::Decode, #(#derives),*)]
+                pub enum #enum_name {
+                    #(#fields,)*
+                }
+
+                impl sqlx::Type<sqlx::MySql> for #enum_name {
+                    fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+                        <str as sqlx::Type<sqlx::MySql>>::type_info()
+                    }
+
+                    fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+                        <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
+                    }
+                }
+            };
+        }
         let derive_tt = if derives.is_empty() {
             quote::quote! {#[derive(Debug,Clone,Copy, sqlx::Type)]}
         } else {
@@ -550,9 +1248,45 @@ impl DbCrate for Sqlx {
         }
     }
 
+    fn defined_newtype(&self, newtype: &DbNewtype) -> proc_macro2::TokenStream {
+        let ident = newtype.ident();
+        let inner = newtype.inner.owned();
+        let derives = super::newtype_derives(newtype);
+        quote::quote! {
+            #[derive(#derives, sqlx::Type)]
+            #[sqlx(transparent)]
+            pub struct #ident(pub #inner);
+        }
+    }
+
+    fn defined_composite(&self, composite: &DbComposite) -> proc_macro2::TokenStream {
+        if !matches!(self, Sqlx::Postgres) {
+            return quote::quote! {
+                compile_error!("composite types are only supported by postgres")
+            };
+        }
+
+        let fields = composite.fields.iter().map(|field| {
+            let name = &field.name;
+            let typ = field.typ.to_row_tokens();
+            quote::quote! {
+                pub #name: #typ
+            }
+        });
+
+        let type_name = composite.type_name();
+        let ident = composite.ident();
+        quote::quote! {
+            #[derive(Debug, Clone, sqlx::Type)]
+            #[sqlx(type_name = #type_name)]
+            pub struct #ident {
+                #(#fields,)*
+            }
+        }
+    }
+
     fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
         let query_ast = super::QueryAst::new(query, (*self).into());
-        let struct_ident = &query_ast.ident;
         let lifetime_a = &query_ast.lifetime;
         let need_lifetime = query_ast.need_lifetime();
 
@@ -711,11 +1445,7 @@ impl DbCrate for Sqlx {
             }
         };
         let fetch_tt = {
-            let imp_ident = if need_lifetime {
-                quote::quote! {<#lifetime_a> #struct_ident<#lifetime_a>}
-            } else {
-                quote::quote! {#struct_ident}
-            };
+            let imp_ident = query_ast.impl_ident();
             quote::quote! {
                 impl #imp_ident {
                     #query_fns
@@ -733,3 +1463,114 @@ impl DbCrate for Sqlx {
         }
     }
 }
+
+impl DbCrateConfig for Sqlx {
+    fn set_engine(&mut self, engine: &str) -> Result<(), String> {
+        if let Sqlx::Any(kind) = self {
+            *kind = match engine {
+                "postgresql" => crate::db_crates::DataBaseKind::Postgres,
+                "sqlite" => crate::db_crates::DataBaseKind::Sqlite,
+                _ => return Err(format!("`{engine}` engine is not supported by sqlx-any.")),
+            };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        db_crates::{DbCrateExt as _, snapshot},
+        plugin,
+        query::RsColType,
+    };
+
+    fn any_crate(engine: &str) -> Sqlx {
+        let mut db_crate = Sqlx::Any(crate::db_crates::DataBaseKind::Postgres);
+        db_crate.set_engine(engine).unwrap();
+        db_crate
+    }
+
+    #[test]
+    fn test_sqlx_any_snapshot() {
+        let db_crate = any_crate("sqlite");
+        let queries_tt = snapshot::generate_queries(&db_crate, &snapshot::author_queries());
+        snapshot::assert_snapshot("sqlx_any", queries_tt);
+    }
+
+    #[test]
+    fn test_sqlx_any_type_map() {
+        assert!(
+            Sqlx::Any(crate::db_crates::DataBaseKind::Postgres)
+                .set_engine("mysql")
+                .is_err()
+        );
+
+        let db_type = any_crate("postgresql").db_type_map();
+        assert!(
+            db_type
+                .get_column_type(&snapshot::column("id", "pg_catalog.int8", true))
+                .is_ok()
+        );
+        for column in [
+            snapshot::column("created_at", "pg_catalog.timestamp", true),
+            snapshot::column("id", "uuid", true),
+            plugin::Column {
+                array_dims: 1,
+                ..snapshot::column("ids", "pg_catalog.int8", true)
+            },
+        ] {
+            let result = db_type.get_column_type(&column);
+            assert!(result.is_err_and(|e| e.is_cannot_map_type()));
+        }
+
+        let db_type = any_crate("sqlite").db_type_map();
+        for db_type_name in ["numeric", "datetime"] {
+            let column = snapshot::column("value", db_type_name, true);
+            assert!(db_type.get_column_type(&column).is_err());
+        }
+    }
+
+    #[test]
+    fn test_sqlx_nd_array_elements() {
+        let db_crate = Sqlx::Postgres;
+        let db_type = db_crate.db_type_map();
+        let matrix = |db_type_name: &str| plugin::Column {
+            array_dims: 2,
+            ..snapshot::column("matrix", db_type_name, true)
+        };
+
+        let typ = RsColType::new_with_type(&db_type, &matrix("uuid")).unwrap();
+        let element = typ.nd_array_element().unwrap();
+        assert_eq!(element.to_token_stream().to_string(), "uuid :: Uuid");
+
+        let result = RsColType::new_with_type(&db_type, &matrix("interval"));
+        assert!(result.is_err_and(|e| e.is_cannot_map_type()));
+
+        let definition = db_crate.nd_array_definition(&[element]).to_string();
+        assert!(definition.contains("impl PgNdArrayElement for uuid :: Uuid"));
+        assert!(!definition.contains("impl PgNdArrayElement for i64"));
+        assert!(!db_crate.init().to_string().contains("PgNdArray"));
+    }
+
+    #[test]
+    fn test_sqlx_row_param_type_snapshot() {
+        let db_crate = Sqlx::Sqlite;
+        let mut db_type = db_crate.db_type_map();
+        let text_type = RsType::new(syn::parse_quote! {String}, None, false)
+            .with_row_type(syn::parse_quote! {Box<str>})
+            .with_param_type(syn::parse_quote! {str});
+        db_type.insert_db_type("varchar", text_type.clone());
+        db_type.insert_db_type("text", text_type);
+        let queries_tt =
+            snapshot::generate_queries_with(&db_crate, &db_type, &snapshot::author_queries());
+        snapshot::assert_snapshot("sqlx_row_param_type", queries_tt);
+    }
+
+    #[test]
+    fn test_sqlx_untyped_snapshot() {
+        let queries_tt = snapshot::generate_queries(&Sqlx::Postgres, &snapshot::untyped_queries());
+        snapshot::assert_snapshot("sqlx_untyped", queries_tt);
+    }
+}
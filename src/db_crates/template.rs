@@ -0,0 +1,436 @@
+use quote::ToTokens as _;
+
+use super::{DataBaseKind, DbCrate, DbCrateConfig, Templates};
+use crate::{
+    field_ident,
+    query::{DbComposite, DbEnum, DbNewtype, Query, ReturningRows, TypeMapper},
+    value_ident,
+};
+
+/// Generate code by rendering the user's `template` with minijinja
+#[derive(Debug, Clone)]
+pub(crate) struct Template {
+    /// database of the sqlc `engine`, which decides the type map
+    kind: DataBaseKind,
+    env: minijinja::Environment<'static>,
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Self {
+            kind: DataBaseKind::Postgres,
+            env: minijinja::Environment::new(),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Template {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.trim() {
+            "template" => Ok(Self::default()),
+            _ => Err(serde::de::Error::custom(format!(
+                "`{s}` is unsupported crate."
+            ))),
+        }
+    }
+}
+
+/// Column or parameter as seen by templates
+#[derive(serde::Serialize)]
+struct FieldModel {
+    /// Rust field name, e.g. `author_id`
+    name: String,
+    /// name of the column or parameter in the query
+    column: String,
+    /// owned Rust type, e.g. `Option<String>`
+    rust_type: String,
+    /// Rust type taken by the parameter struct, e.g. `Option<&'a str>`
+    param_type: String,
+    nullable: bool,
+    array: bool,
+}
+
+#[derive(serde::Serialize)]
+struct QueryModel {
+    /// name after `-- name:`
+    name: String,
+    /// e.g. `:one`
+    annotation: String,
+    /// SQL to run, the `COPY` for `:copyfrom` of the postgresql engine and the `INSERT` of others
+    sql: String,
+    /// SQL as written in the query file, the `INSERT` for `:copyfrom`
+    text: String,
+    struct_name: String,
+    row_name: String,
+    fn_name: String,
+    /// some `param_type` borrows with `'a`
+    lifetime: bool,
+    params: Vec<FieldModel>,
+    columns: Vec<FieldModel>,
+}
+
+#[derive(serde::Serialize)]
+struct EnumValueModel {
+    /// value in the database
+    name: String,
+    /// Rust variant name
+    ident: String,
+}
+
+#[derive(serde::Serialize)]
+struct EnumModel {
+    /// name in the database
+    name: String,
+    /// Rust type name
+    ident: String,
+    values: Vec<EnumValueModel>,
+    /// `enum_derives`
+    derives: Vec<String>,
+}
+
+/// `tokens` of a type as formatted in the generated code, e.g. `Option<&'a str>`
+fn type_string(tokens: proc_macro2::TokenStream) -> String {
+    let file: syn::File = syn::parse_quote! {type T = #tokens;};
+    let formatted = prettyplease::unparse(&file);
+    formatted
+        .trim()
+        .trim_start_matches("type T = ")
+        .trim_end_matches(';')
+        .to_string()
+}
+
+fn field_models(fields: &[crate::query::ColumnField]) -> Vec<FieldModel> {
+    let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+    fields
+        .iter()
+        .map(|field| FieldModel {
+            name: field.name.to_string(),
+            column: field.name_original.value(),
+            rust_type: type_string(field.typ.to_row_tokens()),
+            param_type: type_string(field.typ.to_param_tokens(&lifetime)),
+            nullable: field.typ.is_optional(),
+            array: field.typ.is_array(),
+        })
+        .collect()
+}
+
+fn compile_error(message: String) -> proc_macro2::TokenStream {
+    quote::quote! {compile_error!(#message);}
+}
+
+impl Template {
+    /// Render the template `name` and parse it as Rust items
+    fn render(
+        &self,
+        name: &str,
+        ctx: minijinja::Value,
+    ) -> Result<proc_macro2::TokenStream, String> {
+        let template = self.env.get_template(name).map_err(|e| e.to_string())?;
+        let rendered = template.render(ctx).map_err(|e| e.to_string())?;
+        let file = syn::parse_str::<syn::File>(&rendered)
+            .map_err(|e| format!("rendered code is not valid Rust, {e}"))?;
+        Ok(file.into_token_stream())
+    }
+
+    fn has_template(&self, name: &str) -> bool {
+        self.env.get_template(name).is_ok()
+    }
+}
+
+impl DbCrate for Template {
+    fn type_map(&self) -> Box<dyn TypeMapper> {
+        match self.kind {
+            DataBaseKind::Postgres => super::postgres::Postgres::default().type_map(),
+            DataBaseKind::MySql => super::mysql::Mysql::default().type_map(),
+            DataBaseKind::Sqlite => super::rusqlite::Rusqlite::default().type_map(),
+        }
+    }
+
+    fn init(&self) -> proc_macro2::TokenStream {
+        // NUMERIC columns of the rusqlite type map are `SqliteNumeric`
+        let numeric_tt =
+            matches!(self.kind, DataBaseKind::Sqlite).then(super::sqlite_numeric_tokens);
+        if !self.has_template("init") {
+            return numeric_tt.unwrap_or_default();
+        }
+        let init_tt = self
+            .render("init", minijinja::context! {})
+            .unwrap_or_else(|e| compile_error(format!("`init` template: {e}")));
+        quote::quote! {
+            #numeric_tt
+            #init_tt
+        }
+    }
+
+    fn defined_enum(&self, enum_type: &DbEnum) -> proc_macro2::TokenStream {
+        let enum_name = enum_type.ident();
+        if !self.has_template("enum") {
+            let derives = &enum_type.derives;
+            let variants = enum_type.values.iter().map(|v| value_ident(v));
+            return quote::quote! {
+                #[derive(Debug, Clone, Copy, #(#derives),*)]
+                pub enum #enum_name {
+                    #(#variants,)*
+                }
+            };
+        }
+
+        let model = EnumModel {
+            name: enum_type.name.clone(),
+            ident: enum_name.to_string(),
+            values: enum_type
+                .values
+                .iter()
+                .map(|v| EnumValueModel {
+                    name: v.clone(),
+                    ident: value_ident(v).to_string(),
+                })
+                .collect(),
+            derives: enum_type
+                .derives
+                .iter()
+                .map(|d| d.to_token_stream().to_string().replace(' ', ""))
+                .collect(),
+        };
+        self.render("enum", minijinja::context! {enum => model})
+            .unwrap_or_else(|e| compile_error(format!("`enum` template of `{enum_name}`: {e}")))
+    }
+
+    fn defined_newtype(&self, _newtype: &DbNewtype) -> proc_macro2::TokenStream {
+        compile_error("newtypes are not supported by template".to_string())
+    }
+
+    fn defined_composite(&self, _composite: &DbComposite) -> proc_macro2::TokenStream {
+        compile_error("composite types are not supported by template".to_string())
+    }
+
+    fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
+        let model = QueryModel {
+            name: query.query_name.clone(),
+            annotation: query.annotation.to_string(),
+            // `COPY ... FROM STDIN` is Postgres only
+            sql: match self.kind {
+                DataBaseKind::Postgres => query.sql(),
+                DataBaseKind::MySql | DataBaseKind::Sqlite => query.text().to_string(),
+            },
+            text: query.text().to_string(),
+            struct_name: value_ident(&query.query_name).to_string(),
+            row_name: row.struct_ident().to_string(),
+            fn_name: field_ident(&query.query_name).to_string(),
+            lifetime: query.fields.iter().any(|f| f.typ.need_lifetime()),
+            params: field_models(&query.fields),
+            columns: field_models(&row.fields),
+        };
+        self.render("query", minijinja::context! {query => model})
+            .unwrap_or_else(|e| {
+                let name = &query.query_name;
+                compile_error(format!("`query` template of `{name}`: {e}"))
+            })
+    }
+}
+
+impl DbCrateConfig for Template {
+    fn set_engine(&mut self, engine: &str) -> Result<(), String> {
+        self.kind = match engine {
+            "postgresql" => DataBaseKind::Postgres,
+            "mysql" => DataBaseKind::MySql,
+            "sqlite" => DataBaseKind::Sqlite,
+            _ => return Err(format!("`{engine}` engine is not supported by template.")),
+        };
+        Ok(())
+    }
+
+    fn set_templates(&mut self, templates: Option<&Templates>) -> Result<(), String> {
+        let Some(templates) = templates else {
+            return Err("db_crate: template requires the `template` option.".into());
+        };
+        let mut env = minijinja::Environment::new();
+        env.set_undefined_behavior(minijinja::UndefinedBehavior::Strict);
+        // `{{ query.sql | rust_str }}` quotes and escapes a Rust string literal
+        env.add_filter("rust_str", |value: String| {
+            proc_macro2::Literal::string(&value).to_string()
+        });
+        let sources = [
+            ("init", templates.init.as_ref()),
+            ("query", Some(&templates.query)),
+            ("enum", templates.enum_type.as_ref()),
+        ];
+        for (name, source) in sources {
+            if let Some(source) = source {
+                env.add_template_owned(name, source.clone())
+                    .map_err(|e| format!("`{name}` template is invalid, {e}"))?;
+            }
+        }
+        self.env = env;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{db_crates::snapshot, plugin};
+
+    const QUERY: &str = r#"
+{% if query.columns %}
+pub struct {{ query.row_name }} {
+{% for column in query.columns %}
+    pub {{ column.name }}: {{ column.rust_type }},
+{% endfor %}
+}
+{% endif %}
+{% set generics = "<'a>" if query.lifetime else "" %}
+pub struct {{ query.struct_name }}{{ generics }} {
+{% for param in query.params %}
+    pub {{ param.name }}: {{ param.param_type }},
+{% endfor %}
+}
+impl{{ generics }} {{ query.struct_name }}{{ generics }} {
+    pub const QUERY: &'static str = {{ query.sql | rust_str }};
+{% if query.annotation == ":one" %}
+    pub fn query_one(&self, client: &Client) -> my_db::Result<{{ query.row_name }}> {
+        client.query_one(Self::QUERY, &[{% for param in query.params %}&self.{{ param.name }},{% endfor %}])
+    }
+{% elif query.annotation == ":many" %}
+    pub fn query_many(&self, client: &Client) -> my_db::Result<Vec<{{ query.row_name }}>> {
+        client.query(Self::QUERY, &[{% for param in query.params %}&self.{{ param.name }},{% endfor %}])
+    }
+{% else %}
+    pub fn execute(&self, client: &Client) -> my_db::Result<u64> {
+        client.execute(Self::QUERY, &[{% for param in query.params %}&self.{{ param.name }},{% endfor %}])
+    }
+{% endif %}
+}
+"#;
+
+    fn templates() -> Templates {
+        Templates {
+            init: Some("use my_db::Client;".to_string()),
+            query: QUERY.to_string(),
+            enum_type: Some(
+                "pub enum {{ enum.ident }} { {% for v in enum.values %}{{ v.ident }},{% endfor %} }"
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn book_type() -> DbEnum {
+        DbEnum {
+            name: "book_type".to_string(),
+            values: vec!["FICTION".to_string(), "NONFICTION".to_string()],
+            derives: vec![],
+        }
+    }
+
+    #[test]
+    fn test_template_snapshot() {
+        let mut db_crate = Template::default();
+        db_crate.set_engine("sqlite").unwrap();
+        db_crate.set_templates(Some(&templates())).unwrap();
+        let init_tt = db_crate.init();
+        let enum_tt = db_crate.defined_enum(&book_type());
+        let queries_tt = snapshot::generate_queries(&db_crate, &snapshot::author_queries());
+        snapshot::assert_snapshot(
+            "template",
+            quote::quote! {
+                #init_tt
+                #enum_tt
+                #queries_tt
+            },
+        );
+    }
+
+    #[test]
+    fn test_template_copy_from_snapshot() {
+        let templates = Templates {
+            init: None,
+            query: r#"pub struct {{ query.struct_name }} { pub amount: {{ query.params[0].rust_type }} }
+impl {{ query.struct_name }} {
+    pub const SQL: &'static str = {{ query.sql | rust_str }};
+    pub const TEXT: &'static str = {{ query.text | rust_str }};
+}"#
+            .to_string(),
+            enum_type: None,
+        };
+        let copy_from = |insert: &str, db_type: &str| plugin::Query {
+            insert_into_table: Some(plugin::Identifier {
+                name: "authors".to_string(),
+                ..Default::default()
+            }),
+            ..snapshot::query(
+                "CopyAuthors",
+                ":copyfrom",
+                insert,
+                vec![],
+                vec![snapshot::column("amount", db_type, true)],
+            )
+        };
+
+        let mut db_crate = Template::default();
+        db_crate.set_engine("sqlite").unwrap();
+        db_crate.set_templates(Some(&templates)).unwrap();
+        let init_tt = db_crate.init();
+        let sqlite_tt = snapshot::generate_queries(
+            &db_crate,
+            &[copy_from(
+                "INSERT INTO authors (amount) VALUES (?)",
+                "numeric",
+            )],
+        );
+        db_crate.set_engine("postgresql").unwrap();
+        let postgres_tt = snapshot::generate_queries(
+            &db_crate,
+            &[copy_from(
+                "INSERT INTO authors (amount) VALUES ($1)",
+                "pg_catalog.int8",
+            )],
+        );
+        snapshot::assert_snapshot(
+            "template_copy_from",
+            quote::quote! {
+                #init_tt
+                mod sqlite {
+                    #sqlite_tt
+                }
+                mod postgres {
+                    #postgres_tt
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_template_errors_snapshot() {
+        let mut db_crate = Template::default();
+        assert!(db_crate.set_templates(None).is_err());
+        assert!(db_crate.set_engine("duckdb").is_err());
+        db_crate.set_engine("sqlite").unwrap();
+
+        let invalid = Templates {
+            query: "{% for %}".to_string(),
+            ..templates()
+        };
+        assert!(db_crate.set_templates(Some(&invalid)).is_err());
+
+        // without `enum`, enums are plain Rust enums
+        let undefined = Templates {
+            query: "pub struct {{ query.unknown }};".to_string(),
+            enum_type: None,
+            ..templates()
+        };
+        db_crate.set_templates(Some(&undefined)).unwrap();
+        let enum_tt = db_crate.defined_enum(&book_type());
+        let queries_tt = snapshot::generate_queries(&db_crate, &snapshot::author_queries());
+        snapshot::assert_snapshot(
+            "template_errors",
+            quote::quote! {
+                #enum_tt
+                #queries_tt
+            },
+        );
+    }
+}
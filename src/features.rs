@@ -0,0 +1,816 @@
+//! Merge the code generated for several `db_crate`s into one file gated by cargo features
+use quote::ToTokens as _;
+use std::collections::{HashMap, HashSet};
+
+/// Same item as generated by one or more `db_crate`s
+struct Variant {
+    item: syn::Item,
+    tokens: String,
+    features: Vec<usize>,
+}
+
+/// Items sharing a name, or identical unnamed items such as `impl` blocks
+struct Group {
+    name: Option<String>,
+    variants: Vec<Variant>,
+}
+
+impl Group {
+    fn matches(&self, name: Option<&str>, tokens: &str) -> bool {
+        match (name, &self.name) {
+            (Some(name), Some(group_name)) => name == group_name,
+            (None, None) => self.variants.iter().any(|v| v.tokens == tokens),
+            _ => false,
+        }
+    }
+}
+
+/// Kind and identifier of items which cannot be defined twice in a module
+fn item_kind_ident(item: &syn::Item) -> Option<(&'static str, &syn::Ident)> {
+    let kind_ident = match item {
+        syn::Item::Const(i) => ("const", &i.ident),
+        syn::Item::Enum(i) => ("type", &i.ident),
+        syn::Item::Fn(i) => ("fn", &i.sig.ident),
+        syn::Item::Mod(i) => ("mod", &i.ident),
+        syn::Item::Static(i) => ("static", &i.ident),
+        syn::Item::Struct(i) => ("type", &i.ident),
+        syn::Item::Trait(i) => ("type", &i.ident),
+        syn::Item::Type(i) => ("type", &i.ident),
+        syn::Item::Union(i) => ("type", &i.ident),
+        syn::Item::Macro(syn::ItemMacro {
+            ident: Some(ident), ..
+        }) => ("macro", ident),
+        _ => return None,
+    };
+    Some(kind_ident)
+}
+
+/// Name of items which cannot be defined twice in a module
+fn item_name(item: &syn::Item) -> Option<String> {
+    item_kind_ident(item).map(|(kind, ident)| format!("{kind} {ident}"))
+}
+
+/// Names an item defines in its module, including the names a `use` imports
+fn defined_names(item: &syn::Item) -> Vec<String> {
+    fn use_names(tree: &syn::UseTree, names: &mut Vec<String>) {
+        match tree {
+            syn::UseTree::Path(path) => use_names(&path.tree, names),
+            syn::UseTree::Name(name) => names.push(name.ident.to_string()),
+            syn::UseTree::Rename(rename) if rename.rename != "_" => {
+                names.push(rename.rename.to_string())
+            }
+            syn::UseTree::Group(group) => group.items.iter().for_each(|t| use_names(t, names)),
+            _ => {}
+        }
+    }
+    match item {
+        syn::Item::Use(item) => {
+            let mut names = Vec::new();
+            use_names(&item.tree, &mut names);
+            names
+        }
+        item => item_kind_ident(item)
+            .map(|(_, ident)| ident.to_string())
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Identifiers starting a path in `tokens`, i.e. those not following `::` or `.`
+fn leading_idents(tokens: proc_macro2::TokenStream, idents: &mut HashSet<String>) {
+    let mut prev = [None, None];
+    for tt in tokens {
+        match &tt {
+            proc_macro2::TokenTree::Group(group) => leading_idents(group.stream(), idents),
+            proc_macro2::TokenTree::Ident(ident)
+                if prev != [Some(':'), Some(':')] && prev[1] != Some('.') =>
+            {
+                idents.insert(ident.to_string());
+            }
+            _ => {}
+        }
+        let punct = match &tt {
+            proc_macro2::TokenTree::Punct(punct) => Some(punct.as_char()),
+            _ => None,
+        };
+        prev = [prev[1], punct];
+    }
+}
+
+/// Identifier of the type an `impl` block is for
+fn self_ident(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn item_attrs_mut(item: &mut syn::Item) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        syn::Item::Const(i) => Some(&mut i.attrs),
+        syn::Item::Enum(i) => Some(&mut i.attrs),
+        syn::Item::ExternCrate(i) => Some(&mut i.attrs),
+        syn::Item::Fn(i) => Some(&mut i.attrs),
+        syn::Item::ForeignMod(i) => Some(&mut i.attrs),
+        syn::Item::Impl(i) => Some(&mut i.attrs),
+        syn::Item::Macro(i) => Some(&mut i.attrs),
+        syn::Item::Mod(i) => Some(&mut i.attrs),
+        syn::Item::Static(i) => Some(&mut i.attrs),
+        syn::Item::Struct(i) => Some(&mut i.attrs),
+        syn::Item::Trait(i) => Some(&mut i.attrs),
+        syn::Item::TraitAlias(i) => Some(&mut i.attrs),
+        syn::Item::Type(i) => Some(&mut i.attrs),
+        syn::Item::Union(i) => Some(&mut i.attrs),
+        syn::Item::Use(i) => Some(&mut i.attrs),
+        _ => None,
+    }
+}
+
+/// Attributes of a struct or an enum, its fields and its variants. `None` for other items
+fn attr_slots(item: &mut syn::Item) -> Option<Vec<&mut Vec<syn::Attribute>>> {
+    match item {
+        syn::Item::Struct(s) => Some(
+            core::iter::once(&mut s.attrs)
+                .chain(s.fields.iter_mut().map(|f| &mut f.attrs))
+                .collect(),
+        ),
+        syn::Item::Enum(e) => {
+            let mut slots = vec![&mut e.attrs];
+            for variant in e.variants.iter_mut() {
+                slots.push(&mut variant.attrs);
+                slots.extend(variant.fields.iter_mut().map(|f| &mut f.attrs));
+            }
+            Some(slots)
+        }
+        _ => None,
+    }
+}
+
+/// Tokens of a struct or an enum without any attributes
+fn skeleton(item: &syn::Item) -> Option<String> {
+    let mut item = item.clone();
+    for attrs in attr_slots(&mut item)? {
+        attrs.clear();
+    }
+    Some(item.to_token_stream().to_string())
+}
+
+/// `feature = "a"` or `any(feature = "a", feature = "b")`
+fn feature_predicate(features: &[String], indices: &[usize]) -> proc_macro2::TokenStream {
+    let names = indices.iter().map(|&idx| features[idx].as_str());
+    if let [idx] = indices {
+        let name = &features[*idx];
+        quote::quote! {feature = #name}
+    } else {
+        quote::quote! {any(#(feature = #names),*)}
+    }
+}
+
+fn derive_paths(attr: &syn::Attribute) -> Option<Vec<syn::Path>> {
+    if !attr.path().is_ident("derive") {
+        return None;
+    }
+    let paths = attr
+        .parse_args_with(syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+        .ok()?;
+    Some(paths.into_iter().collect())
+}
+
+fn token_keys<T: quote::ToTokens>(items: &[T]) -> Vec<String> {
+    items
+        .iter()
+        .map(|i| i.to_token_stream().to_string())
+        .collect()
+}
+
+/// Keys of the first list which every list contains
+fn common_keys(keys: &[Vec<String>]) -> Vec<String> {
+    keys[0]
+        .iter()
+        .filter(|k| keys.iter().all(|ks| ks.contains(k)))
+        .cloned()
+        .collect()
+}
+
+/// Keep attributes every variant has, and gate the others by the features of their variant
+fn merge_attrs(
+    features: &[String],
+    variants: &[(&[usize], Vec<syn::Attribute>)],
+) -> Vec<syn::Attribute> {
+    let split = variants
+        .iter()
+        .map(|(indices, attrs)| {
+            let mut derives = Vec::new();
+            let mut others = Vec::new();
+            for attr in attrs {
+                match derive_paths(attr) {
+                    Some(paths) => derives.extend(paths),
+                    None => others.push(attr.clone()),
+                }
+            }
+            (*indices, derives, others)
+        })
+        .collect::<Vec<_>>();
+    let common_derives = common_keys(
+        &split
+            .iter()
+            .map(|(_, derives, _)| token_keys(derives))
+            .collect::<Vec<_>>(),
+    );
+    let common_others = common_keys(
+        &split
+            .iter()
+            .map(|(_, _, others)| token_keys(others))
+            .collect::<Vec<_>>(),
+    );
+
+    // derives come first so that helper attributes follow the derive introducing them
+    let mut attrs: Vec<syn::Attribute> = Vec::new();
+    let shared_derives = split[0]
+        .1
+        .iter()
+        .filter(|p| common_derives.contains(&p.to_token_stream().to_string()))
+        .collect::<Vec<_>>();
+    if !shared_derives.is_empty() {
+        attrs.push(syn::parse_quote! {#[derive(#(#shared_derives),*)]});
+    }
+    for (indices, derives, _) in &split {
+        let gated = derives
+            .iter()
+            .filter(|p| !common_derives.contains(&p.to_token_stream().to_string()))
+            .collect::<Vec<_>>();
+        if !gated.is_empty() {
+            let predicate = feature_predicate(features, indices);
+            attrs.push(syn::parse_quote! {#[cfg_attr(#predicate, derive(#(#gated),*))]});
+        }
+    }
+    attrs.extend(
+        split[0]
+            .2
+            .iter()
+            .filter(|a| common_others.contains(&a.to_token_stream().to_string()))
+            .cloned(),
+    );
+    for (indices, _, others) in &split {
+        let predicate = feature_predicate(features, indices);
+        for attr in others
+            .iter()
+            .filter(|a| !common_others.contains(&a.to_token_stream().to_string()))
+        {
+            let meta = &attr.meta;
+            attrs.push(syn::parse_quote! {#[cfg_attr(#predicate, #meta)]});
+        }
+    }
+    attrs
+}
+
+/// Merge variants whose struct or enum differs only in attributes. `None` if they differ otherwise
+fn merge_variants(features: &[String], variants: &[Variant]) -> Option<syn::Item> {
+    let first = skeleton(&variants[0].item)?;
+    if variants
+        .iter()
+        .any(|v| skeleton(&v.item).as_ref() != Some(&first))
+    {
+        return None;
+    }
+
+    let mut items = variants.iter().map(|v| v.item.clone()).collect::<Vec<_>>();
+    let mut slots = items
+        .iter_mut()
+        .map(|item| attr_slots(item).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let mut merged = variants[0].item.clone();
+    let merged_slots = attr_slots(&mut merged)?;
+    for (idx, merged_attrs) in merged_slots.into_iter().enumerate() {
+        let slot_variants = variants
+            .iter()
+            .zip(slots.iter_mut())
+            .map(|(v, slots)| (v.features.as_slice(), core::mem::take(slots[idx])))
+            .collect::<Vec<_>>();
+        *merged_attrs = merge_attrs(features, &slot_variants);
+    }
+    Some(merged)
+}
+
+fn gate(mut item: syn::Item, predicate: proc_macro2::TokenStream) -> syn::Item {
+    if let Some(attrs) = item_attrs_mut(&mut item) {
+        attrs.insert(0, syn::parse_quote! {#[cfg(#predicate)]});
+    }
+    item
+}
+
+/// Merged item and the features generating it
+struct Entry {
+    item: syn::Item,
+    features: Vec<usize>,
+    /// Emitted in the module of each of its features instead of the top level
+    in_modules: bool,
+}
+
+/// Move to the feature modules the items which would conflict with another feature's items:
+/// inherent impls of a type other features implement too, `use`s importing the same name,
+/// and the items referring to anything moved
+fn place_entries(entries: &mut [Entry], feature_count: usize) {
+    let type_features = entries
+        .iter()
+        .filter(|e| !e.in_modules)
+        .filter_map(|e| match item_kind_ident(&e.item)? {
+            ("type", ident) => Some((ident.to_string(), e.features.clone())),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    for idx in 0..entries.len() {
+        let entry = &entries[idx];
+        if entry.in_modules || entry.features.len() == feature_count {
+            continue;
+        }
+        let conflicts = match &entry.item {
+            syn::Item::Impl(item) if item.trait_.is_none() => self_ident(&item.self_ty)
+                .and_then(|ident| type_features.get(&ident))
+                .is_some_and(|features| features != &entry.features),
+            syn::Item::Use(_) => {
+                let names = defined_names(&entry.item);
+                entries.iter().enumerate().any(|(other_idx, other)| {
+                    other_idx != idx
+                        && matches!(other.item, syn::Item::Use(_))
+                        && defined_names(&other.item).iter().any(|n| names.contains(n))
+                })
+            }
+            _ => false,
+        };
+        entries[idx].in_modules = conflicts;
+    }
+
+    loop {
+        let moved = entries
+            .iter()
+            .filter(|e| e.in_modules)
+            .flat_map(|e| defined_names(&e.item))
+            .collect::<HashSet<_>>();
+        let mut changed = false;
+        for entry in entries.iter_mut().filter(|e| !e.in_modules) {
+            let mut idents = HashSet::new();
+            leading_idents(entry.item.to_token_stream(), &mut idents);
+            if idents.iter().any(|ident| moved.contains(ident)) {
+                entry.in_modules = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+/// Turn the inherent impls of types defined outside the module into extension traits,
+/// since inherent methods of the same name would conflict between features
+fn into_extension_traits(items: Vec<syn::Item>, feature: &str) -> Vec<syn::Item> {
+    let local = items.iter().flat_map(defined_names).collect::<HashSet<_>>();
+
+    // impls of the same type with the same generics share one trait
+    let mut impls: Vec<(String, syn::ItemImpl)> = Vec::new();
+    let mut slots = Vec::new();
+    for item in items {
+        match item {
+            syn::Item::Impl(item)
+                if item.trait_.is_none()
+                    && self_ident(&item.self_ty).is_some_and(|i| !local.contains(&i)) =>
+            {
+                let key = format!(
+                    "{} {} {}",
+                    item.generics.to_token_stream(),
+                    item.generics.where_clause.to_token_stream(),
+                    item.self_ty.to_token_stream()
+                );
+                match impls.iter_mut().find(|(k, _)| k == &key) {
+                    Some((_, first)) => first.items.extend(item.items),
+                    None => {
+                        slots.push(Err(impls.len()));
+                        impls.push((key, item));
+                    }
+                }
+            }
+            item => slots.push(Ok(item)),
+        }
+    }
+
+    let mut names = Vec::new();
+    let traits = impls
+        .into_iter()
+        .map(|(_, item)| {
+            let type_ident = self_ident(&item.self_ty).unwrap_or_default();
+            let mut name = format!("{type_ident}Ext");
+            let mut count = 1;
+            while names.contains(&name) {
+                count += 1;
+                name = format!("{type_ident}Ext{count}");
+            }
+            names.push(name.clone());
+            extension_trait(item, quote::format_ident!("{name}"), &type_ident, feature)
+        })
+        .collect::<Vec<_>>();
+    slots
+        .into_iter()
+        .flat_map(|slot| match slot {
+            Ok(item) => vec![item],
+            Err(idx) => traits[idx].to_vec(),
+        })
+        .collect()
+}
+
+/// Trait declaring the items of an inherent impl, and the impl of the trait
+fn extension_trait(
+    mut item: syn::ItemImpl,
+    name: syn::Ident,
+    type_ident: &str,
+    feature: &str,
+) -> [syn::Item; 2] {
+    let is_declared = |attr: &&syn::Attribute| {
+        ["doc", "cfg", "cfg_attr", "allow", "expect", "deprecated"]
+            .iter()
+            .any(|path| attr.path().is_ident(path))
+    };
+    let mut declarations: Vec<syn::TraitItem> = Vec::new();
+    let mut is_async = false;
+    for impl_item in item.items.iter_mut() {
+        match impl_item {
+            syn::ImplItem::Fn(f) => {
+                f.vis = syn::Visibility::Inherited;
+                f.sig.constness = None;
+                is_async |= f.sig.asyncness.is_some();
+                let attrs = f.attrs.iter().filter(is_declared);
+                let sig = &f.sig;
+                declarations.push(syn::parse_quote! {#(#attrs)* #sig;});
+            }
+            syn::ImplItem::Const(c) => {
+                c.vis = syn::Visibility::Inherited;
+                let attrs = c.attrs.iter().filter(is_declared);
+                let (ident, ty) = (&c.ident, &c.ty);
+                declarations.push(syn::parse_quote! {#(#attrs)* const #ident: #ty;});
+            }
+            syn::ImplItem::Type(t) => {
+                t.vis = syn::Visibility::Inherited;
+                let attrs = t.attrs.iter().filter(is_declared);
+                let (ident, generics) = (&t.ident, &t.generics);
+                declarations.push(syn::parse_quote! {#(#attrs)* type #ident #generics;});
+            }
+            _ => {}
+        }
+    }
+
+    let doc = format!(" Methods of [`{type_ident}`] for the feature `{feature}`");
+    let lint = is_async.then(|| quote::quote! {#[allow(async_fn_in_trait)]});
+    let generics = &item.generics;
+    let where_clause = &generics.where_clause;
+    let definition = syn::parse_quote! {
+        #[doc = #doc]
+        #lint
+        pub trait #name #generics: Sized #where_clause {
+            #(#declarations)*
+        }
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (attrs, self_ty, items) = (&item.attrs, &item.self_ty, &item.items);
+    let implementation = syn::parse_quote! {
+        #(#attrs)*
+        impl #impl_generics #name #ty_generics for #self_ty #where_clause {
+            #(#items)*
+        }
+    };
+    [definition, implementation]
+}
+
+/// Merge the files generated for each feature. Items shared by every feature are emitted once,
+/// structs and enums differing only in attributes are merged with `cfg_attr`,
+/// and other items are gated by `cfg` of the features generating them.
+/// Items which would conflict when several features are enabled go to a module per feature,
+/// where inherent impls of the top level types become extension traits.
+pub(crate) fn merge(generated: Vec<(String, syn::File)>) -> syn::File {
+    let features = generated
+        .iter()
+        .map(|(feature, _)| feature.clone())
+        .collect::<Vec<_>>();
+
+    let mut groups: Vec<Group> = Vec::new();
+    for (feature_idx, (_, file)) in generated.into_iter().enumerate() {
+        let items = file
+            .items
+            .into_iter()
+            .map(|item| {
+                let name = item_name(&item);
+                let tokens = item.to_token_stream().to_string();
+                (item, name, tokens)
+            })
+            .collect::<Vec<_>>();
+        let matches_any = |group: &Group| {
+            items
+                .iter()
+                .any(|(_, name, tokens)| group.matches(name.as_deref(), tokens))
+        };
+
+        // items new to this feature follow the last item this feature shares before them
+        let mut cursor = 0;
+        for (item, name, tokens) in &items {
+            let group_idx = match groups
+                .iter()
+                .position(|g| g.matches(name.as_deref(), tokens))
+            {
+                Some(idx) => idx,
+                None => {
+                    // and the items only earlier features generate there
+                    while groups.get(cursor).is_some_and(|g| !matches_any(g)) {
+                        cursor += 1;
+                    }
+                    groups.insert(
+                        cursor,
+                        Group {
+                            name: name.clone(),
+                            variants: Vec::new(),
+                        },
+                    );
+                    cursor
+                }
+            };
+            let group = &mut groups[group_idx];
+            match group.variants.iter_mut().find(|v| &v.tokens == tokens) {
+                Some(variant) => variant.features.push(feature_idx),
+                None => group.variants.push(Variant {
+                    item: item.clone(),
+                    tokens: tokens.clone(),
+                    features: vec![feature_idx],
+                }),
+            }
+            cursor = group_idx + 1;
+        }
+    }
+
+    let mut entries: Vec<Entry> = Vec::new();
+    for group in groups {
+        let mut covered = group
+            .variants
+            .iter()
+            .flat_map(|v| v.features.iter().copied())
+            .collect::<Vec<_>>();
+        covered.sort_unstable();
+
+        let merged = match group.variants.as_slice() {
+            [variant] => Some(variant.item.clone()),
+            variants => merge_variants(&features, variants),
+        };
+        match merged {
+            Some(item) => entries.push(Entry {
+                item,
+                features: covered,
+                in_modules: false,
+            }),
+            // variants of the same name cannot share a module
+            None => entries.extend(group.variants.into_iter().map(|v| Entry {
+                item: v.item,
+                features: v.features,
+                in_modules: true,
+            })),
+        }
+    }
+    place_entries(&mut entries, features.len());
+
+    let mut items = Vec::new();
+    for entry in entries.iter().filter(|e| !e.in_modules) {
+        if entry.features.len() == features.len() {
+            items.push(entry.item.clone());
+        } else {
+            let predicate = feature_predicate(&features, &entry.features);
+            items.push(gate(entry.item.clone(), predicate));
+        }
+    }
+    for (feature_idx, feature) in features.iter().enumerate() {
+        let module_items = entries
+            .iter()
+            .filter(|e| e.in_modules && e.features.contains(&feature_idx))
+            .map(|e| e.item.clone())
+            .collect::<Vec<_>>();
+        if module_items.is_empty() {
+            continue;
+        }
+        let module_items = into_extension_traits(module_items, feature);
+        let ident = quote::format_ident!("{}_ext", crate::field_ident(feature));
+        let doc = format!(" Items generated for the feature `{feature}`");
+        items.push(syn::parse_quote! {
+            #[doc = #doc]
+            #[cfg(feature = #feature)]
+            pub mod #ident {
+                use super::*;
+                #(#module_items)*
+            }
+        });
+    }
+
+    syn::File {
+        shebang: None,
+        attrs: Vec::new(),
+        items,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge_tokens(generated: Vec<(&str, proc_macro2::TokenStream)>) -> String {
+        let generated = generated
+            .into_iter()
+            .map(|(feature, tokens)| (feature.to_string(), syn::parse2(tokens).unwrap()))
+            .collect();
+        prettyplease::unparse(&merge(generated))
+    }
+
+    #[test]
+    fn test_merge_shared_items() {
+        let shared = quote::quote! {
+            pub struct GetAuthor {
+                id: i64,
+            }
+            impl GetAuthor {
+                pub const QUERY: &'static str = "SELECT 1";
+            }
+        };
+        let actual = merge_tokens(vec![
+            (
+                "tokio-postgres",
+                quote::quote! {
+                    use tokio_postgres::types::ToSql;
+                    #shared
+                    impl GetAuthor {
+                        pub async fn query_one(&self) {}
+                    }
+                },
+            ),
+            (
+                "sqlx",
+                quote::quote! {
+                    #shared
+                    impl GetAuthor {
+                        pub fn query_one(&self) {}
+                    }
+                },
+            ),
+        ]);
+        let expected = prettyplease::unparse(&syn::parse_quote! {
+            #[cfg(feature = "tokio-postgres")]
+            use tokio_postgres::types::ToSql;
+            #shared
+            /// Items generated for the feature `tokio-postgres`
+            #[cfg(feature = "tokio-postgres")]
+            pub mod tokio_postgres_ext {
+                use super::*;
+                /// Methods of [`GetAuthor`] for the feature `tokio-postgres`
+                #[allow(async_fn_in_trait)]
+                pub trait GetAuthorExt: Sized {
+                    async fn query_one(&self);
+                }
+                impl GetAuthorExt for GetAuthor {
+                    async fn query_one(&self) {}
+                }
+            }
+            /// Items generated for the feature `sqlx`
+            #[cfg(feature = "sqlx")]
+            pub mod sqlx_ext {
+                use super::*;
+                /// Methods of [`GetAuthor`] for the feature `sqlx`
+                pub trait GetAuthorExt: Sized {
+                    fn query_one(&self);
+                }
+                impl GetAuthorExt for GetAuthor {
+                    fn query_one(&self) {}
+                }
+            }
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_merge_conflicting_names() {
+        let actual = merge_tokens(vec![
+            (
+                "postgres",
+                quote::quote! {
+                    use postgres::types::ToSql;
+                    pub struct Array(Vec<i32>);
+                    impl Array {
+                        pub fn params(&self) -> &dyn ToSql {
+                            &self.0
+                        }
+                    }
+                    pub struct Row {
+                        pub ids: Array,
+                    }
+                },
+            ),
+            (
+                "sqlx",
+                quote::quote! {
+                    use sqlx::Encode as ToSql;
+                    pub struct Array(Vec<i64>);
+                    pub struct Row {
+                        pub ids: Array,
+                    }
+                },
+            ),
+        ]);
+        let expected = prettyplease::unparse(&syn::parse_quote! {
+            /// Items generated for the feature `postgres`
+            #[cfg(feature = "postgres")]
+            pub mod postgres_ext {
+                use super::*;
+                use postgres::types::ToSql;
+                pub struct Array(Vec<i32>);
+                impl Array {
+                    pub fn params(&self) -> &dyn ToSql {
+                        &self.0
+                    }
+                }
+                pub struct Row {
+                    pub ids: Array,
+                }
+            }
+            /// Items generated for the feature `sqlx`
+            #[cfg(feature = "sqlx")]
+            pub mod sqlx_ext {
+                use super::*;
+                use sqlx::Encode as ToSql;
+                pub struct Array(Vec<i64>);
+                pub struct Row {
+                    pub ids: Array,
+                }
+            }
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_merge_attributes() {
+        let actual = merge_tokens(vec![
+            (
+                "postgres",
+                quote::quote! {
+                    #[derive(Debug, Clone, Copy, postgres_types::ToSql)]
+                    #[postgres(name = "mood")]
+                    pub enum Mood {
+                        #[postgres(name = "sad")]
+                        Sad,
+                    }
+                    pub struct Row {
+                        pub id: i64,
+                    }
+                },
+            ),
+            (
+                "tokio-postgres",
+                quote::quote! {
+                    #[derive(Debug, Clone, Copy, postgres_types::ToSql)]
+                    #[postgres(name = "mood")]
+                    pub enum Mood {
+                        #[postgres(name = "sad")]
+                        Sad,
+                    }
+                    pub struct Row {
+                        pub id: i64,
+                    }
+                },
+            ),
+            (
+                "sqlx",
+                quote::quote! {
+                    #[derive(Debug, Clone, Copy, sqlx::Type)]
+                    #[sqlx(type_name = "mood")]
+                    pub enum Mood {
+                        #[sqlx(rename = "sad")]
+                        Sad,
+                    }
+                    #[derive(sqlx::FromRow)]
+                    pub struct Row {
+                        #[sqlx(rename = "id")]
+                        pub id: i64,
+                    }
+                },
+            ),
+        ]);
+        let expected = prettyplease::unparse(&syn::parse_quote! {
+            #[derive(Debug, Clone, Copy)]
+            #[cfg_attr(any(feature = "postgres", feature = "tokio-postgres"), derive(postgres_types::ToSql))]
+            #[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+            #[cfg_attr(any(feature = "postgres", feature = "tokio-postgres"), postgres(name = "mood"))]
+            #[cfg_attr(feature = "sqlx", sqlx(type_name = "mood"))]
+            pub enum Mood {
+                #[cfg_attr(any(feature = "postgres", feature = "tokio-postgres"), postgres(name = "sad"))]
+                #[cfg_attr(feature = "sqlx", sqlx(rename = "sad"))]
+                Sad,
+            }
+            #[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+            pub struct Row {
+                #[cfg_attr(feature = "sqlx", sqlx(rename = "id"))]
+                pub id: i64,
+            }
+        });
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,15 +1,30 @@
+//! sqlc plugin generating Rust code, usable as a library to build plugins with custom backends
+//!
+//! [`generate`] runs the whole generation on a decoded request, and backends implementing
+//! [`db_crates::DbCrate`] are added with [`GenerateOptions::with_db_crate`].
+#![warn(missing_docs)]
+
 use convert_case::{Case, Casing as _};
 use prost::Message as _;
 use std::io::{Read as _, Write};
 
-pub(crate) mod plugin {
+/// Protocol buffer messages exchanged with sqlc
+#[allow(missing_docs)]
+pub mod plugin {
     include!(concat!(env!("OUT_DIR"), "/plugin.rs"));
 }
-pub(crate) mod db_crates;
+/// Backends generating code for each db crate
+pub mod db_crates;
+pub(crate) mod features;
 pub(crate) mod path_map;
-pub(crate) mod query;
-use db_crates::DbCrate as _;
-use query::{Query, ReturningRows, RsType, collect_enums};
+/// Queries and types resolved from the request, which backends generate code from
+pub mod query;
+use db_crates::{DbCrate, DbCrateConfig, DbCrateExt as _};
+use query::{
+    DbComposite, Query, ReturningRows, RsType, collect_enums, collect_newtypes,
+    collect_table_types, table_type_ident, table_type_names,
+};
+/// Error wrapping the error of an inner step, formatted one layer per step
 pub trait StackError: std::error::Error {
     /// format each error stack
     fn format_stack(&self, layer: usize, buf: &mut Vec<String>);
@@ -35,7 +50,9 @@ pub(crate) trait StackErrorResult<T, E> {
     fn stacked(self) -> Result<T, E>;
 }
 
+/// Formatting of all the layers of a [`StackError`]
 pub trait StackErrorExt: StackError {
+    /// Lines of each layer, from this error to the last one
     fn stack_error(&self) -> Vec<String>
     where
         Self: Sized,
@@ -61,26 +78,42 @@ pub trait StackErrorExt: StackError {
 
 impl<E: StackError> StackErrorExt for E {}
 
+/// Error of the plugin
 #[derive(Debug)]
 pub enum Error {
+    /// Reading the request or writing the response failed
     Io {
+        /// Error of the IO
         source: std::io::Error,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// The request is not a valid protobuf message
     ProstDecode {
+        /// Error of the decoding
         source: prost::DecodeError,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// The plugin options are not valid
     Json {
+        /// Error of the deserialization
         source: serde_json::Error,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// A query or a type of the request cannot be resolved
     QueryError {
+        /// Error of the resolution
         source: query::QueryError,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// Other errors, such as conflicting options
     Any {
+        /// Error with its message
         source: Box<dyn std::error::Error + 'static>,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
 }
@@ -224,12 +257,14 @@ pub(crate) fn normalize_str(value: &str) -> String {
     value.to_string()
 }
 
-pub(crate) fn value_ident(ident: &str) -> syn::Ident {
+/// `ident` as a type or variant name, e.g. `get_author` to `GetAuthor`
+pub fn value_ident(ident: &str) -> syn::Ident {
     let ident = normalize_str(ident).to_case(Case::Pascal);
     quote::format_ident!("{}", ident)
 }
 
-pub(crate) fn field_ident(ident: &str) -> syn::Ident {
+/// `ident` as a field or fn name, e.g. `GetAuthor` to `get_author`
+pub fn field_ident(ident: &str) -> syn::Ident {
     let ident = normalize_str(ident).to_case(Case::Snake);
     quote::format_ident!("{}", ident)
 }
@@ -239,26 +274,97 @@ pub(crate) fn field_ident(ident: &str) -> syn::Ident {
 struct OverrideType {
     /// Override db type
     db_type: Option<String>,
-    /// Override column name
+    /// Override column name, glob patterns and `.{QueryName}.{FieldName}` are allowed
     column: Option<String>,
+    /// Apply only to nullable (`true`) or non-null (`false`) columns
+    nullable: Option<bool>,
     /// Override Rust type
     rs_type: String,
     /// Rust type's slice if have
     rs_slice: Option<String>,
     /// Marker is copy cheap
     copy_cheap: bool,
+    /// Rust type of parameters if it differs from `rs_type`
+    param_type: Option<String>,
+    /// Rust type of row fields if it differs from `rs_type`
+    row_type: Option<String>,
+    /// Type the db crate encodes and decodes instead of `rs_type`
+    via: Option<String>,
+    /// Path of `fn(via) -> rs_type`
+    from: Option<String>,
+    /// Path of `fn(rs_type) -> via`
+    into: Option<String>,
+}
+
+impl OverrideType {
+    fn rs_type(&self) -> Result<RsType, Error> {
+        let owned_type =
+            syn::parse_str::<syn::Type>(&self.rs_type).map_err(|e| Error::any(e.into()))?;
+        let slice_type = self
+            .rs_slice
+            .as_ref()
+            .map(|s| syn::parse_str::<syn::Type>(s))
+            .transpose()
+            .map_err(|e| Error::any(e.into()))?;
+        let mut rs_type = RsType::new(owned_type, slice_type, self.copy_cheap);
+        if let Some(param_type) = &self.param_type {
+            let param_type = syn::parse_str(param_type).map_err(|e| Error::any(e.into()))?;
+            rs_type = rs_type.with_param_type(param_type);
+        }
+        if let Some(row_type) = &self.row_type {
+            let row_type = syn::parse_str(row_type).map_err(|e| Error::any(e.into()))?;
+            rs_type = rs_type.with_row_type(row_type);
+        }
+
+        match (&self.via, &self.from, &self.into) {
+            (None, None, None) => Ok(rs_type),
+            (Some(via), Some(from), Some(into)) => {
+                let via = query::ViaType {
+                    via: syn::parse_str(via).map_err(|e| Error::any(e.into()))?,
+                    from: syn::parse_str(from).map_err(|e| Error::any(e.into()))?,
+                    into: syn::parse_str(into).map_err(|e| Error::any(e.into()))?,
+                };
+                Ok(rs_type.with_via(via))
+            }
+            _ => {
+                let message = "`via`, `from` and `into` must be set together.";
+                Err(Error::any(message.into()))
+            }
+        }
+    }
 }
 
 #[derive(Debug, serde::Deserialize)]
 #[serde(default)]
 struct Config {
     output: String,
-    db_crate: db_crates::SupportedDbCrate,
+    db_crate: db_crates::DbCrateList,
+    /// `db_crate` name to the cargo feature enabling it, when `db_crate` is a list
+    features: std::collections::BTreeMap<String, String>,
     overrides: Vec<OverrideType>,
     debug: bool,
     #[serde(flatten)]
     return_row_attributes: query::ReturnRowAttributes,
     enum_derives: Vec<String>,
+    nullable_array_elements: path_map::PathMap<bool>,
+    /// Column path to forced nullability of result columns and parameters
+    nullability: path_map::PathMap<query::Nullability>,
+    /// Domain type to base type
+    type_aliases: std::collections::BTreeMap<String, String>,
+    /// What to do with types which cannot be mapped
+    unknown_type: query::UnknownType,
+    /// Keep MySQL `ENUM(...)` columns as `String` instead of generating Rust enums
+    mysql_enum_as_string: bool,
+    /// Column path to serde type stored as JSON
+    json_types: std::collections::BTreeMap<String, String>,
+    /// Column path to newtype name
+    newtypes: std::collections::BTreeMap<String, String>,
+    /// Connection pools whose pooled connections the generated code accepts
+    pools: Vec<db_crates::Pool>,
+    /// Generate both sync and async methods, suffixing one side
+    sync_async: Option<db_crates::SyncAsync>,
+    /// Templates of `db_crate: template`
+    template: Option<db_crates::Templates>,
 }
 
 impl Default for Config {
@@ -266,10 +372,21 @@ impl Default for Config {
         Config {
             output: "queries.rs".into(),
             db_crate: Default::default(),
+            features: Default::default(),
             overrides: Default::default(),
             debug: false,
             return_row_attributes: Default::default(),
             enum_derives: Vec::new(),
+            nullable_array_elements: Default::default(),
+            nullability: Default::default(),
+            type_aliases: Default::default(),
+            unknown_type: Default::default(),
+            mysql_enum_as_string: false,
+            json_types: Default::default(),
+            newtypes: Default::default(),
+            pools: Vec::new(),
+            sync_async: None,
+            template: None,
         }
     }
 }
@@ -280,6 +397,41 @@ impl Config {
     }
 }
 
+/// Generate `item`, or `compile_error!` in its place if its types cannot be mapped under `unknown_type: compile_error`
+///
+/// Only queries and composite types are replaced. Newtypes need their inner type, so `collect_newtypes` errors are always fatal
+fn or_compile_error<T>(
+    db_type: &query::DbTypeMap,
+    item: Result<T, query::QueryError>,
+    generate: impl FnOnce(&T) -> proc_macro2::TokenStream,
+) -> Result<proc_macro2::TokenStream, query::QueryError> {
+    match item {
+        Ok(item) => Ok(generate(&item)),
+        Err(e)
+            if e.is_cannot_map_type()
+                && db_type.unknown_type() == query::UnknownType::CompileError =>
+        {
+            let message = e.to_string();
+            Ok(quote::quote! {compile_error!(#message);})
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Add element types of multi-dimensional arrays in `fields` to `elements` without duplicates
+fn collect_nd_array_elements(elements: &mut Vec<syn::Type>, fields: &[query::ColumnField]) {
+    let to_string = |typ: &syn::Type| quote::ToTokens::to_token_stream(typ).to_string();
+    for element in fields
+        .iter()
+        .filter_map(|field| field.typ.nd_array_element())
+    {
+        let element_str = to_string(&element);
+        if !elements.iter().any(|e| to_string(e) == element_str) {
+            elements.push(element);
+        }
+    }
+}
+
 fn generate_comment(sqlc_version: &str) -> String {
     format!(
         r"//! Code generated by {}. SHOULD NOT EDIT.
@@ -292,49 +444,87 @@ fn generate_comment(sqlc_version: &str) -> String {
     )
 }
 
-pub fn try_main() -> Result<(), Error> {
-    let mut stdin = std::io::stdin().lock();
-    let mut buffer = Vec::new();
-    stdin.read_to_end(&mut buffer)?;
+/// Makes a fresh backend for each generation
+type MakeDbCrate = Box<dyn Fn() -> Box<dyn DbCrate>>;
 
-    let request = deserialize_codegen_request(&buffer)?;
-    let config = if request.plugin_options.is_empty() {
-        Config::default()
-    } else {
-        Config::from_option(&request.plugin_options)?
-    };
+/// Options of [`generate`] which cannot be written in the sqlc plugin options
+#[derive(Default)]
+pub struct GenerateOptions {
+    /// `db_crate` name to its backend
+    db_crates: Vec<(String, MakeDbCrate)>,
+}
+
+impl GenerateOptions {
+    /// Options with the built-in crates only
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    let mut db_type = config.db_crate.db_type_map();
-    for override_type in config.overrides {
-        let owned_type = syn::parse_str::<syn::Type>(&override_type.rs_type)
+    /// Generate with `db_crate` when the `db_crate` option is `name`. It takes precedence over a built-in crate of the same name
+    pub fn with_db_crate<T>(mut self, name: impl Into<String>, db_crate: T) -> Self
+    where
+        T: DbCrate + Clone + 'static,
+    {
+        let make = move || Box::new(db_crate.clone()) as Box<dyn DbCrate>;
+        self.db_crates.push((name.into(), Box::new(make)));
+        self
+    }
+
+    /// Generate with the backend named `name`, a registered one or a built-in one
+    fn generate_tokens(
+        &self,
+        name: &str,
+        config: &Config,
+        request: &plugin::GenerateRequest,
+    ) -> Result<proc_macro2::TokenStream, Error> {
+        if let Some((_, make)) = self.db_crates.iter().rev().find(|(n, _)| n == name) {
+            return generate_tokens(&mut *make(), config, request);
+        }
+        match db_crates::SupportedDbCrate::from_name(name) {
+            Some(mut db_crate) => generate_tokens(&mut db_crate, config, request),
+            None => {
+                let message = format!("`{name}` is unsupported crate.");
+                Err(Error::any(message.into()))
+            }
+        }
+    }
+}
+
+/// Generate the items of `db_crate`, without the file comment
+fn generate_tokens<T: DbCrateConfig + ?Sized>(
+    db_crate: &mut T,
+    config: &Config,
+    request: &plugin::GenerateRequest,
+) -> Result<proc_macro2::TokenStream, Error> {
+    db_crate
+        .set_pools(&config.pools)
+        .map_err(|e| Error::any(e.into()))?;
+    if let Some(settings) = &request.settings {
+        db_crate
+            .set_engine(&settings.engine)
             .map_err(|e| Error::any(e.into()))?;
-        let slice_type = override_type
-            .rs_slice
-            .map(|s| syn::parse_str::<syn::Type>(&s))
-            .transpose()
+    }
+    if let Some(sync_async) = config.sync_async {
+        db_crate
+            .set_sync_async(sync_async)
             .map_err(|e| Error::any(e.into()))?;
+    }
+    db_crate
+        .set_templates(config.template.as_ref())
+        .map_err(|e| Error::any(e.into()))?;
+
+    let mut db_type = db_crate.db_type_map();
+    for override_type in &config.overrides {
+        let rs_type = override_type.rs_type()?;
 
         match (&override_type.db_type, &override_type.column) {
             (None, Some(column)) => {
-                db_type.insert_column_type(
-                    column,
-                    RsType::new(
-                        owned_type.clone(),
-                        slice_type.clone(),
-                        override_type.copy_cheap,
-                    ),
-                );
-            }
-            (Some(db_type_name), None) => {
-                db_type.insert_db_type(
-                    db_type_name,
-                    RsType::new(
-                        owned_type.clone(),
-                        slice_type.clone(),
-                        override_type.copy_cheap,
-                    ),
-                );
+                db_type.insert_column_type(column, override_type.nullable, rs_type);
             }
+            (Some(db_type_name), None) => match override_type.nullable {
+                Some(nullable) => db_type.insert_nullable_db_type(db_type_name, nullable, rs_type),
+                None => db_type.insert_db_type(db_type_name, rs_type),
+            },
 
             (Some(_), Some(_)) => {
                 let message = "Cannot override both db_type and column name at the same time.";
@@ -347,6 +537,22 @@ pub fn try_main() -> Result<(), Error> {
         }
     }
 
+    db_type.set_nullable_array_elements(config.nullable_array_elements.clone());
+    db_type.set_nullability(config.nullability.clone());
+    db_type.set_type_aliases(&config.type_aliases);
+    db_type.set_unknown_type(config.unknown_type);
+
+    if !config.json_types.is_empty() && db_type.json_wrapper().is_none() {
+        let message = "json_types is not supported by this db_crate.";
+        return Err(Error::any(message.into()));
+    }
+    let mut json_types = path_map::PathMap::default();
+    for (column, rs_type) in &config.json_types {
+        let rs_type = syn::parse_str::<syn::Type>(rs_type).map_err(|e| Error::any(e.into()))?;
+        json_types.insert(column.clone(), rs_type);
+    }
+    db_type.set_json_types(json_types);
+
     let enum_derives = config
         .enum_derives
         .iter()
@@ -363,6 +569,26 @@ pub fn try_main() -> Result<(), Error> {
         e.derives = enum_derives.clone();
     }
 
+    let is_mysql = request
+        .settings
+        .as_ref()
+        .is_some_and(|settings| settings.engine == "mysql");
+    if is_mysql && config.mysql_enum_as_string {
+        let string_type = RsType::new(
+            syn::parse_quote! {String},
+            Some(syn::parse_quote! {str}),
+            false,
+        );
+        for e in defined_enums.drain(..) {
+            db_type.insert_db_type(&e.name, string_type.clone());
+        }
+    }
+
+    // columns of enum types are left unmapped, so only queries using them fail
+    if !db_crate.supports_enum() {
+        defined_enums.clear();
+    }
+
     for e in &defined_enums {
         db_type.insert_db_type(
             &e.name,
@@ -378,36 +604,136 @@ pub fn try_main() -> Result<(), Error> {
         );
     }
 
-    let returning_rows = request
-        .queries
+    let table_types = request
+        .catalog
+        .as_ref()
+        .map(|catalog| collect_table_types(catalog, &request.queries, &db_type))
+        .unwrap_or_default();
+
+    let default_schema = request
+        .catalog
+        .as_ref()
+        .map_or("", |catalog| catalog.default_schema.as_str());
+    for table in &table_types {
+        let Some(ident) = table_type_ident(table, default_schema) else {
+            continue;
+        };
+        let rs_type = RsType::new(
+            syn::TypePath {
+                qself: None,
+                path: ident.into(),
+            }
+            .into(),
+            None,
+            false,
+        );
+        for name in &table_type_names(table, default_schema) {
+            db_type.insert_db_type(name, rs_type.clone());
+        }
+    }
+
+    let mut newtype_paths = path_map::PathMap::default();
+    for (column, name) in &config.newtypes {
+        newtype_paths.insert(column.clone(), name.clone());
+    }
+    let newtypes = collect_newtypes(&newtype_paths, &request.queries, &db_type)?;
+    for (column, name) in &config.newtypes {
+        if let Some(newtype) = newtypes.iter().find(|newtype| &newtype.name == name) {
+            db_type.insert_column_type(column, None, newtype.rs_type());
+        }
+    }
+
+    let mut nd_array_elements = Vec::new();
+    let composites_ts = table_types
         .iter()
-        .map(|q| ReturningRows::from_query(&db_type, &config.return_row_attributes, q))
+        .map(|table| {
+            let composite = DbComposite::from_table(&db_type, table, default_schema);
+            or_compile_error(&db_type, composite, |c| {
+                collect_nd_array_elements(&mut nd_array_elements, &c.fields);
+                db_crate.defined_composite(c)
+            })
+        })
         .collect::<Result<Vec<_>, _>>()?;
+    let composites_tt = quote::quote! {#(#composites_ts)*};
 
-    let queries = request
+    let queries_ts = request
         .queries
         .iter()
-        .map(|q| Query::from_query(&db_type, q))
+        .map(|q| {
+            let query = ReturningRows::from_query(&db_type, &config.return_row_attributes, q)
+                .and_then(|r| Query::from_query(&db_type, q).map(|query| (r, query)));
+            or_compile_error(&db_type, query, |(r, query)| {
+                collect_nd_array_elements(&mut nd_array_elements, &r.fields);
+                collect_nd_array_elements(&mut nd_array_elements, &query.fields);
+                db_crate.generate_query(r, query)
+            })
+        })
         .collect::<Result<Vec<_>, _>>()?;
+    let queries_tt = quote::quote! {#(#queries_ts)*};
 
     let enums_ts = defined_enums
         .iter()
-        .map(|e| config.db_crate.defined_enum(e))
+        .map(|e| db_crate.defined_enum(e))
         .collect::<Vec<_>>();
     let enums_tt = quote::quote! {#(#enums_ts)*};
 
-    let queries_ts = returning_rows
+    let newtypes_ts = newtypes
         .iter()
-        .zip(queries.iter())
-        .map(|(r, q)| config.db_crate.generate_query(r, q))
+        .map(|n| db_crate.defined_newtype(n))
         .collect::<Vec<_>>();
-    let queries_tt = quote::quote! {#(#queries_ts)*};
+    let newtypes_tt = quote::quote! {#(#newtypes_ts)*};
 
-    let init_tt = config.db_crate.init();
-    let tt = quote::quote! {
+    let init_tt = db_crate.init();
+    let nd_array_tt = if nd_array_elements.is_empty() {
+        proc_macro2::TokenStream::new()
+    } else {
+        db_crate.nd_array_definition(&nd_array_elements)
+    };
+    Ok(quote::quote! {
         #init_tt
+        #nd_array_tt
         #enums_tt
+        #newtypes_tt
+        #composites_tt
         #queries_tt
+    })
+}
+
+/// Generate the response to `request`, resolving `db_crate` with the backends of `options`
+pub fn generate(
+    request: plugin::GenerateRequest,
+    options: &GenerateOptions,
+) -> Result<plugin::GenerateResponse, Error> {
+    let config = if request.plugin_options.is_empty() {
+        Config::default()
+    } else {
+        Config::from_option(&request.plugin_options)?
+    };
+
+    let tt = if config.db_crate.len() == 1 {
+        let name = config.db_crate.iter().next().unwrap();
+        options.generate_tokens(name, &config, &request)?
+    } else {
+        if let Some(name) = config
+            .features
+            .keys()
+            .find(|name| config.db_crate.iter().all(|n| n != name.as_str()))
+        {
+            let message = format!("`{name}` of features is not listed in db_crate.");
+            return Err(Error::any(message.into()));
+        }
+        let mut generated = Vec::new();
+        for name in config.db_crate.iter() {
+            let feature = config.features.get(name).map_or(name, String::as_str);
+            if generated.iter().any(|(f, _)| f == feature) {
+                let message = format!("feature `{feature}` is used by more than one db_crate.");
+                return Err(Error::any(message.into()));
+            }
+            let tt = options.generate_tokens(name, &config, &request)?;
+            let file = syn::parse2(tt).map_err(|e| Error::any(e.into()))?;
+            generated.push((feature.to_string(), file));
+        }
+        quote::ToTokens::into_token_stream(features::merge(generated))
     };
     let mut response = plugin::GenerateResponse::default();
     let ast = syn::parse2(tt).map_err(|e| Error::any(e.into()))?;
@@ -431,13 +757,183 @@ pub fn try_main() -> Result<(), Error> {
 
         response.files.push(plugin::File {
             name: "input.bin".into(),
-            contents: buffer,
+            contents: request.encode_to_vec(),
         });
     }
 
+    Ok(response)
+}
+
+/// Run as a sqlc plugin, reading the request from stdin and writing the response to stdout
+pub fn try_main_with(options: &GenerateOptions) -> Result<(), Error> {
+    let mut stdin = std::io::stdin().lock();
+    let mut buffer = Vec::new();
+    stdin.read_to_end(&mut buffer)?;
+
+    let request = deserialize_codegen_request(&buffer)?;
+    let response = generate(request, options)?;
     let serialized_response = serialize_codegen_response(&response);
 
     std::io::stdout().write_all(&serialized_response)?;
 
     Ok(())
 }
+
+/// Run as a sqlc plugin with the built-in crates only
+pub fn try_main() -> Result<(), Error> {
+    try_main_with(&GenerateOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use query::{DbEnum, DbNewtype, TypeMapper as _};
+
+    #[derive(Clone)]
+    struct Custom;
+
+    impl DbCrate for Custom {
+        fn type_map(&self) -> Box<dyn query::TypeMapper> {
+            let mut map = query::SimpleTypeMap::default();
+            map.insert_db_type("bigint", RsType::new(syn::parse_quote! {i64}, None, true));
+            Box::new(map)
+        }
+
+        fn defined_enum(&self, _: &DbEnum) -> proc_macro2::TokenStream {
+            proc_macro2::TokenStream::new()
+        }
+
+        fn defined_newtype(&self, _: &DbNewtype) -> proc_macro2::TokenStream {
+            proc_macro2::TokenStream::new()
+        }
+
+        fn defined_composite(&self, _: &DbComposite) -> proc_macro2::TokenStream {
+            proc_macro2::TokenStream::new()
+        }
+
+        fn generate_query(&self, row: &ReturningRows, query: &Query) -> proc_macro2::TokenStream {
+            let query_ast = db_crates::QueryAst::new(query, db_crates::DataBaseKind::Postgres);
+            let impl_ident = query_ast.impl_ident();
+            let row_tt = db_crates::make_return_row(row);
+            quote::quote! {
+                #row_tt
+                #query_ast
+                impl #impl_ident {
+                    pub fn custom(&self) {}
+                }
+            }
+        }
+    }
+
+    fn request(plugin_options: &str) -> plugin::GenerateRequest {
+        let column = plugin::Column {
+            name: "id".to_string(),
+            not_null: true,
+            r#type: Some(plugin::Identifier {
+                name: "bigint".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        plugin::GenerateRequest {
+            queries: vec![plugin::Query {
+                name: "GetAuthor".to_string(),
+                cmd: ":one".to_string(),
+                text: "SELECT id FROM authors WHERE id = $1".to_string(),
+                columns: vec![column.clone()],
+                params: vec![plugin::Parameter {
+                    number: 1,
+                    column: Some(column),
+                }],
+                ..Default::default()
+            }],
+            plugin_options: plugin_options.as_bytes().to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_custom_db_crate() {
+        let options = GenerateOptions::new().with_db_crate("custom", Custom);
+        let response = generate(request(r#"{"db_crate": "custom"}"#), &options).unwrap();
+        let contents = String::from_utf8(response.files[0].contents.clone()).unwrap();
+        assert_eq!(response.files[0].name, "queries.rs");
+        assert!(contents.contains("pub struct GetAuthorRow"));
+        assert!(contents.contains("pub fn custom(&self)"));
+
+        let response = generate(request(""), &options).unwrap();
+        let contents = String::from_utf8(response.files[0].contents.clone()).unwrap();
+        assert!(contents.contains("tokio_postgres"));
+
+        let result = generate(
+            request(r#"{"db_crate": "custom", "pools": ["bb8"]}"#),
+            &options,
+        );
+        assert!(result.is_err_and(|e| e.to_string().contains("`bb8` pool is not supported")));
+    }
+
+    #[test]
+    fn test_override_via_with_row_type() {
+        let options = r#"{"overrides": [{
+            "db_type": "bigint", "rs_type": "AuthorId", "row_type": "RowId",
+            "via": "i64", "from": "AuthorId::from", "into": "i64::from"
+        }]}"#;
+        let result = generate(request(options), &GenerateOptions::new());
+        assert!(result.is_err_and(|e| e.to_string().contains("Cannot combine `via`")));
+    }
+
+    #[test]
+    fn test_unknown_type_compile_error() {
+        let mut request = request(r#"{"unknown_type": "compile_error"}"#);
+        let query = &mut request.queries[0];
+        let params = query.params.iter_mut().filter_map(|p| p.column.as_mut());
+        for column in query.columns.iter_mut().chain(params) {
+            column.r#type.as_mut().unwrap().name = "ltree_unknown".to_string();
+        }
+        let response = generate(request.clone(), &GenerateOptions::new()).unwrap();
+        let contents = String::from_utf8(response.files[0].contents.clone()).unwrap();
+        assert!(contents.contains("compile_error!"));
+
+        // newtypes can't be generated without their inner type
+        request.plugin_options =
+            br#"{"unknown_type": "compile_error", "newtypes": {".id": "AuthorId"}}"#.to_vec();
+        let result = generate(request, &GenerateOptions::new());
+        assert!(result.is_err_and(|e| e.to_string().contains("ltree_unknown")));
+    }
+
+    #[test]
+    fn test_sqlx_any_enum() {
+        let mut request = request(r#"{"db_crate": "sqlx-any"}"#);
+        request.catalog = Some(plugin::Catalog {
+            default_schema: "public".to_string(),
+            schemas: vec![plugin::Schema {
+                name: "public".to_string(),
+                enums: vec![plugin::Enum {
+                    name: "mood".to_string(),
+                    vals: vec!["happy".to_string(), "sad".to_string()],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        // enums no query uses are skipped
+        let response = generate(request.clone(), &GenerateOptions::new()).unwrap();
+        let contents = String::from_utf8(response.files[0].contents.clone()).unwrap();
+        assert!(!contents.contains("Mood"));
+        assert!(!contents.contains("compile_error!"));
+
+        request.queries[0].columns[0].r#type.as_mut().unwrap().name = "mood".to_string();
+        let result = generate(request, &GenerateOptions::new());
+        assert!(result.is_err_and(|e| e.to_string().contains("Cannot map type `mood`")));
+    }
+
+    #[test]
+    fn test_generate_unsupported_db_crate() {
+        let result = generate(
+            request(r#"{"db_crate": "custom"}"#),
+            &GenerateOptions::new(),
+        );
+        assert!(result.is_err_and(|e| e.to_string() == "`custom` is unsupported crate."));
+    }
+}
@@ -1,6 +1,6 @@
 use core::iter;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct PathMap<T> {
     paths: std::collections::BTreeMap<String, T>,
 }
@@ -10,13 +10,75 @@ impl<T> PathMap<T> {
         self.paths.insert(path, val)
     }
 
+    pub(crate) fn get_mut(&mut self, path: &str) -> Option<&mut T> {
+        self.paths.get_mut(path)
+    }
+
     pub(crate) fn find_best_match(&self, path: &str) -> Option<&T> {
-        sub_path_iter(path).find_map(|sub_path| {
-            self.paths
-                .iter()
-                .find(|(p, _v)| p.as_str() == sub_path)
-                .map(|(_p, v)| v)
-        })
+        self.find_map_best_match(path, Some)
+    }
+
+    /// Like `find_best_match`, but skips paths whose value `f` maps to `None`
+    pub(crate) fn find_map_best_match<'a, U>(
+        &'a self,
+        path: &str,
+        f: impl Fn(&'a T) -> Option<&'a U>,
+    ) -> Option<&'a U> {
+        sub_path_iter(path).find_map(|sub_path| self.find_map_path(sub_path, &f))
+    }
+
+    /// Search `query_path` by full match, then `path` like `find_map_best_match`.
+    /// Exact keys of either path win over glob patterns, and the query path wins among equals
+    pub(crate) fn find_map_scoped<'a, U>(
+        &'a self,
+        query_path: Option<&str>,
+        path: &str,
+        f: impl Fn(&'a T) -> Option<&'a U>,
+    ) -> Option<&'a U> {
+        let exact = |p: &str| self.paths.get(p).and_then(&f);
+        let glob = |p: &str| self.find_map_glob(p, &f);
+        query_path
+            .and_then(exact)
+            .or_else(|| sub_path_iter(path).find_map(exact))
+            .or_else(|| query_path.and_then(glob))
+            .or_else(|| sub_path_iter(path).find_map(glob))
+    }
+
+    /// Exact match wins over glob patterns
+    fn find_map_path<'a, U>(
+        &'a self,
+        path: &str,
+        f: &impl Fn(&'a T) -> Option<&'a U>,
+    ) -> Option<&'a U> {
+        let exact = self.paths.get(path).and_then(f);
+        exact.or_else(|| self.find_map_glob(path, f))
+    }
+
+    /// The most specific matching pattern wins, see `glob_specificity`
+    fn find_map_glob<'a, U>(
+        &'a self,
+        path: &str,
+        f: &impl Fn(&'a T) -> Option<&'a U>,
+    ) -> Option<&'a U> {
+        self.paths
+            .iter()
+            .filter(|(p, _v)| glob_match(p, path))
+            .filter_map(|(p, v)| f(v).map(|u| (p, u)))
+            .min_by_key(|(p, _u)| glob_specificity(p))
+            .map(|(_p, u)| u)
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for PathMap<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let paths = std::collections::BTreeMap::<String, T>::deserialize(deserializer)?;
+        Ok(Self { paths })
     }
 }
 
@@ -34,6 +96,46 @@ fn sub_path_iter(path: &str) -> impl Iterator<Item = &str> {
         .chain(iter::once("."))
 }
 
+/// Match a path against a pattern where `*` matches any characters within a segment
+///
+/// glob_match(".*.created_at", ".authors.created_at") -> true
+///
+fn glob_match(pattern: &str, path: &str) -> bool {
+    if !pattern.contains('*') {
+        return false;
+    }
+    let mut pattern_segments = pattern.split('.');
+    let mut path_segments = path.split('.');
+    loop {
+        match (pattern_segments.next(), path_segments.next()) {
+            (None, None) => return true,
+            (Some(p), Some(s)) if segment_match(p.as_bytes(), s.as_bytes()) => {}
+            _ => return false,
+        }
+    }
+}
+
+/// Ordering key of a glob pattern, smaller is more specific
+///
+/// More literal characters win, then fewer `*`. Equal patterns keep the lexical order of the keys
+///
+/// glob_specificity(".audit_*.created_at") < glob_specificity(".*.created_at")
+///
+fn glob_specificity(pattern: &str) -> (core::cmp::Reverse<usize>, usize) {
+    let stars = pattern.bytes().filter(|b| *b == b'*').count();
+    (core::cmp::Reverse(pattern.len() - stars), stars)
+}
+
+fn segment_match(pattern: &[u8], segment: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => segment.is_empty(),
+        Some((b'*', rest)) => (0..=segment.len()).any(|i| segment_match(rest, &segment[i..])),
+        Some((c, rest)) => segment
+            .split_first()
+            .is_some_and(|(s, segment)| s == c && segment_match(rest, segment)),
+    }
+}
+
 /// Return all suffixes
 ///
 /// suffixes("authors.a.b") -> [".a.b",".b"]
@@ -89,6 +191,80 @@ mod tests {
         assert_eq!(m.find_best_match("totally.unrelated"), Some(&4));
     }
 
+    #[test]
+    fn test_glob_match() {
+        let mut m = PathMap::default();
+        m.insert(".*.created_at".into(), 1);
+        m.insert(".audit_*.*".into(), 2);
+        m.insert(".audit_log.created_at".into(), 3);
+
+        assert_eq!(m.find_best_match(".authors.created_at"), Some(&1));
+        assert_eq!(m.find_best_match(".audit_users.name"), Some(&2));
+        // exact match wins over glob patterns
+        assert_eq!(m.find_best_match(".audit_log.created_at"), Some(&3));
+        // `*` does not cross segments
+        assert_eq!(m.find_best_match(".audit.name"), None);
+        assert_eq!(m.find_map_scoped(None, ".name", Some), None);
+
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match(".a*c.*", ".abbc.x"));
+        assert!(!glob_match(".a*c.*", ".abcd.x"));
+    }
+
+    #[test]
+    fn test_overlapping_globs() {
+        let mut m = PathMap::default();
+        m.insert(".*.created_at".into(), 1);
+        m.insert(".audit_*.*".into(), 2);
+        m.insert(".audit_*.created_*".into(), 3);
+        m.insert(".*_log.created_at".into(), 4);
+
+        // the pattern with the most literal characters wins regardless of key order
+        assert_eq!(m.find_best_match(".audit_log.created_at"), Some(&4));
+        assert_eq!(m.find_best_match(".audit_users.created_at"), Some(&3));
+        assert_eq!(m.find_best_match(".audit_users.created_by"), Some(&3));
+        assert_eq!(m.find_best_match(".audit_users.name"), Some(&2));
+        assert_eq!(m.find_best_match(".books.created_at"), Some(&1));
+
+        // fewer `*` wins among equal literal lengths, then the lexical order of the keys
+        let mut m = PathMap::default();
+        m.insert(".a*.*b".into(), 1);
+        m.insert(".a*.b".into(), 2);
+        m.insert(".*b.b".into(), 3);
+        assert_eq!(m.find_best_match(".ac.b"), Some(&2));
+        assert_eq!(m.find_best_match(".ab.b"), Some(&3));
+    }
+
+    #[test]
+    fn test_scoped_match() {
+        let mut m = PathMap::default();
+        m.insert(".*.created_at".into(), 1);
+        m.insert(".authors.created_at".into(), 2);
+        m.insert(".GetAuthor.*".into(), 3);
+
+        // exact table key wins over a glob matching the query path
+        let query_path = Some(".GetAuthor.created_at");
+        assert_eq!(
+            m.find_map_scoped(query_path, ".authors.created_at", Some),
+            Some(&2)
+        );
+        // query path wins among glob patterns
+        m.insert(".books.*".into(), 5);
+        assert_eq!(
+            m.find_map_scoped(Some(".GetAuthor.title"), ".books.title", Some),
+            Some(&3)
+        );
+        m.insert(".GetAuthor.created_at".into(), 4);
+        assert_eq!(
+            m.find_map_scoped(query_path, ".authors.created_at", Some),
+            Some(&4)
+        );
+        assert_eq!(
+            m.find_map_scoped(Some(".ListBooks.created_at"), ".books.created_at", Some),
+            Some(&1)
+        );
+    }
+
     #[test]
     fn test_suffixes() {
         let mut it = suffixes("authors.a.b");
@@ -2,26 +2,42 @@ use quote::ToTokens;
 
 use crate::{StackError, StackErrorResult, field_ident, plugin, value_ident};
 
+/// Error resolving the queries and types of a request
 #[derive(Debug, Clone)]
 pub enum QueryError {
+    /// A column has no type
     MissingColumnType {
+        /// Name of the column
         column_name: String,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// A parameter has no column
     MissingParamColumn {
+        /// Number of the parameter, starting at 1
         param_number: i32,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// No Rust type is mapped to a DB type
     CannotMapType {
+        /// What cannot be mapped
         message: String,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// The command of a query is not a sqlc annotation
     UnknownAnnotation {
+        /// Command as written
         annotation: String,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
+    /// Error of an inner step
     Stacked {
+        /// Error of the step
         source: Box<Self>,
+        /// Where the error was raised
         location: &'static std::panic::Location<'static>,
     },
 }
@@ -53,6 +69,46 @@ impl QueryError {
         }
     }
 
+    #[track_caller]
+    pub(crate) fn unsupported_via(col_name: String) -> Self {
+        Self::CannotMapType {
+            message: format!(
+                "Cannot use `via` override for array column `{col_name}`. Only non-array columns are supported."
+            ),
+            location: std::panic::Location::caller(),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn via_with_row_or_param_type(col_name: String) -> Self {
+        Self::CannotMapType {
+            message: format!(
+                "Cannot combine `via` with `row_type` or `param_type` for column `{col_name}`. Convert between `via` and `rs_type` instead."
+            ),
+            location: std::panic::Location::caller(),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn unsupported_nd_array_element(col_name: String, typ_name: String) -> Self {
+        Self::CannotMapType {
+            message: format!(
+                "Cannot use `{typ_name}` as an element of multi-dimensional array column `{col_name}`. Consider one-dimensional array or another type in overrides."
+            ),
+            location: std::panic::Location::caller(),
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn conflicting_newtype(name: String, first: String, second: String) -> Self {
+        Self::CannotMapType {
+            message: format!(
+                "Newtype `{name}` wraps columns of different types: {first}, {second}. Use a separate newtype for each type."
+            ),
+            location: std::panic::Location::caller(),
+        }
+    }
+
     #[track_caller]
     pub(crate) fn unknown_annotation(annotation: String) -> Self {
         Self::UnknownAnnotation {
@@ -61,6 +117,15 @@ impl QueryError {
         }
     }
 
+    /// The root cause is a type which cannot be mapped
+    pub(crate) fn is_cannot_map_type(&self) -> bool {
+        match self {
+            QueryError::CannotMapType { .. } => true,
+            QueryError::Stacked { source, .. } => source.is_cannot_map_type(),
+            _ => false,
+        }
+    }
+
     fn location(&self) -> &'static std::panic::Location<'static> {
         match self {
             QueryError::MissingColumnType { location, .. } => location,
@@ -147,46 +212,126 @@ impl<T> StackErrorResult<T, QueryError> for Result<T, QueryError> {
     }
 }
 
+/// Conversion between a Rust type and a type the db crate can encode and decode
 #[derive(Clone)]
-pub(crate) struct RsType {
+pub struct ViaType {
+    /// type the db crate encodes and decodes, e.g. `String`
+    pub via: syn::Type,
+    /// `fn(via) -> owned`
+    pub from: syn::Path,
+    /// `fn(owned) -> via`
+    pub into: syn::Path,
+}
+
+/// Rust type of a DB type
+#[derive(Clone)]
+pub struct RsType {
     owned: syn::Type,
     slice: Option<syn::Type>,
     copy_cheap: bool,
+    via: Option<ViaType>,
+    /// type of row fields if it differs from `owned`
+    row: Option<syn::Type>,
+    /// type of parameters if it differs from `owned`
+    param: Option<syn::Type>,
 }
 
 impl RsType {
-    pub(crate) fn new(owned: syn::Type, slice: Option<syn::Type>, copy_cheap: bool) -> Self {
+    /// `owned` borrowed as `&slice` if any. `copy_cheap` types are passed by value
+    pub fn new(owned: syn::Type, slice: Option<syn::Type>, copy_cheap: bool) -> Self {
         RsType {
             owned,
             slice,
             copy_cheap,
+            via: None,
+            row: None,
+            param: None,
         }
     }
 
+    /// Use `row` for row fields instead of the owned type. Cannot be combined with `via`
+    pub fn with_row_type(mut self, row: syn::Type) -> Self {
+        self.row = Some(row);
+        self
+    }
+
+    /// Use `param` for parameters instead of the owned type. Cannot be combined with `via`
+    pub fn with_param_type(mut self, param: syn::Type) -> Self {
+        self.param = Some(param);
+        self
+    }
+
+    /// Encode and decode this type through `via`
+    pub fn with_via(mut self, via: ViaType) -> Self {
+        self.via = Some(via);
+        self
+    }
+
     /// 自己所有の型を返す
-    pub(crate) fn owned(&self) -> proc_macro2::TokenStream {
+    pub fn owned(&self) -> proc_macro2::TokenStream {
         self.owned.to_token_stream()
     }
 
     /// スライスの型を返す。これに`&`をつけると参照になる
-    pub(crate) fn slice(&self) -> proc_macro2::TokenStream {
-        if let Some(ref slice) = self.slice {
+    pub fn slice(&self) -> proc_macro2::TokenStream {
+        if let Some(ref param) = self.param {
+            param.to_token_stream()
+        } else if let Some(ref slice) = self.slice {
             slice.to_token_stream()
         } else {
             self.owned()
         }
     }
+
+    /// Type of row fields
+    pub fn row_owned(&self) -> proc_macro2::TokenStream {
+        self.row
+            .as_ref()
+            .map(|row| row.to_token_stream())
+            .unwrap_or_else(|| self.owned())
+    }
+
+    /// Type of array elements in parameters
+    pub fn param_owned(&self) -> proc_macro2::TokenStream {
+        self.param
+            .as_ref()
+            .map(|param| param.to_token_stream())
+            .unwrap_or_else(|| self.owned())
+    }
 }
 
+/// Rust type of a column or parameter, with its nullability and array dimensions
 #[derive(Clone)]
-pub(crate) struct RsColType {
+pub struct RsColType {
     rs_type: RsType,
     /// maybe dim
     dim: usize,
     /// col is optional
     optional: bool,
+    /// array elements are optional
+    nullable_elements: bool,
+    /// wrapper for multi-dimensional arrays, e.g. `postgres_array::Array`
+    nd_array: Option<syn::Path>,
+    /// wrapper to encode and decode `rs_type` as JSON, e.g. `sqlx::types::Json`
+    json_wrapper: Option<syn::Path>,
+    /// parameter whose type sqlc cannot infer
+    untyped: Option<UntypedParam>,
 }
-pub(crate) fn make_column_type(db_type: &plugin::Identifier) -> String {
+
+/// Rust form of parameters whose type sqlc cannot infer, e.g. `SELECT $1`
+#[derive(Clone)]
+pub enum UntypedParam {
+    /// Trait object taken by reference, e.g. `dyn ToSql + Sync`
+    TraitObject(syn::Type),
+    /// Bounds of a generic type parameter, e.g. `for<'q> sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres>`
+    Generic(proc_macro2::TokenStream),
+}
+
+/// Type names sqlc uses for parameters it cannot infer
+const UNTYPED_PARAM_TYPES: &[&str] = &["", "any", "unknown"];
+
+/// Type name looked up in [`TypeMapper`], e.g. `pg_catalog.int8`
+pub fn make_column_type(db_type: &plugin::Identifier) -> String {
     if !db_type.schema.is_empty() {
         format!("{}.{}", db_type.schema, db_type.name)
     } else {
@@ -194,6 +339,13 @@ pub(crate) fn make_column_type(db_type: &plugin::Identifier) -> String {
     }
 }
 
+fn with_column_type(column: &plugin::Column, db_type: plugin::Identifier) -> plugin::Column {
+    plugin::Column {
+        r#type: Some(db_type),
+        ..column.clone()
+    }
+}
+
 pub(crate) fn make_column_name(column: &plugin::Column) -> String {
     if let Some(table) = &column.table {
         format!(".{}.{}", table.name, column.name)
@@ -203,41 +355,186 @@ pub(crate) fn make_column_name(column: &plugin::Column) -> String {
 }
 
 impl RsColType {
-    pub(crate) fn is_array(&self) -> bool {
+    /// Whether the column is an array of any dimensions
+    pub fn is_array(&self) -> bool {
         self.dim != 0
     }
 
+    /// Whether the column is nullable
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    /// Number of array dimensions, `0` if not an array
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
     pub(crate) fn new_with_type(
         db_type: &DbTypeMap,
         column: &plugin::Column,
     ) -> Result<Self, QueryError> {
-        let rs_type = db_type.get_column_type(column).stacked()?;
+        Self::new_in_query(db_type, None, FieldKind::Column, column)
+    }
+
+    /// Type of a column used in a query, `query_path` is `.{QueryName}.{FieldName}`
+    pub(crate) fn new_in_query(
+        db_type: &DbTypeMap,
+        query_path: Option<&str>,
+        kind: FieldKind,
+        column: &plugin::Column,
+    ) -> Result<Self, QueryError> {
+        let column = &db_type.apply_nullability(query_path, kind, column);
         let dim = if column.is_sqlc_slice {
             1
         } else {
             usize::try_from(column.array_dims).unwrap_or_default()
         };
 
+        let json_type = (dim == 0)
+            .then(|| db_type.json_type(&make_column_name(column)))
+            .flatten();
+        let (rs_type, json_wrapper) = match (json_type, db_type.json_wrapper()) {
+            (Some(json_type), Some(json_wrapper)) => (
+                RsType::new(json_type.clone(), None, false),
+                Some(json_wrapper.clone()),
+            ),
+            _ => (
+                db_type
+                    .get_scoped_column_type(query_path, column)
+                    .stacked()?,
+                None,
+            ),
+        };
+        if rs_type.via.is_some() && dim != 0 {
+            return Err(QueryError::unsupported_via(make_column_name(column)));
+        }
+        if rs_type.via.is_some() && (rs_type.row.is_some() || rs_type.param.is_some()) {
+            return Err(QueryError::via_with_row_or_param_type(make_column_name(
+                column,
+            )));
+        }
+        if dim > 1 && !db_type.is_nd_array_element(&rs_type) {
+            return Err(QueryError::unsupported_nd_array_element(
+                make_column_name(column),
+                rs_type.row_owned().to_string(),
+            ));
+        }
+
         // sqlc.slice parameters are never optional.
         // https://docs.sqlc.dev/en/latest/howto/select.html#mysql-and-sqlite
         let optional = !column.is_sqlc_slice && !column.not_null;
 
+        let nullable_elements = !column.is_sqlc_slice
+            && dim != 0
+            && db_type.is_nullable_array_element(&make_column_name(column));
+
         Ok(Self {
             rs_type,
             dim,
             optional,
+            nullable_elements,
+            nd_array: db_type.nd_array_type().cloned(),
+            json_wrapper,
+            untyped: None,
         })
     }
 
+    /// Type of a parameter whose type sqlc cannot infer, `None` if the parameter is typed
+    /// or the db crate does not support untyped parameters
+    pub(crate) fn new_untyped(
+        db_type: &DbTypeMap,
+        query_path: &str,
+        column: &plugin::Column,
+        field_name: &syn::Ident,
+    ) -> Option<Self> {
+        let untyped = db_type.untyped_param()?;
+        if column.is_sqlc_slice || column.array_dims != 0 || !db_type.is_untyped(query_path, column)
+        {
+            return None;
+        }
+        let rs_type = match untyped {
+            UntypedParam::TraitObject(typ) => RsType::new(typ.clone(), None, false),
+            UntypedParam::Generic(_) => {
+                let generic = quote::format_ident!("{}Value", value_ident(&field_name.to_string()));
+                RsType::new(syn::parse_quote! {#generic}, None, true)
+            }
+        };
+        // `None` can be passed as a value of the parameter
+        Some(Self {
+            rs_type,
+            dim: 0,
+            optional: false,
+            nullable_elements: false,
+            nd_array: None,
+            json_wrapper: None,
+            untyped: Some(untyped.clone()),
+        })
+    }
+
+    /// Trait object of an untyped parameter, which is stored by reference
+    pub fn is_trait_object(&self) -> bool {
+        matches!(self.untyped, Some(UntypedParam::TraitObject(_)))
+    }
+
+    /// Generic type parameter and its bounds of an untyped parameter
+    pub fn generic_param(&self) -> Option<(proc_macro2::TokenStream, &proc_macro2::TokenStream)> {
+        match &self.untyped {
+            Some(UntypedParam::Generic(bounds)) => Some((self.rs_type.owned(), bounds)),
+            _ => None,
+        }
+    }
+
+    /// JSON wrapper if this column holds a serde type
+    pub fn json_wrapper(&self) -> Option<&syn::Path> {
+        self.json_wrapper.as_ref()
+    }
+
+    /// Conversion if this column is encoded and decoded through another type
+    pub fn via(&self) -> Option<&ViaType> {
+        self.rs_type.via.as_ref()
+    }
+
+    /// Values are converted between the row or param and what the db crate handles
+    pub fn needs_conversion(&self) -> bool {
+        self.json_wrapper.is_some() || self.via().is_some()
+    }
+
+    /// Multi-dimensional array wrapper if this column needs one
+    fn nd_array(&self) -> Option<&syn::Path> {
+        self.nd_array.as_ref().filter(|_| self.dim > 1)
+    }
+
+    /// Element type held by the multi-dimensional array wrapper, `None` if not wrapped
+    pub(crate) fn nd_array_element(&self) -> Option<syn::Type> {
+        self.nd_array()?;
+        let row_owned = self.rs_type.row_owned();
+        Some(syn::parse_quote! {#row_owned})
+    }
+
+    /// Array element type
+    fn element_tokens(&self, base_type: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if self.nullable_elements {
+            quote::quote! { Option<#base_type> }
+        } else {
+            base_type
+        }
+    }
+
     /// Convert to tokens for row struct
-    pub(crate) fn to_row_tokens(&self) -> proc_macro2::TokenStream {
-        let base_type = self.rs_type.owned();
+    pub fn to_row_tokens(&self) -> proc_macro2::TokenStream {
+        let base_type = self.element_tokens(self.rs_type.row_owned());
 
         // 配列の次元数に応じてVecでラップ
-        let mut wrapped_type = base_type;
-        for _ in 0..self.dim {
-            wrapped_type = quote::quote! { Vec<#wrapped_type> };
-        }
+        let wrapped_type = if let Some(nd_array) = self.nd_array() {
+            quote::quote! { #nd_array<#base_type> }
+        } else {
+            let mut wrapped_type = base_type;
+            for _ in 0..self.dim {
+                wrapped_type = quote::quote! { Vec<#wrapped_type> };
+            }
+            wrapped_type
+        };
 
         // optionalの場合はOptionでラップ
         if self.optional {
@@ -247,7 +544,46 @@ impl RsColType {
         }
     }
 
-    pub(crate) fn need_lifetime(&self) -> bool {
+    /// Convert to tokens for the value fetched from a row, before `unwrap_fetched`
+    pub fn to_fetch_tokens(&self) -> proc_macro2::TokenStream {
+        let fetch_type = match (&self.json_wrapper, self.via()) {
+            (Some(json_wrapper), _) => {
+                let base_type = self.rs_type.owned();
+                quote::quote! { #json_wrapper<#base_type> }
+            }
+            (None, Some(via)) => via.via.to_token_stream(),
+            (None, None) => return self.to_row_tokens(),
+        };
+        if self.optional {
+            quote::quote! { Option<#fetch_type> }
+        } else {
+            fetch_type
+        }
+    }
+
+    /// Convert a value of `to_fetch_tokens` into the row field type
+    pub fn unwrap_fetched(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if let Some(via) = self.via() {
+            let from = &via.from;
+            return if self.optional {
+                quote::quote! { #value.map(#from) }
+            } else {
+                quote::quote! { #from(#value) }
+            };
+        }
+        match (&self.json_wrapper, self.optional) {
+            (None, _) => value,
+            (Some(_), true) => quote::quote! { #value.map(|v| v.0) },
+            (Some(_), false) => quote::quote! { #value.0 },
+        }
+    }
+
+    /// Whether the parameter type borrows, and needs the lifetime of the parameter struct
+    pub fn need_lifetime(&self) -> bool {
+        // converted and generic params are taken by value
+        if self.via().is_some() || self.generic_param().is_some() {
+            return false;
+        }
         let is_slice = self.dim != 0;
         let copy_expensive = !self.rs_type.copy_cheap;
 
@@ -255,14 +591,23 @@ impl RsColType {
     }
 
     /// Convert to tokens for function parameter struct
-    pub(crate) fn to_param_tokens(&self, life_time: &syn::Lifetime) -> proc_macro2::TokenStream {
-        let wrapped_type = match self.dim {
-            0 => {
+    pub fn to_param_tokens(&self, life_time: &syn::Lifetime) -> proc_macro2::TokenStream {
+        // generic params are taken by value
+        if let Some((generic, _)) = self.generic_param() {
+            return generic;
+        }
+        let wrapped_type = match (self.dim, self.nd_array()) {
+            (0, _) if self.via().is_some() => self.rs_type.owned(),
+            (0, _) => {
                 let slice_type = self.rs_type.slice();
                 quote::quote! {#slice_type}
             }
-            _ => {
-                let mut base_type = self.rs_type.owned();
+            (_, Some(nd_array)) => {
+                let base_type = self.element_tokens(self.rs_type.param_owned());
+                quote::quote! {#nd_array<#base_type>}
+            }
+            (_, None) => {
+                let mut base_type = self.element_tokens(self.rs_type.param_owned());
                 for _ in 1..self.dim {
                     base_type = quote::quote! {Vec<#base_type>}
                 }
@@ -286,19 +631,56 @@ impl RsColType {
             }
         }
     }
+
+    /// Convert to tokens for the field of the query struct.
+    /// JSON values are kept wrapped and `via` values converted so they can be bound as they are
+    pub fn to_stored_param_tokens(&self, life_time: &syn::Lifetime) -> proc_macro2::TokenStream {
+        let stored_type = match (&self.json_wrapper, self.via()) {
+            (Some(json_wrapper), _) => {
+                let base_type = self.rs_type.owned();
+                quote::quote! {#json_wrapper<&#life_time #base_type>}
+            }
+            (None, Some(via)) => via.via.to_token_stream(),
+            (None, None) => return self.to_param_tokens(life_time),
+        };
+        if self.optional {
+            quote::quote! {Option<#stored_type>}
+        } else {
+            stored_type
+        }
+    }
+
+    /// Convert a value of `to_param_tokens` into `to_stored_param_tokens`
+    pub fn wrap_param(&self, value: &syn::Ident) -> proc_macro2::TokenStream {
+        let wrapper = match (&self.json_wrapper, self.via()) {
+            (Some(json_wrapper), _) => json_wrapper,
+            (None, Some(via)) => &via.into,
+            (None, None) => return quote::quote! {#value},
+        };
+        if self.optional {
+            quote::quote! {#value.map(#wrapper)}
+        } else {
+            quote::quote! {#wrapper(#value)}
+        }
+    }
 }
 
+/// DB type name to Rust type mapping of a db crate
 pub trait TypeMapper {
+    /// Rust type of the DB type named `db_type_name`
     fn find_rs_type(&self, db_type_name: &str) -> Option<&RsType>;
+    /// Rust type of `column`, by its type name unless overridden
     fn find_column_type(&self, column: &plugin::Column) -> Option<RsType> {
         let col_type = column.r#type.as_ref().map(make_column_type)?;
         self.find_rs_type(&col_type).cloned()
     }
+    /// Map the DB type named `db_type` to `rs_type`
     fn insert_db_type(&mut self, db_type: &str, rs_type: RsType);
 }
 
+/// [`TypeMapper`] looking up type names as is
 #[derive(Default)]
-pub(crate) struct SimpleTypeMap {
+pub struct SimpleTypeMap {
     /// db_type to rust type
     map: std::collections::BTreeMap<String, RsType>,
 }
@@ -313,25 +695,79 @@ impl TypeMapper for SimpleTypeMap {
     }
 }
 
+/// Rust types of an override, optionally qualified by the nullability of the column
+#[derive(Default)]
+struct NullableTypes {
+    any: Option<RsType>,
+    nullable: Option<RsType>,
+    not_null: Option<RsType>,
+}
+
+impl NullableTypes {
+    fn insert(&mut self, nullable: Option<bool>, rs_type: RsType) {
+        match nullable {
+            None => self.any = Some(rs_type),
+            Some(true) => self.nullable = Some(rs_type),
+            Some(false) => self.not_null = Some(rs_type),
+        }
+    }
+
+    fn get(&self, nullable: bool) -> Option<&RsType> {
+        let qualified = if nullable {
+            self.nullable.as_ref()
+        } else {
+            self.not_null.as_ref()
+        };
+        qualified.or(self.any.as_ref())
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct ColumnTypeMap {
     /// column name to rust type
-    column_map: crate::path_map::PathMap<RsType>,
+    column_map: crate::path_map::PathMap<NullableTypes>,
 }
 
 impl ColumnTypeMap {
-    pub(crate) fn insert(&mut self, column_name: &str, rs_type: RsType) {
-        self.column_map.insert(column_name.to_string(), rs_type);
+    pub(crate) fn insert(&mut self, column_name: &str, nullable: Option<bool>, rs_type: RsType) {
+        if let Some(types) = self.column_map.get_mut(column_name) {
+            types.insert(nullable, rs_type);
+        } else {
+            let mut types = NullableTypes::default();
+            types.insert(nullable, rs_type);
+            self.column_map.insert(column_name.to_string(), types);
+        }
     }
 
-    pub(crate) fn find_type(&self, column_name: &str) -> Option<&RsType> {
-        self.column_map.find_best_match(column_name)
+    /// Search query scope `.{QueryName}.{FieldName}` by full match first, then `.{TableName}.{ColumnName}`.
+    /// Exact keys win over glob patterns in either scope
+    pub(crate) fn find_type(
+        &self,
+        query_path: Option<&str>,
+        column_name: &str,
+        nullable: bool,
+    ) -> Option<&RsType> {
+        self.column_map
+            .find_map_scoped(query_path, column_name, |types| types.get(nullable))
     }
 }
 
 pub(crate) struct DbTypeMap {
     type_map: Box<dyn TypeMapper>,
     column_map: ColumnTypeMap,
+    /// db types overridden only for nullable or non-null columns
+    nullable_db_types: std::collections::BTreeMap<String, NullableTypes>,
+    /// domain type to base type
+    type_aliases: std::collections::BTreeMap<String, plugin::Identifier>,
+    unknown_type: UnknownType,
+    nd_array: Option<syn::Path>,
+    /// element types `nd_array` can hold, `None` if any
+    nd_array_elements: Option<Vec<syn::Type>>,
+    nullable_array_elements: crate::path_map::PathMap<bool>,
+    nullability: crate::path_map::PathMap<Nullability>,
+    json_wrapper: Option<syn::Path>,
+    json_types: crate::path_map::PathMap<syn::Type>,
+    untyped_param: Option<UntypedParam>,
 }
 
 impl DbTypeMap {
@@ -339,14 +775,61 @@ impl DbTypeMap {
         Self {
             type_map,
             column_map: Default::default(),
+            nullable_db_types: Default::default(),
+            type_aliases: Default::default(),
+            unknown_type: Default::default(),
+            nd_array: None,
+            nd_array_elements: None,
+            nullable_array_elements: Default::default(),
+            nullability: Default::default(),
+            json_wrapper: None,
+            json_types: Default::default(),
+            untyped_param: None,
         }
     }
+
+    /// Set the wrapper used for arrays with two or more dimensions
+    pub(crate) fn with_nd_array(mut self, nd_array: Option<syn::Path>) -> Self {
+        self.nd_array = nd_array;
+        self
+    }
+
+    /// Restrict the element types of the multi-dimensional array wrapper
+    pub(crate) fn with_nd_array_elements(mut self, elements: Option<Vec<syn::Type>>) -> Self {
+        self.nd_array_elements = elements;
+        self
+    }
+
+    /// Set the form of parameters whose type sqlc cannot infer
+    pub(crate) fn with_untyped_param(mut self, untyped_param: Option<UntypedParam>) -> Self {
+        self.untyped_param = untyped_param;
+        self
+    }
+
+    /// Set the wrapper used for `json_types`
+    pub(crate) fn with_json_wrapper(mut self, json_wrapper: Option<syn::Path>) -> Self {
+        self.json_wrapper = json_wrapper;
+        self
+    }
 }
 
 impl DbTypeMap {
     pub(crate) fn get_column_type(&self, column: &plugin::Column) -> Result<RsType, QueryError> {
+        self.get_scoped_column_type(None, column)
+    }
+
+    /// Column type with overrides scoped to `.{QueryName}.{FieldName}` searched first
+    pub(crate) fn get_scoped_column_type(
+        &self,
+        query_path: Option<&str>,
+        column: &plugin::Column,
+    ) -> Result<RsType, QueryError> {
         let db_col_name = make_column_name(column);
-        if let Some(rs_type) = self.column_map.find_type(&db_col_name) {
+        let nullable = !column.not_null && !column.is_sqlc_slice;
+        if let Some(rs_type) = self
+            .column_map
+            .find_type(query_path, &db_col_name, nullable)
+        {
             return Ok(rs_type.clone());
         };
 
@@ -357,22 +840,209 @@ impl DbTypeMap {
             .ok_or_else(|| QueryError::missing_column_type(db_col_name.clone()))?
             .to_lowercase();
 
+        let column = match self.resolve_type_alias(column) {
+            Some(db_type) => std::borrow::Cow::Owned(with_column_type(column, db_type)),
+            None => std::borrow::Cow::Borrowed(column),
+        };
+        let resolved_type = column
+            .r#type
+            .as_ref()
+            .map(|t| make_column_type(t).to_lowercase())
+            .unwrap_or_default();
+
+        if let Some(rs_type) = self
+            .nullable_db_types
+            .get(&resolved_type)
+            .and_then(|types| types.get(nullable))
+        {
+            return Ok(rs_type.clone());
+        }
+
         self.type_map
-            .find_column_type(column)
-            .ok_or_else(|| QueryError::cannot_map_type(db_col_type, db_col_name))
+            .find_column_type(&column)
+            .or_else(|| match self.unknown_type {
+                UnknownType::Text => {
+                    let text = plugin::Identifier {
+                        name: "text".to_string(),
+                        ..Default::default()
+                    };
+                    self.type_map
+                        .find_column_type(&with_column_type(&column, text))
+                }
+                UnknownType::Error | UnknownType::CompileError => None,
+            })
+            .ok_or_else(|| {
+                // name array columns as such, some type maps do not map arrays at all
+                let dims = usize::try_from(column.array_dims).unwrap_or_default();
+                QueryError::cannot_map_type(db_col_type + &"[]".repeat(dims), db_col_name)
+            })
+    }
+
+    pub(crate) fn untyped_param(&self) -> Option<&UntypedParam> {
+        self.untyped_param.as_ref()
+    }
+
+    /// sqlc could not infer the type, and no override or alias gives one
+    fn is_untyped(&self, query_path: &str, column: &plugin::Column) -> bool {
+        let type_name = column
+            .r#type
+            .as_ref()
+            .map(|t| t.name.to_lowercase())
+            .unwrap_or_default();
+        UNTYPED_PARAM_TYPES.contains(&type_name.as_str())
+            && self
+                .column_map
+                .find_type(
+                    Some(query_path),
+                    &make_column_name(column),
+                    !column.not_null,
+                )
+                .is_none()
+            && self.resolve_type_alias(column).is_none()
+            && column
+                .r#type
+                .as_ref()
+                .is_none_or(|t| !self.contains_db_type(&make_column_type(t).to_lowercase()))
+    }
+
+    /// Base type of a domain type in `type_aliases`, searched with `schema.name` then `name`
+    fn resolve_type_alias(&self, column: &plugin::Column) -> Option<plugin::Identifier> {
+        let db_type = column.r#type.as_ref()?;
+        self.type_aliases
+            .get(&make_column_type(db_type).to_lowercase())
+            .or_else(|| self.type_aliases.get(&db_type.name.to_lowercase()))
+            .cloned()
+    }
+
+    pub(crate) fn set_type_aliases(
+        &mut self,
+        aliases: &std::collections::BTreeMap<String, String>,
+    ) {
+        self.type_aliases = aliases
+            .iter()
+            .map(|(alias, base)| {
+                let base = match base.rsplit_once('.') {
+                    Some((schema, name)) => plugin::Identifier {
+                        schema: schema.to_string(),
+                        name: name.to_string(),
+                        ..Default::default()
+                    },
+                    None => plugin::Identifier {
+                        name: base.to_string(),
+                        ..Default::default()
+                    },
+                };
+                (alias.to_lowercase(), base)
+            })
+            .collect();
+    }
+
+    pub(crate) fn set_unknown_type(&mut self, unknown_type: UnknownType) {
+        self.unknown_type = unknown_type;
+    }
+
+    pub(crate) fn unknown_type(&self) -> UnknownType {
+        self.unknown_type
     }
 
     pub(crate) fn insert_db_type(&mut self, db_type: &str, rs_type: RsType) {
         self.type_map.insert_db_type(db_type, rs_type);
     }
 
-    pub(crate) fn insert_column_type(&mut self, column_name: &str, rs_type: RsType) {
-        self.column_map.insert(column_name, rs_type);
+    pub(crate) fn insert_column_type(
+        &mut self,
+        column_name: &str,
+        nullable: Option<bool>,
+        rs_type: RsType,
+    ) {
+        self.column_map.insert(column_name, nullable, rs_type);
+    }
+
+    /// Override a db type only for nullable or non-null columns
+    pub(crate) fn insert_nullable_db_type(
+        &mut self,
+        db_type: &str,
+        nullable: bool,
+        rs_type: RsType,
+    ) {
+        self.nullable_db_types
+            .entry(db_type.to_lowercase())
+            .or_default()
+            .insert(Some(nullable), rs_type);
+    }
+
+    pub(crate) fn contains_db_type(&self, db_type: &str) -> bool {
+        self.type_map.find_rs_type(db_type).is_some()
+    }
+
+    pub(crate) fn nd_array_type(&self) -> Option<&syn::Path> {
+        self.nd_array.as_ref()
+    }
+
+    /// `rs_type` can be held by the multi-dimensional array wrapper
+    fn is_nd_array_element(&self, rs_type: &RsType) -> bool {
+        let (Some(_), Some(elements)) = (&self.nd_array, &self.nd_array_elements) else {
+            return true;
+        };
+        let row_owned = rs_type.row_owned().to_string();
+        elements
+            .iter()
+            .any(|element| quote::ToTokens::to_token_stream(element).to_string() == row_owned)
+    }
+
+    pub(crate) fn set_nullable_array_elements(&mut self, paths: crate::path_map::PathMap<bool>) {
+        self.nullable_array_elements = paths;
+    }
+
+    pub(crate) fn json_wrapper(&self) -> Option<&syn::Path> {
+        self.json_wrapper.as_ref()
+    }
+
+    pub(crate) fn set_json_types(&mut self, json_types: crate::path_map::PathMap<syn::Type>) {
+        self.json_types = json_types;
+    }
+
+    pub(crate) fn json_type(&self, column_name: &str) -> Option<&syn::Type> {
+        self.json_types.find_best_match(column_name)
+    }
+
+    pub(crate) fn set_nullability(&mut self, nullability: crate::path_map::PathMap<Nullability>) {
+        self.nullability = nullability;
+    }
+
+    /// Override `not_null` of the column by `nullability`, searching `.{QueryName}.{FieldName}`
+    /// by full match first, then `.{TableName}.{ColumnName}`. Exact keys win over glob patterns
+    pub(crate) fn apply_nullability<'a>(
+        &self,
+        query_path: Option<&str>,
+        kind: FieldKind,
+        column: &'a plugin::Column,
+    ) -> std::borrow::Cow<'a, plugin::Column> {
+        let nullable =
+            self.nullability
+                .find_map_scoped(query_path, &make_column_name(column), |n| n.get(kind));
+        match nullable {
+            Some(&nullable) if nullable == column.not_null => {
+                std::borrow::Cow::Owned(plugin::Column {
+                    not_null: !nullable,
+                    ..column.clone()
+                })
+            }
+            _ => std::borrow::Cow::Borrowed(column),
+        }
+    }
+
+    pub(crate) fn is_nullable_array_element(&self, column_name: &str) -> bool {
+        self.nullable_array_elements
+            .find_best_match(column_name)
+            .copied()
+            .unwrap_or(false)
     }
 }
 
+/// Enum defined in the catalog
 #[derive(Clone)]
-pub(crate) struct DbEnum {
+pub struct DbEnum {
     /// name of enum
     ///
     /// ```sql
@@ -382,7 +1052,7 @@ pub(crate) struct DbEnum {
     ///           'NONFICTION'
     /// );
     /// ```
-    pub(crate) name: String,
+    pub name: String,
 
     /// values of enum
     ///
@@ -394,14 +1064,15 @@ pub(crate) struct DbEnum {
     ///            ^^^^^^^^^^
     /// );
     /// ```
-    pub(crate) values: Vec<String>,
+    pub values: Vec<String>,
 
     /// additional derives for enum
-    pub(crate) derives: Vec<syn::Path>,
+    pub derives: Vec<syn::Path>,
 }
 
 impl DbEnum {
-    pub(crate) fn ident(&self) -> syn::Ident {
+    /// Name of the Rust enum
+    pub fn ident(&self) -> syn::Ident {
         value_ident(&self.name)
     }
 }
@@ -423,14 +1094,246 @@ pub(crate) fn collect_enums(catalog: &plugin::Catalog) -> Vec<DbEnum> {
     res
 }
 
+/// Field of a row, composite type or parameter struct
 #[derive(Clone)]
-pub(crate) struct ColumnField {
+pub struct ColumnField {
     /// normalized field name
-    pub(crate) name: syn::Ident,
+    pub name: syn::Ident,
     /// original field name
-    pub(crate) name_original: syn::LitStr,
-    pub(crate) typ: RsColType,
-    pub(crate) attribute: Option<proc_macro2::TokenStream>,
+    pub name_original: syn::LitStr,
+    /// Rust type of the field
+    pub typ: RsColType,
+    /// Attributes from `column_attributes`
+    pub attribute: Option<proc_macro2::TokenStream>,
+}
+
+/// Table used as a composite type
+///
+/// ```sql
+/// SELECT a FROM authors a;
+///        ^
+/// ```
+#[derive(Clone)]
+pub struct DbComposite {
+    /// name of table
+    pub name: String,
+    /// schema of table, empty if it is the default schema
+    pub schema: String,
+    /// columns of table
+    pub fields: Vec<ColumnField>,
+}
+
+impl DbComposite {
+    /// Struct name, prefixed by the schema unless it is the default schema, e.g. `AuditUsers`
+    pub fn ident(&self) -> syn::Ident {
+        if self.schema.is_empty() {
+            value_ident(&self.name)
+        } else {
+            value_ident(&format!("{}_{}", self.schema, self.name))
+        }
+    }
+
+    /// Type name in the database, qualified by the schema unless it is the default schema
+    pub fn type_name(&self) -> String {
+        if self.schema.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.schema, self.name)
+        }
+    }
+
+    pub(crate) fn from_table(
+        db_type: &DbTypeMap,
+        table: &plugin::Table,
+        default_schema: &str,
+    ) -> Result<Self, QueryError> {
+        let fields = table
+            .columns
+            .iter()
+            .map(|col| {
+                Ok(ColumnField {
+                    name: field_ident(&col.name),
+                    name_original: syn::LitStr::new(&col.name, proc_macro2::Span::call_site()),
+                    typ: RsColType::new_with_type(db_type, col).stacked()?,
+                    attribute: None,
+                })
+            })
+            .collect::<Result<Vec<_>, QueryError>>()?;
+
+        let rel = table.rel.clone().unwrap_or_default();
+        Ok(Self {
+            schema: non_default_schema(&rel, default_schema).to_string(),
+            name: rel.name,
+            fields,
+        })
+    }
+}
+
+/// Schema of `rel`, empty if it is the default schema
+fn non_default_schema<'a>(rel: &'a plugin::Identifier, default_schema: &str) -> &'a str {
+    if rel.schema == default_schema {
+        ""
+    } else {
+        &rel.schema
+    }
+}
+
+/// Struct name of a table used as a composite type, see [`DbComposite::ident`]
+pub(crate) fn table_type_ident(table: &plugin::Table, default_schema: &str) -> Option<syn::Ident> {
+    let rel = table.rel.as_ref()?;
+    let composite = DbComposite {
+        name: rel.name.clone(),
+        schema: non_default_schema(rel, default_schema).to_string(),
+        fields: vec![],
+    };
+    Some(composite.ident())
+}
+
+/// Names a table can be referred to as a type, e.g. `authors` and `public.authors`.
+/// Tables outside the default schema are only referred to by the qualified name
+pub(crate) fn table_type_names(table: &plugin::Table, default_schema: &str) -> Vec<String> {
+    let Some(rel) = &table.rel else {
+        return vec![];
+    };
+    let mut names = vec![];
+    if non_default_schema(rel, default_schema).is_empty() {
+        names.push(rel.name.clone());
+    }
+    if !rel.schema.is_empty() {
+        names.push(make_column_type(rel));
+    }
+    names
+}
+
+/// Collect tables which are used as composite types by queries, including tables nested in them
+pub(crate) fn collect_table_types<'a>(
+    catalog: &'a plugin::Catalog,
+    queries: &[plugin::Query],
+    db_type: &DbTypeMap,
+) -> Vec<&'a plugin::Table> {
+    let tables = catalog
+        .schemas
+        .iter()
+        .flat_map(|schema| schema.tables.iter())
+        // already mapped by overrides or enums
+        .filter(|table| {
+            table_type_names(table, &catalog.default_schema)
+                .iter()
+                .all(|name| !db_type.contains_db_type(name))
+        })
+        .collect::<Vec<_>>();
+
+    let mut pending = queries
+        .iter()
+        .flat_map(|q| {
+            q.columns
+                .iter()
+                .chain(q.params.iter().filter_map(|p| p.column.as_ref()))
+        })
+        .filter_map(|col| col.r#type.as_ref().map(make_column_type))
+        .collect::<Vec<_>>();
+
+    let mut used = vec![false; tables.len()];
+    while let Some(type_name) = pending.pop() {
+        let found = tables.iter().position(|table| {
+            table_type_names(table, &catalog.default_schema).contains(&type_name)
+        });
+        let Some(idx) = found else {
+            continue;
+        };
+        if used[idx] {
+            continue;
+        }
+        used[idx] = true;
+        pending.extend(
+            tables[idx]
+                .columns
+                .iter()
+                .filter_map(|col| col.r#type.as_ref().map(make_column_type)),
+        );
+    }
+
+    tables
+        .into_iter()
+        .zip(used)
+        .filter_map(|(table, used)| used.then_some(table))
+        .collect()
+}
+
+/// Newtype wrapping the type of key columns
+///
+/// ```text
+/// pub struct AuthorId(pub i64);
+/// ```
+#[derive(Clone)]
+pub struct DbNewtype {
+    /// name of newtype
+    pub name: String,
+    /// type of the wrapped column
+    pub inner: RsType,
+}
+
+impl DbNewtype {
+    /// Name of the Rust newtype
+    pub fn ident(&self) -> syn::Ident {
+        value_ident(&self.name)
+    }
+
+    /// Whether the wrapped type is passed by value
+    pub fn is_copy_cheap(&self) -> bool {
+        self.inner.copy_cheap
+    }
+
+    /// Type used for the columns wrapped by this newtype
+    pub fn rs_type(&self) -> RsType {
+        let ident = self.ident();
+        RsType::new(syn::parse_quote! {#ident}, None, self.inner.copy_cheap)
+    }
+}
+
+/// Collect newtypes used by queries. The inner type is the one columns are currently mapped to.
+/// An unmappable column is an error even under `unknown_type: compile_error`
+pub(crate) fn collect_newtypes(
+    newtypes: &crate::path_map::PathMap<String>,
+    queries: &[plugin::Query],
+    db_type: &DbTypeMap,
+) -> Result<Vec<DbNewtype>, QueryError> {
+    // newtype and the column which decided its inner type
+    let mut res: Vec<(DbNewtype, String)> = vec![];
+
+    let columns = queries.iter().flat_map(|q| {
+        q.columns
+            .iter()
+            .chain(q.params.iter().filter_map(|p| p.column.as_ref()))
+    });
+    for column in columns {
+        let column_name = make_column_name(column);
+        let Some(name) = newtypes.find_best_match(&column_name) else {
+            continue;
+        };
+        let inner = db_type.get_column_type(column).stacked()?;
+        match res.iter().find(|(newtype, _)| &newtype.name == name) {
+            Some((newtype, first_column)) => {
+                let (first_type, typ) = (newtype.inner.owned(), inner.owned());
+                if first_type.to_string() != typ.to_string() {
+                    return Err(QueryError::conflicting_newtype(
+                        name.clone(),
+                        format!("`{first_column}` is `{first_type}`"),
+                        format!("`{column_name}` is `{typ}`"),
+                    ));
+                }
+            }
+            None => res.push((
+                DbNewtype {
+                    name: name.clone(),
+                    inner,
+                },
+                column_name,
+            )),
+        }
+    }
+
+    Ok(res.into_iter().map(|(newtype, _)| newtype).collect())
 }
 
 fn deserialize_path_map<'de, D>(
@@ -468,6 +1371,66 @@ where
     Ok(map)
 }
 
+/// What to do with a column whose type cannot be mapped to a Rust type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum UnknownType {
+    /// Abort generation
+    #[default]
+    Error,
+    /// Use the type of `text`
+    Text,
+    /// Emit `compile_error!` in place of the affected query
+    CompileError,
+}
+
+/// Whether a field is a result column or a parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FieldKind {
+    Column,
+    Param,
+}
+
+/// Forced nullability of result columns and parameters, `true` means `Option<T>`
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize)]
+#[serde(from = "NullabilityConfig")]
+pub(crate) struct Nullability {
+    column: Option<bool>,
+    param: Option<bool>,
+}
+
+impl Nullability {
+    fn get(&self, kind: FieldKind) -> Option<&bool> {
+        match kind {
+            FieldKind::Column => self.column.as_ref(),
+            FieldKind::Param => self.param.as_ref(),
+        }
+    }
+}
+
+/// `true | false` for both, or `{column: bool, param: bool}`
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum NullabilityConfig {
+    Both(bool),
+    Split {
+        column: Option<bool>,
+        param: Option<bool>,
+    },
+}
+
+impl From<NullabilityConfig> for Nullability {
+    fn from(value: NullabilityConfig) -> Self {
+        match value {
+            NullabilityConfig::Both(nullable) => Self {
+                column: Some(nullable),
+                param: Some(nullable),
+            },
+            NullabilityConfig::Split { column, param } => Self { column, param },
+        }
+    }
+}
+
 #[derive(Default, Debug, serde::Deserialize)]
 #[serde(default)]
 pub(crate) struct ReturnRowAttributes {
@@ -477,11 +1440,15 @@ pub(crate) struct ReturnRowAttributes {
     column_attributes: crate::path_map::PathMap<proc_macro2::TokenStream>,
 }
 
+/// Row returned by a query
 #[derive(Clone)]
-pub(crate) struct ReturningRows {
-    pub(crate) fields: Vec<ColumnField>,
-    pub(crate) query_name: String,
-    pub(crate) attributes: Option<proc_macro2::TokenStream>,
+pub struct ReturningRows {
+    /// Fields of the returned columns
+    pub fields: Vec<ColumnField>,
+    /// Name of the query, from which the row is named
+    pub query_name: String,
+    /// Attributes from `row_attributes`
+    pub attributes: Option<proc_macro2::TokenStream>,
 }
 
 impl ReturningRows {
@@ -517,7 +1484,12 @@ impl ReturningRows {
         let column_types = query
             .columns
             .iter()
-            .map(|col| RsColType::new_with_type(db_type, col).stacked())
+            .zip(column_names.iter())
+            .map(|(col, (name, _))| {
+                let query_path = format!(".{}.{}", query.name, name);
+                RsColType::new_in_query(db_type, Some(&query_path), FieldKind::Column, col)
+                    .stacked()
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
         let fields = column_names
@@ -545,24 +1517,35 @@ impl ReturningRows {
         })
     }
 
-    pub(crate) fn struct_ident(&self) -> syn::Ident {
+    /// Name of the row struct, e.g. `GetAuthorRow`
+    pub fn struct_ident(&self) -> syn::Ident {
         value_ident(&format!("{}Row", self.query_name))
     }
 }
 
 /// sqlc annotation
-/// See https://docs.sqlc.dev/en/stable/reference/query-annotations.html
+/// See <https://docs.sqlc.dev/en/stable/reference/query-annotations.html>
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) enum Annotation {
+pub enum Annotation {
+    /// `:exec`
     Exec,
+    /// `:execresult`
     ExecResult,
+    /// `:execrows`
     ExecRows,
+    /// `:execlastid`
     ExecLastId,
+    /// `:many`
     Many,
+    /// `:one`
     One,
+    /// `:batchexec`
     BatchExec,
+    /// `:batchmany`
     BatchMany,
+    /// `:batchone`
     BatchOne,
+    /// `:copyfrom`
     CopyFrom,
 }
 
@@ -636,18 +1619,22 @@ fn make_raw_string_literal(s: &str) -> proc_macro2::TokenStream {
         .unwrap_or_else(|_| proc_macro2::Literal::string(s).to_token_stream())
 }
 
-pub(crate) struct Query {
-    pub(crate) fields: Vec<ColumnField>,
+/// Query with its parameters
+pub struct Query {
+    /// Fields of the parameters
+    pub fields: Vec<ColumnField>,
 
-    pub(crate) annotation: Annotation,
-    pub(crate) insert_table: Option<String>,
+    /// Command of the query
+    pub annotation: Annotation,
+    /// Table an `INSERT` inserts into
+    pub insert_table: Option<String>,
     /// ```sql
     /// -- name: GetAuthor :one
     ///          ^^^^^^^^^
     /// SELECT * FROM authors
     /// WHERE id = $1 LIMIT 1;
     /// ```
-    pub(crate) query_name: String,
+    pub query_name: String,
     /// ```sql
     /// -- name: GetAuthor :one
     /// SELECT * FROM authors
@@ -679,14 +1666,24 @@ impl Query {
         let original_names = columns
             .iter()
             .map(|col| syn::LitStr::new(&col.name, proc_macro2::Span::call_site()));
-        let param_names = field_names.zip(original_names);
+        let param_names = field_names.zip(original_names).collect::<Vec<_>>();
 
         let param_types = columns
             .iter()
-            .map(|col| RsColType::new_with_type(db_type, col))
+            .zip(param_names.iter())
+            .map(|(col, (name, _))| {
+                let query_path = format!(".{}.{}", query.name, name);
+                match RsColType::new_untyped(db_type, &query_path, col, name) {
+                    Some(typ) => Ok(typ),
+                    None => {
+                        RsColType::new_in_query(db_type, Some(&query_path), FieldKind::Param, col)
+                    }
+                }
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
         let fields = param_names
+            .into_iter()
             .zip(param_types)
             .map(|((par_name, par_name_original), par_type)| ColumnField {
                 name: par_name,
@@ -711,7 +1708,8 @@ impl Query {
         })
     }
 
-    pub(crate) fn query_str(&self) -> proc_macro2::TokenStream {
+    /// SQL text sent to the database
+    pub fn sql(&self) -> String {
         match self.annotation {
             Annotation::CopyFrom => {
                 let params = self
@@ -722,12 +1720,21 @@ impl Query {
                     .unwrap_or_default();
                 let table = self.insert_table.as_deref().unwrap_or("table");
 
-                let q = format!("COPY {table} ({params}) FROM STDIN (FORMAT BINARY)");
-                make_raw_string_literal(&q)
+                format!("COPY {table} ({params}) FROM STDIN (FORMAT BINARY)")
             }
-            _ => make_raw_string_literal(&self.query_str),
+            _ => self.query_str.clone(),
         }
     }
+
+    /// [`Self::sql`] as a raw string literal
+    pub fn query_str(&self) -> proc_macro2::TokenStream {
+        make_raw_string_literal(&self.sql())
+    }
+
+    /// SQL text as written in the query file, which is the `INSERT` of `:copyfrom` queries
+    pub fn text(&self) -> &str {
+        &self.query_str
+    }
 }
 
 /// 次の命名規則で、カラム名を生成する
@@ -934,4 +1941,527 @@ mod tests {
             ]
         );
     }
+
+    fn create_array_column(array_dims: i32) -> plugin::Column {
+        plugin::Column {
+            not_null: true,
+            r#type: Some(plugin::Identifier {
+                name: "int4".to_string(),
+                schema: String::new(),
+                catalog: String::new(),
+            }),
+            array_dims,
+            ..create_test_column(Some("matrices"), "matrix")
+        }
+    }
+
+    fn create_type_map() -> DbTypeMap {
+        let mut map = SimpleTypeMap::default();
+        map.insert_db_type("int4", RsType::new(syn::parse_quote! {i32}, None, true));
+        DbTypeMap::from_dyn(Box::new(map))
+    }
+
+    fn assert_tokens(actual: proc_macro2::TokenStream, expected: proc_macro2::TokenStream) {
+        let normalize = |ts| {
+            syn::parse2::<syn::Type>(ts)
+                .unwrap()
+                .to_token_stream()
+                .to_string()
+        };
+        assert_eq!(normalize(actual), normalize(expected));
+    }
+
+    #[test]
+    fn test_nd_array_tokens() {
+        let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+        let db_type =
+            create_type_map().with_nd_array(Some(syn::parse_quote! {postgres_array::Array}));
+
+        let one_dim = RsColType::new_with_type(&db_type, &create_array_column(1)).unwrap();
+        assert_tokens(one_dim.to_row_tokens(), quote::quote! {Vec<i32>});
+        assert_tokens(
+            one_dim.to_param_tokens(&lifetime),
+            quote::quote! {&'a [i32]},
+        );
+
+        let two_dim = RsColType::new_with_type(&db_type, &create_array_column(2)).unwrap();
+        assert_tokens(
+            two_dim.to_row_tokens(),
+            quote::quote! {postgres_array::Array<i32>},
+        );
+        assert_tokens(
+            two_dim.to_param_tokens(&lifetime),
+            quote::quote! {&'a postgres_array::Array<i32>},
+        );
+    }
+
+    #[test]
+    fn test_nested_vec_without_nd_array() {
+        let db_type = create_type_map();
+
+        let two_dim = RsColType::new_with_type(&db_type, &create_array_column(2)).unwrap();
+        assert_tokens(two_dim.to_row_tokens(), quote::quote! {Vec<Vec<i32>>});
+    }
+
+    #[test]
+    fn test_nullable_array_elements() {
+        let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+        let mut db_type =
+            create_type_map().with_nd_array(Some(syn::parse_quote! {postgres_array::Array}));
+        let mut paths = crate::path_map::PathMap::default();
+        paths.insert(".matrices.matrix".to_string(), true);
+        db_type.set_nullable_array_elements(paths);
+
+        let one_dim = RsColType::new_with_type(&db_type, &create_array_column(1)).unwrap();
+        assert_tokens(one_dim.to_row_tokens(), quote::quote! {Vec<Option<i32>>});
+        assert_tokens(
+            one_dim.to_param_tokens(&lifetime),
+            quote::quote! {&'a [Option<i32>]},
+        );
+
+        let two_dim = RsColType::new_with_type(&db_type, &create_array_column(2)).unwrap();
+        assert_tokens(
+            two_dim.to_row_tokens(),
+            quote::quote! {postgres_array::Array<Option<i32>>},
+        );
+
+        let other = plugin::Column {
+            table: None,
+            ..create_array_column(1)
+        };
+        let other = RsColType::new_with_type(&db_type, &other).unwrap();
+        assert_tokens(other.to_row_tokens(), quote::quote! {Vec<i32>});
+    }
+
+    #[test]
+    fn test_json_types() {
+        let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+        let mut db_type =
+            create_type_map().with_json_wrapper(Some(syn::parse_quote! {sqlx::types::Json}));
+        let mut json_types = crate::path_map::PathMap::default();
+        json_types.insert(
+            ".users.settings".to_string(),
+            syn::parse_quote! {crate::UserSettings},
+        );
+        db_type.set_json_types(json_types);
+
+        let settings = plugin::Column {
+            r#type: Some(plugin::Identifier {
+                name: "jsonb".to_string(),
+                schema: String::new(),
+                catalog: String::new(),
+            }),
+            ..create_test_column(Some("users"), "settings")
+        };
+        let settings = RsColType::new_with_type(&db_type, &settings).unwrap();
+        assert_tokens(
+            settings.to_row_tokens(),
+            quote::quote! {Option<crate::UserSettings>},
+        );
+        assert_tokens(
+            settings.to_fetch_tokens(),
+            quote::quote! {Option<sqlx::types::Json<crate::UserSettings>>},
+        );
+        assert_tokens(
+            settings.to_param_tokens(&lifetime),
+            quote::quote! {Option<&'a crate::UserSettings>},
+        );
+        assert_tokens(
+            settings.to_stored_param_tokens(&lifetime),
+            quote::quote! {Option<sqlx::types::Json<&'a crate::UserSettings>>},
+        );
+
+        // not matched columns are mapped as usual
+        let other = RsColType::new_with_type(&db_type, &create_array_column(0)).unwrap();
+        assert!(other.json_wrapper().is_none());
+        assert_tokens(other.to_row_tokens(), quote::quote! {i32});
+    }
+
+    #[test]
+    fn test_via_override() {
+        let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+        let mut db_type = create_type_map();
+        db_type.insert_column_type(
+            ".matrices.matrix",
+            None,
+            RsType::new(syn::parse_quote! {crate::Cell}, None, false).with_via(ViaType {
+                via: syn::parse_quote! {i32},
+                from: syn::parse_quote! {crate::Cell::from_db},
+                into: syn::parse_quote! {crate::Cell::into_db},
+            }),
+        );
+
+        let column = plugin::Column {
+            not_null: false,
+            ..create_array_column(0)
+        };
+        let cell = RsColType::new_with_type(&db_type, &column).unwrap();
+        assert!(!cell.need_lifetime());
+        assert_tokens(cell.to_row_tokens(), quote::quote! {Option<crate::Cell>});
+        assert_tokens(cell.to_fetch_tokens(), quote::quote! {Option<i32>});
+        assert_tokens(
+            cell.to_param_tokens(&lifetime),
+            quote::quote! {Option<crate::Cell>},
+        );
+        assert_tokens(
+            cell.to_stored_param_tokens(&lifetime),
+            quote::quote! {Option<i32>},
+        );
+        let value = quote::format_ident!("value");
+        assert_eq!(
+            cell.wrap_param(&value).to_string(),
+            quote::quote! {value.map(crate::Cell::into_db)}.to_string()
+        );
+        assert_eq!(
+            cell.unwrap_fetched(quote::quote! {value}).to_string(),
+            quote::quote! {value.map(crate::Cell::from_db)}.to_string()
+        );
+
+        // arrays cannot be converted
+        assert!(RsColType::new_with_type(&db_type, &create_array_column(1)).is_err());
+    }
+
+    #[test]
+    fn test_param_and_row_types() {
+        let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+        let mut db_type = create_type_map();
+        db_type.insert_db_type(
+            "int4",
+            RsType::new(syn::parse_quote! {i32}, None, false)
+                .with_row_type(syn::parse_quote! {RowInt})
+                .with_param_type(syn::parse_quote! {ParamInt}),
+        );
+
+        let scalar = RsColType::new_with_type(&db_type, &create_array_column(0)).unwrap();
+        assert_tokens(scalar.to_row_tokens(), quote::quote! {RowInt});
+        assert_tokens(
+            scalar.to_param_tokens(&lifetime),
+            quote::quote! {&'a ParamInt},
+        );
+
+        let array = RsColType::new_with_type(&db_type, &create_array_column(1)).unwrap();
+        assert_tokens(array.to_row_tokens(), quote::quote! {Vec<RowInt>});
+        assert_tokens(
+            array.to_param_tokens(&lifetime),
+            quote::quote! {&'a [ParamInt]},
+        );
+    }
+
+    #[test]
+    fn test_nullability() {
+        let mut db_type = create_type_map();
+        let nullability = serde_json::from_str(
+            r#"{".matrices.matrix": {"column": true}, ".GetMatrix.matrix": false}"#,
+        )
+        .unwrap();
+        db_type.set_nullability(nullability);
+        let column = create_array_column(0);
+
+        let typ = |query_path: Option<&str>, kind: FieldKind| {
+            RsColType::new_in_query(&db_type, query_path, kind, &column)
+                .unwrap()
+                .to_row_tokens()
+        };
+        assert_tokens(typ(None, FieldKind::Column), quote::quote! {Option<i32>});
+        assert_tokens(typ(None, FieldKind::Param), quote::quote! {i32});
+        // query scope wins
+        assert_tokens(
+            typ(Some(".GetMatrix.matrix"), FieldKind::Column),
+            quote::quote! {i32},
+        );
+        assert_tokens(
+            typ(Some(".ListMatrices.matrix"), FieldKind::Column),
+            quote::quote! {Option<i32>},
+        );
+    }
+
+    #[test]
+    fn test_unknown_types() {
+        let mut db_type = create_type_map();
+        db_type.insert_db_type("text", RsType::new(syn::parse_quote! {String}, None, false));
+        let column = |type_name: &str| plugin::Column {
+            r#type: Some(plugin::Identifier {
+                name: type_name.to_string(),
+                schema: "public".to_string(),
+                catalog: String::new(),
+            }),
+            ..create_array_column(0)
+        };
+        let resolve = |db_type: &DbTypeMap, type_name: &str| {
+            db_type
+                .get_column_type(&column(type_name))
+                .map(|rs_type| rs_type.owned().to_string())
+        };
+
+        let error = resolve(&db_type, "email").err().unwrap();
+        assert!(error.is_cannot_map_type());
+
+        db_type.set_type_aliases(&[("email".to_string(), "int4".to_string())].into());
+        assert_eq!(resolve(&db_type, "email").unwrap(), "i32");
+        assert!(resolve(&db_type, "geography").is_err());
+
+        db_type.set_unknown_type(UnknownType::Text);
+        assert_eq!(resolve(&db_type, "geography").unwrap(), "String");
+    }
+
+    #[test]
+    fn test_untyped_params() {
+        let lifetime = syn::Lifetime::new("'a", proc_macro2::Span::call_site());
+        let untyped = plugin::Column {
+            r#type: Some(plugin::Identifier {
+                name: "unknown".to_string(),
+                schema: "pg_catalog".to_string(),
+                catalog: String::new(),
+            }),
+            ..create_test_column(None, "column_1")
+        };
+        let name = quote::format_ident!("column_1");
+
+        // not supported by the db crate
+        let db_type = create_type_map();
+        assert!(RsColType::new_untyped(&db_type, ".Echo.column_1", &untyped, &name).is_none());
+
+        let db_type = create_type_map().with_untyped_param(Some(UntypedParam::TraitObject(
+            syn::parse_quote! {(dyn ToSql + Sync)},
+        )));
+        let typ = RsColType::new_untyped(&db_type, ".Echo.column_1", &untyped, &name).unwrap();
+        assert!(typ.is_trait_object());
+        assert_tokens(
+            typ.to_param_tokens(&lifetime),
+            quote::quote! {&'a (dyn ToSql + Sync)},
+        );
+        // typed params are mapped as usual
+        let typed = create_array_column(0);
+        assert!(RsColType::new_untyped(&db_type, ".Echo.matrix", &typed, &name).is_none());
+
+        let db_type = create_type_map().with_untyped_param(Some(UntypedParam::Generic(
+            quote::quote! {for<'q> sqlx::Encode<'q, sqlx::Postgres>},
+        )));
+        let typ = RsColType::new_untyped(&db_type, ".Echo.column_1", &untyped, &name).unwrap();
+        assert!(!typ.need_lifetime());
+        assert_tokens(typ.to_param_tokens(&lifetime), quote::quote! {Column1Value});
+    }
+
+    #[test]
+    fn test_override_matching() {
+        let typ = |ident: &str| RsType::new(syn::parse_str(ident).unwrap(), None, true);
+        let mut db_type = create_type_map();
+        db_type.insert_column_type(".*.created_at", None, typ("Timestamp"));
+        db_type.insert_column_type(".GetAuthor.created_at", None, typ("AuthorTimestamp"));
+        db_type.insert_nullable_db_type("int4", true, typ("NullableInt"));
+        db_type.insert_column_type(".matrices.*", Some(false), typ("NonNullInt"));
+
+        let created_at = create_test_column(Some("authors"), "created_at");
+        let resolve = |query_path: Option<&str>, column: &plugin::Column| {
+            let rs_type = db_type.get_scoped_column_type(query_path, column).unwrap();
+            rs_type.owned().to_string()
+        };
+        assert_eq!(resolve(None, &created_at), "Timestamp");
+        assert_eq!(
+            resolve(Some(".ListAuthors.created_at"), &created_at),
+            "Timestamp"
+        );
+        // query scope wins
+        assert_eq!(
+            resolve(Some(".GetAuthor.created_at"), &created_at),
+            "AuthorTimestamp"
+        );
+
+        // exact table key wins over a glob matching the query path
+        let mut db_type = create_type_map();
+        db_type.insert_column_type(".*.created_at", None, typ("Timestamp"));
+        db_type.insert_column_type(".authors.created_at", None, typ("AuthorTimestamp"));
+        let rs_type = db_type
+            .get_scoped_column_type(Some(".GetAuthor.created_at"), &created_at)
+            .unwrap();
+        assert_eq!(rs_type.owned().to_string(), "AuthorTimestamp");
+
+        let not_null = create_array_column(0);
+        let nullable = plugin::Column {
+            not_null: false,
+            table: None,
+            ..create_array_column(0)
+        };
+        assert_eq!(resolve(None, &not_null), "NonNullInt");
+        assert_eq!(resolve(None, &nullable), "NullableInt");
+        assert_eq!(
+            resolve(
+                None,
+                &plugin::Column {
+                    not_null: true,
+                    ..nullable.clone()
+                }
+            ),
+            "i32"
+        );
+    }
+
+    #[test]
+    fn test_collect_newtypes() {
+        let db_type = create_type_map();
+        let mut newtypes = crate::path_map::PathMap::default();
+        newtypes.insert(".authors.id".to_string(), "AuthorId".to_string());
+        newtypes.insert(".books.author_id".to_string(), "AuthorId".to_string());
+
+        let typed = |table: &str, name: &str| plugin::Column {
+            table: Some(plugin::Identifier {
+                name: table.to_string(),
+                schema: String::new(),
+                catalog: String::new(),
+            }),
+            ..create_typed_column(name, "int4")
+        };
+        let query = plugin::Query {
+            columns: vec![typed("authors", "id"), typed("authors", "age")],
+            params: vec![plugin::Parameter {
+                number: 1,
+                column: Some(typed("books", "author_id")),
+            }],
+            ..Default::default()
+        };
+
+        let newtypes = collect_newtypes(&newtypes, &[query], &db_type).unwrap();
+        assert_eq!(newtypes.len(), 1);
+        assert_eq!(newtypes[0].ident(), "AuthorId");
+        assert!(newtypes[0].is_copy_cheap());
+        assert_tokens(newtypes[0].inner.owned(), quote::quote! {i32});
+    }
+
+    #[test]
+    fn test_collect_newtypes_conflict() {
+        let mut db_type = create_type_map();
+        db_type.insert_db_type("int8", RsType::new(syn::parse_quote! {i64}, None, true));
+        let mut newtypes = crate::path_map::PathMap::default();
+        newtypes.insert(".*.id".to_string(), "Id".to_string());
+
+        let typed = |table: &str, type_name: &str| plugin::Column {
+            table: Some(plugin::Identifier {
+                name: table.to_string(),
+                schema: String::new(),
+                catalog: String::new(),
+            }),
+            ..create_typed_column("id", type_name)
+        };
+        let query = plugin::Query {
+            columns: vec![typed("authors", "int4"), typed("books", "int8")],
+            ..Default::default()
+        };
+
+        let err = collect_newtypes(&newtypes, &[query], &db_type)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("`.authors.id` is `i32`"), "{err}");
+        assert!(err.contains("`.books.id` is `i64`"), "{err}");
+    }
+
+    fn create_table(name: &str, columns: Vec<plugin::Column>) -> plugin::Table {
+        plugin::Table {
+            rel: Some(plugin::Identifier {
+                name: name.to_string(),
+                schema: "public".to_string(),
+                catalog: String::new(),
+            }),
+            columns,
+            comment: String::new(),
+        }
+    }
+
+    fn create_typed_column(column_name: &str, type_name: &str) -> plugin::Column {
+        plugin::Column {
+            r#type: Some(plugin::Identifier {
+                name: type_name.to_string(),
+                schema: String::new(),
+                catalog: String::new(),
+            }),
+            ..create_test_column(None, column_name)
+        }
+    }
+
+    #[test]
+    fn test_collect_table_types() {
+        let catalog = plugin::Catalog {
+            schemas: vec![plugin::Schema {
+                name: "public".to_string(),
+                tables: vec![
+                    create_table("authors", vec![create_typed_column("id", "int4")]),
+                    create_table(
+                        "books",
+                        vec![
+                            create_typed_column("id", "int4"),
+                            create_typed_column("author", "authors"),
+                        ],
+                    ),
+                    create_table("unused", vec![create_typed_column("id", "int4")]),
+                ],
+                ..Default::default()
+            }],
+            default_schema: "public".to_string(),
+            ..Default::default()
+        };
+        let query = plugin::Query {
+            columns: vec![create_typed_column("books", "books")],
+            ..Default::default()
+        };
+
+        let tables = collect_table_types(&catalog, &[query], &create_type_map());
+        let names = tables
+            .iter()
+            .flat_map(|t| t.rel.as_ref())
+            .map(|rel| rel.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["authors", "books"]);
+    }
+
+    #[test]
+    fn test_table_types_in_schemas() {
+        let authors = create_table("authors", vec![create_typed_column("id", "int4")]);
+        let audit_authors = plugin::Table {
+            rel: Some(plugin::Identifier {
+                name: "authors".to_string(),
+                schema: "audit".to_string(),
+                catalog: String::new(),
+            }),
+            ..authors.clone()
+        };
+        assert_eq!(
+            table_type_names(&authors, "public"),
+            vec!["authors", "public.authors"]
+        );
+        assert_eq!(
+            table_type_names(&audit_authors, "public"),
+            vec!["audit.authors"]
+        );
+
+        let idents = [&authors, &audit_authors]
+            .map(|table| table_type_ident(table, "public").unwrap().to_string());
+        assert_eq!(idents, ["Authors", "AuditAuthors"]);
+
+        let composite = DbComposite::from_table(&create_type_map(), &audit_authors, "public");
+        let composite = composite.unwrap();
+        assert_eq!(composite.ident().to_string(), "AuditAuthors");
+        assert_eq!(composite.type_name(), "audit.authors");
+    }
+
+    #[test]
+    fn test_copy_from_query() {
+        let insert = "INSERT INTO authors (id) VALUES ($1)";
+        let copy_from = plugin::Query {
+            name: "CopyAuthors".to_string(),
+            cmd: ":copyfrom".to_string(),
+            text: insert.to_string(),
+            params: vec![plugin::Parameter {
+                number: 1,
+                column: Some(create_typed_column("id", "int4")),
+            }],
+            insert_into_table: Some(plugin::Identifier {
+                name: "authors".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let query = Query::from_query(&create_type_map(), &copy_from).unwrap();
+        assert_eq!(query.sql(), "COPY authors (id) FROM STDIN (FORMAT BINARY)");
+        assert_eq!(query.text(), insert);
+    }
 }